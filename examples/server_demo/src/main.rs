@@ -80,25 +80,7 @@ async fn home_handler() -> Html<String> {
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>{}</title>
-    <meta name="description" content="{}">
-    <meta name="keywords" content="{}">
-    <meta property="og:title" content="{}">
-    <meta property="og:description" content="{}">
-    <meta property="og:type" content="website">
-    <meta property="og:url" content="http://127.0.0.1:3004">
-    <meta name="twitter:card" content="summary_large_image">
-    <meta name="twitter:title" content="{}">
-    <meta name="twitter:description" content="{}">
-    <script type="application/ld+json">
-    {{
-        "@context": "https://schema.org",
-        "@type": "WebSite",
-        "name": "Leptos Next Metadata Demo",
-        "description": "A comprehensive demo of server-side metadata management with Leptos",
-        "url": "http://127.0.0.1:3004"
-    }}
-    </script>
+    {}
     <style>
         body {{ font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; margin: 0; padding: 2em; background: linear-gradient(135deg, #667eea 0%, #764ba2 100%); min-height: 100vh; }}
         .container {{ max-width: 1000px; margin: 0 auto; background: white; border-radius: 12px; padding: 2em; box-shadow: 0 20px 40px rgba(0,0,0,0.1); }}
@@ -150,8 +132,7 @@ async fn home_handler() -> Html<String> {
     </div>
 </body>
 </html>"#,
-        metadata.title, metadata.description, metadata.keywords,
-        metadata.og_title, metadata.og_description, metadata.twitter_title, metadata.twitter_description
+        metadata.to_head_html()
     ))
 }
 
@@ -164,19 +145,7 @@ async fn about_handler() -> Html<String> {
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>{}</title>
-    <meta name="description" content="{}">
-    <meta property="og:title" content="{}">
-    <meta property="og:description" content="{}">
-    <meta property="og:type" content="website">
-    <script type="application/ld+json">
-    {{
-        "@context": "https://schema.org",
-        "@type": "AboutPage",
-        "name": "About Leptos Next Metadata",
-        "description": "Learn about our advanced metadata management system for Leptos applications"
-    }}
-    </script>
+    {}
     <style>
         body {{ font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; margin: 0; padding: 2em; background: linear-gradient(135deg, #f093fb 0%, #f5576c 100%); min-height: 100vh; }}
         .container {{ max-width: 800px; margin: 0 auto; background: white; border-radius: 12px; padding: 2em; box-shadow: 0 20px 40px rgba(0,0,0,0.1); }}
@@ -201,7 +170,7 @@ async fn about_handler() -> Html<String> {
     </div>
 </body>
 </html>"#,
-        metadata.title, metadata.description, metadata.og_title, metadata.og_description
+        metadata.to_head_html()
     ))
 }
 
@@ -215,23 +184,7 @@ async fn blog_handler(Query(params): Query<PageQuery>) -> Html<String> {
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>{}</title>
-    <meta name="description" content="{}">
-    <meta property="og:title" content="{}">
-    <meta property="og:description" content="{}">
-    <meta property="og:type" content="article">
-    <meta property="article:author" content="Leptos Team">
-    <meta property="article:published_time" content="2024-01-15T10:00:00Z">
-    <script type="application/ld+json">
-    {{
-        "@context": "https://schema.org",
-        "@type": "BlogPosting",
-        "headline": "{}",
-        "description": "{}",
-        "author": {{ "@type": "Person", "name": "Leptos Team" }},
-        "datePublished": "2024-01-15T10:00:00Z"
-    }}
-    </script>
+    {}
     <style>
         body {{ font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; margin: 0; padding: 2em; background: linear-gradient(135deg, #4facfe 0%, #00f2fe 100%); min-height: 100vh; }}
         .container {{ max-width: 800px; margin: 0 auto; background: white; border-radius: 12px; padding: 2em; box-shadow: 0 20px 40px rgba(0,0,0,0.1); }}
@@ -256,8 +209,9 @@ async fn blog_handler(Query(params): Query<PageQuery>) -> Html<String> {
     </div>
 </body>
 </html>"#,
-        metadata.title, metadata.description, metadata.og_title, metadata.og_description,
-        metadata.title, metadata.description, metadata.title, post_id
+        metadata.to_head_html(),
+        metadata.title.as_ref().map(|title| title.to_string()).unwrap_or_default(),
+        post_id
     ))
 }
 
@@ -270,21 +224,7 @@ async fn products_handler() -> Html<String> {
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>{}</title>
-    <meta name="description" content="{}">
-    <meta property="og:title" content="{}">
-    <meta property="og:description" content="{}">
-    <meta property="og:type" content="product">
-    <script type="application/ld+json">
-    {{
-        "@context": "https://schema.org",
-        "@type": "Product",
-        "name": "Leptos Next Metadata Library",
-        "description": "Advanced metadata management for Leptos applications",
-        "brand": {{ "@type": "Brand", "name": "Leptos" }},
-        "offers": {{ "@type": "Offer", "price": "0", "priceCurrency": "USD" }}
-    }}
-    </script>
+    {}
     <style>
         body {{ font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; margin: 0; padding: 2em; background: linear-gradient(135deg, #fa709a 0%, #fee140 100%); min-height: 100vh; }}
         .container {{ max-width: 800px; margin: 0 auto; background: white; border-radius: 12px; padding: 2em; box-shadow: 0 20px 40px rgba(0,0,0,0.1); }}
@@ -317,7 +257,7 @@ async fn products_handler() -> Html<String> {
     </div>
 </body>
 </html>"#,
-        metadata.title, metadata.description, metadata.og_title, metadata.og_description
+        metadata.to_head_html()
     ))
 }
 
@@ -470,62 +410,109 @@ async fn analytics_handler() -> Html<String> {
 }
 
 // Metadata generation functions
-struct PageMetadata {
-    title: String,
-    description: String,
-    keywords: String,
-    og_title: String,
-    og_description: String,
-    twitter_title: String,
-    twitter_description: String,
-}
-
-fn generate_home_metadata() -> PageMetadata {
-    PageMetadata {
-        title: "Leptos Next Metadata - Advanced SSR Metadata Management".to_string(),
-        description: "A comprehensive demo of server-side metadata management with Leptos, featuring dynamic OG images, JSON-LD structured data, and performance optimization.".to_string(),
-        keywords: "leptos, metadata, rust, ssr, seo, og-images, json-ld, performance".to_string(),
-        og_title: "Leptos Next Metadata - Advanced SSR Demo".to_string(),
-        og_description: "Comprehensive server-side metadata management with dynamic generation, OG images, and structured data.".to_string(),
-        twitter_title: "Leptos Next Metadata Demo".to_string(),
-        twitter_description: "Advanced metadata management for Leptos applications with SSR support.".to_string(),
-    }
+fn generate_home_metadata() -> Metadata {
+    Metadata::with_title_and_description(
+        "Leptos Next Metadata - Advanced SSR Metadata Management",
+        "A comprehensive demo of server-side metadata management with Leptos, featuring dynamic OG images, JSON-LD structured data, and performance optimization.",
+    )
+    .keywords(
+        ["leptos", "metadata", "rust", "ssr", "seo", "og-images", "json-ld", "performance"].as_slice(),
+    )
+    .open_graph(OpenGraph {
+        title: Some("Leptos Next Metadata - Advanced SSR Demo".to_string()),
+        description: Some("Comprehensive server-side metadata management with dynamic generation, OG images, and structured data.".to_string()),
+        url: Some("http://127.0.0.1:3004".to_string()),
+        r#type: Some(OgType::Website),
+        ..Default::default()
+    })
+    .twitter(Twitter {
+        card: Some(TwitterCard::SummaryLargeImage),
+        title: Some("Leptos Next Metadata Demo".to_string()),
+        description: Some("Advanced metadata management for Leptos applications with SSR support.".to_string()),
+        ..Default::default()
+    })
+    .json_ld(serde_json::json!({
+        "@context": "https://schema.org",
+        "@type": "WebSite",
+        "name": "Leptos Next Metadata Demo",
+        "description": "A comprehensive demo of server-side metadata management with Leptos",
+        "url": "http://127.0.0.1:3004"
+    }))
 }
 
-fn generate_about_metadata() -> PageMetadata {
-    PageMetadata {
-        title: "About - Leptos Next Metadata".to_string(),
-        description: "Learn about our advanced metadata management system for Leptos applications with comprehensive SSR support.".to_string(),
-        keywords: "about, leptos, metadata, rust, ssr".to_string(),
-        og_title: "About Leptos Next Metadata".to_string(),
-        og_description: "Advanced metadata management system for Leptos applications.".to_string(),
-        twitter_title: "About Leptos Next Metadata".to_string(),
-        twitter_description: "Learn about our metadata management system.".to_string(),
-    }
+fn generate_about_metadata() -> Metadata {
+    Metadata::with_title_and_description(
+        "About - Leptos Next Metadata",
+        "Learn about our advanced metadata management system for Leptos applications with comprehensive SSR support.",
+    )
+    .keywords(["about", "leptos", "metadata", "rust", "ssr"].as_slice())
+    .open_graph(OpenGraph {
+        title: Some("About Leptos Next Metadata".to_string()),
+        description: Some("Advanced metadata management system for Leptos applications.".to_string()),
+        r#type: Some(OgType::Website),
+        ..Default::default()
+    })
+    .json_ld(serde_json::json!({
+        "@context": "https://schema.org",
+        "@type": "AboutPage",
+        "name": "About Leptos Next Metadata",
+        "description": "Learn about our advanced metadata management system for Leptos applications"
+    }))
 }
 
-fn generate_blog_metadata(post_id: &str) -> PageMetadata {
-    PageMetadata {
-        title: format!("Blog Post {} - Leptos Next Metadata", post_id),
-        description: format!("Dynamic blog post {} with server-side generated metadata for optimal SEO performance.", post_id),
-        keywords: "blog, leptos, metadata, rust, dynamic".to_string(),
-        og_title: format!("Blog Post {} - Dynamic Metadata Demo", post_id),
-        og_description: format!("Server-side generated metadata for blog post {}", post_id),
-        twitter_title: format!("Blog Post {} - Leptos Demo", post_id),
-        twitter_description: format!("Dynamic metadata generation for blog post {}", post_id),
-    }
+fn generate_blog_metadata(post_id: &str) -> Metadata {
+    let title = format!("Blog Post {} - Leptos Next Metadata", post_id);
+    let description = format!(
+        "Dynamic blog post {} with server-side generated metadata for optimal SEO performance.",
+        post_id
+    );
+
+    Metadata::with_title_and_description(title.clone(), description.clone())
+        .keywords(["blog", "leptos", "metadata", "rust", "dynamic"].as_slice())
+        .open_graph(OpenGraph {
+            title: Some(format!("Blog Post {} - Dynamic Metadata Demo", post_id)),
+            description: Some(format!("Server-side generated metadata for blog post {}", post_id)),
+            article: Some(Article {
+                published_time: Some("2024-01-15T10:00:00Z".to_string()),
+                modified_time: None,
+                expiration_time: None,
+                author: Some("Leptos Team".to_string()),
+                section: None,
+                tag: None,
+            }),
+            r#type: Some(OgType::Article),
+            ..Default::default()
+        })
+        .json_ld(serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "BlogPosting",
+            "headline": title,
+            "description": description,
+            "author": { "@type": "Person", "name": "Leptos Team" },
+            "datePublished": "2024-01-15T10:00:00Z"
+        }))
 }
 
-fn generate_products_metadata() -> PageMetadata {
-    PageMetadata {
-        title: "Products - Leptos Next Metadata".to_string(),
-        description: "Discover our range of Leptos-based tools and libraries for modern web development with advanced metadata management.".to_string(),
-        keywords: "products, leptos, tools, libraries, metadata".to_string(),
-        og_title: "Products - Leptos Next Metadata".to_string(),
-        og_description: "Leptos-based tools and libraries for modern web development.".to_string(),
-        twitter_title: "Products - Leptos Tools".to_string(),
-        twitter_description: "Leptos tools and libraries for web development.".to_string(),
-    }
+fn generate_products_metadata() -> Metadata {
+    Metadata::with_title_and_description(
+        "Products - Leptos Next Metadata",
+        "Discover our range of Leptos-based tools and libraries for modern web development with advanced metadata management.",
+    )
+    .keywords(["products", "leptos", "tools", "libraries", "metadata"].as_slice())
+    .open_graph(OpenGraph {
+        title: Some("Products - Leptos Next Metadata".to_string()),
+        description: Some("Leptos-based tools and libraries for modern web development.".to_string()),
+        r#type: Some(OgType::Product),
+        ..Default::default()
+    })
+    .json_ld(serde_json::json!({
+        "@context": "https://schema.org",
+        "@type": "Product",
+        "name": "Leptos Next Metadata Library",
+        "description": "Advanced metadata management for Leptos applications",
+        "brand": { "@type": "Brand", "name": "Leptos" },
+        "offers": { "@type": "Offer", "price": "0", "priceCurrency": "USD" }
+    }))
 }
 
 #[tokio::main]
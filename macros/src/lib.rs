@@ -119,14 +119,7 @@ fn generate_dynamic_metadata_code(input: GenerateMetadataInput) -> proc_macro2::
 
                 // Basic metadata
                 let title_view = meta.title.as_ref().map(|title| {
-                    match title {
-                        leptos_next_metadata::metadata::Title::Static(s) => {
-                            view! { <Title text=s.to_string()/> }
-                        },
-                        leptos_next_metadata::metadata::Title::Template { template: _, default } => {
-                            view! { <Title text=default.to_string()/> }
-                        }
-                    }
+                    view! { <Title text=title.resolve(None)/> }
                 });
 
                 let description_view = meta.description.as_ref().map(|desc| {
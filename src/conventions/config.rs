@@ -45,7 +45,7 @@ pub mod patterns {
     pub const SITEMAP_PATTERNS: &[&str] = &["sitemap"];
 
     /// Manifest patterns
-    pub const MANIFEST_PATTERNS: &[&str] = &["manifest.json"];
+    pub const MANIFEST_PATTERNS: &[&str] = &["manifest.json", "manifest.webmanifest"];
 
     /// Open Graph image patterns
     pub const OG_IMAGE_PATTERNS: &[&str] = &[
@@ -42,7 +42,7 @@ impl PatternMatcher {
     /// Check if a file is a manifest
     pub fn is_manifest(path: &Path) -> bool {
         let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-        file_name.ends_with("manifest.json") || file_name == "manifest.json"
+        file_name.ends_with("manifest.json") || file_name.ends_with(".webmanifest")
     }
 
     /// Check if a file is an Open Graph image
@@ -7,6 +7,7 @@ use super::config::*;
 use super::mime_types::MimeTypeUtils;
 use super::patterns::PatternMatcher;
 use super::types::*;
+use crate::metadata::{IconDescriptor, Icons};
 use crate::{Error, Result};
 use std::path::{Path, PathBuf};
 
@@ -224,6 +225,77 @@ impl ConventionScanner {
         })
     }
 
+    /// Convert the discovered favicons and icons into [`Icons`] for the document head
+    ///
+    /// File paths are turned into URL paths relative to the scanned directory,
+    /// so `app/blog/icon.png` becomes `/blog/icon.png`. Mask icons are added to
+    /// `other` with `rel="mask-icon"`.
+    pub fn icons(&self, conventions: &FileConventions) -> Icons {
+        let mut icons = Icons::default();
+
+        for favicon in conventions.favicon.iter().flatten() {
+            icons.icon.push(IconDescriptor {
+                sizes: icon_sizes(favicon.dimensions).or_else(|| Some("any".to_string())),
+                type_: Some(favicon.mime_type.clone()),
+                ..IconDescriptor::from(self.url_path(&favicon.path))
+            });
+        }
+
+        for file in conventions.icons.iter().flatten() {
+            let icon = IconDescriptor {
+                sizes: icon_sizes(file.dimensions),
+                type_: Some(file.mime_type.clone()),
+                ..IconDescriptor::from(self.url_path(&file.path))
+            };
+
+            match file.icon_type {
+                IconType::Icon => icons.icon.push(icon),
+                IconType::ShortcutIcon => icons.shortcut.push(icon),
+                IconType::MaskIcon => icons.other.push(IconDescriptor {
+                    rel: Some("mask-icon".to_string()),
+                    ..icon
+                }),
+            }
+        }
+
+        for apple in conventions.apple_touch_icons.iter().flatten() {
+            icons.apple.push(IconDescriptor {
+                sizes: icon_sizes(apple.dimensions),
+                type_: Some(apple.mime_type.clone()),
+                color: apple.color.clone(),
+                ..IconDescriptor::from(self.url_path(&apple.path))
+            });
+        }
+
+        icons
+    }
+
+    /// URL path of the first discovered manifest, for [`Metadata::manifest`]
+    ///
+    /// [`Metadata::manifest`]: crate::metadata::Metadata::manifest
+    pub fn manifest_url(&self, conventions: &FileConventions) -> Option<String> {
+        conventions
+            .manifests
+            .iter()
+            .flatten()
+            .next()
+            .map(|manifest| self.url_path(&manifest.path))
+    }
+
+    /// URL path of a file relative to the root directory
+    fn url_path(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.root_dir).unwrap_or(path);
+        let segments: Vec<_> = relative
+            .components()
+            .filter_map(|component| match component {
+                std::path::Component::Normal(segment) => segment.to_str(),
+                _ => None,
+            })
+            .collect();
+
+        format!("/{}", segments.join("/"))
+    }
+
     /// Extract route pattern from file path
     fn extract_route_pattern(&self, path: &Path) -> Result<Option<String>> {
         PatternMatcher::extract_route_pattern(path, &self.root_dir)
@@ -391,8 +463,85 @@ impl ConventionScanner {
     }
 }
 
+/// Format image dimensions as an icon `sizes` value
+fn icon_sizes(dimensions: Option<(u32, u32)>) -> Option<String> {
+    dimensions.map(|(width, height)| format!("{}x{}", width, height))
+}
+
 impl Default for ConventionScanner {
     fn default() -> Self {
         Self::new("./app")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icons_from_conventions() {
+        let scanner = ConventionScanner::new("./app");
+        let conventions = FileConventions {
+            favicon: Some(vec![FaviconFile {
+                path: PathBuf::from("./app/favicon.ico"),
+                size: 1024,
+                mime_type: "image/x-icon".to_string(),
+                dimensions: None,
+            }]),
+            icons: Some(vec![IconFile {
+                path: PathBuf::from("./app/blog/mask-icon.svg"),
+                size: 512,
+                mime_type: "image/svg+xml".to_string(),
+                dimensions: None,
+                icon_type: IconType::MaskIcon,
+            }]),
+            apple_touch_icons: Some(vec![AppleTouchIcon {
+                path: PathBuf::from("./app/apple-touch-icon.png"),
+                size: 2048,
+                mime_type: "image/png".to_string(),
+                dimensions: Some((180, 180)),
+                color: None,
+            }]),
+            robots_txt: None,
+            sitemaps: None,
+            manifests: None,
+            og_images: None,
+            twitter_images: None,
+        };
+
+        let icons = scanner.icons(&conventions);
+
+        assert_eq!(icons.icon[0].url, "/favicon.ico");
+        assert_eq!(icons.icon[0].sizes.as_deref(), Some("any"));
+        assert_eq!(icons.other[0].url, "/blog/mask-icon.svg");
+        assert_eq!(icons.other[0].rel.as_deref(), Some("mask-icon"));
+        assert_eq!(icons.apple[0].url, "/apple-touch-icon.png");
+        assert_eq!(icons.apple[0].sizes.as_deref(), Some("180x180"));
+        assert!(icons.shortcut.is_empty());
+    }
+
+    #[cfg(feature = "manifest")]
+    #[test]
+    fn test_scan_and_parse_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::manifest::WebAppManifest::new("Acme")
+            .start_url("/")
+            .write_to_dir(dir.path())
+            .unwrap();
+
+        let scanner = ConventionScanner::new(dir.path());
+        let conventions = scanner.scan().unwrap();
+        let manifests = conventions.manifests.as_deref().unwrap();
+
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0].manifest_type, ManifestType::WebAppManifest);
+        assert_eq!(
+            scanner.manifest_url(&conventions).as_deref(),
+            Some("/manifest.webmanifest")
+        );
+
+        let manifest = manifests[0].parse().unwrap();
+        assert_eq!(manifest.name.as_deref(), Some("Acme"));
+        assert_eq!(manifest.start_url.as_deref(), Some("/"));
+    }
+}
@@ -100,6 +100,14 @@ pub struct RobotsTxt {
     pub preview: String,
 }
 
+impl RobotsTxt {
+    /// Read the file and parse its crawling rules
+    pub fn rules(&self) -> crate::Result<crate::metadata::RobotsTxtRules> {
+        let content = std::fs::read_to_string(&self.path)?;
+        Ok(crate::metadata::RobotsTxtRules::parse(&content))
+    }
+}
+
 /// Sitemap file information
 #[derive(Debug, Clone)]
 pub struct SitemapFile {
@@ -132,6 +140,14 @@ pub struct ManifestFile {
     pub manifest_type: ManifestType,
 }
 
+#[cfg(feature = "manifest")]
+impl ManifestFile {
+    /// Read and parse the manifest
+    pub fn parse(&self) -> crate::Result<crate::manifest::WebAppManifest> {
+        crate::manifest::WebAppManifest::from_file(&self.path)
+    }
+}
+
 /// Open Graph image file information
 #[derive(Debug, Clone)]
 pub struct OgImageFile {
@@ -0,0 +1,602 @@
+//! Page metadata from Markdown front matter
+//!
+//! [`FrontMatterLoader`] reads the YAML (`---`) or TOML (`+++`) front matter of
+//! a Markdown document and maps it to [`Metadata`] with an Open Graph article
+//! and a [`BlogPosting`] JSON-LD object, so blog posts need no hand-written
+//! mapping code. The keys read for each field are configurable with
+//! [`FrontMatterKeys`], and the Markdown body supplies a fallback description
+//! and the word count.
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::front_matter::FrontMatterLoader;
+//! use leptos_next_metadata::metadata::{OgType, Title};
+//!
+//! let post = FrontMatterLoader::new()
+//!     .parse(
+//!         "---\n\
+//!          title: Shipping metadata diffs\n\
+//!          date: 2025-09-01\n\
+//!          tags: [release, seo]\n\
+//!          authors: Ana Lima\n\
+//!          ---\n\
+//!          We now compare **every** tag before a deploy.\n",
+//!     )
+//!     .unwrap();
+//!
+//! assert_eq!(post.metadata.title, Some(Title::Static("Shipping metadata diffs".into())));
+//! assert_eq!(
+//!     post.metadata.description.as_deref(),
+//!     Some("We now compare every tag before a deploy.")
+//! );
+//! let open_graph = post.metadata.open_graph.as_ref().unwrap();
+//! assert_eq!(open_graph.r#type, Some(OgType::Article));
+//! assert_eq!(post.blog_posting.word_count, Some(8));
+//! ```
+
+use crate::json_ld::BlogPosting;
+use crate::metadata::resolve::resolve_url;
+use crate::metadata::{
+    Article, Author, Authors, Keywords, Metadata, OgImage, OgType, OpenGraph, Robots, Title,
+};
+use crate::{Error, Result};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Length of a description derived from the Markdown body, in characters
+pub const DEFAULT_DESCRIPTION_LENGTH: usize = 160;
+
+/// Front matter keys read for each field
+///
+/// Values are looked up by exact key, so sites using `summary` instead of
+/// `description` only need to change that key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontMatterKeys {
+    /// Post title
+    pub title: String,
+    /// Post description
+    pub description: String,
+    /// Publication date
+    pub date: String,
+    /// Last modification date
+    pub updated: String,
+    /// Tags, as a list or a comma-separated string
+    pub tags: String,
+    /// Authors, as names or `{name, url, email}` objects
+    pub authors: String,
+    /// Social image URL
+    pub image: String,
+    /// Canonical URL
+    pub canonical: String,
+    /// Draft flag
+    pub draft: String,
+}
+
+impl Default for FrontMatterKeys {
+    fn default() -> Self {
+        Self {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            date: "date".to_string(),
+            updated: "updated".to_string(),
+            tags: "tags".to_string(),
+            authors: "authors".to_string(),
+            image: "image".to_string(),
+            canonical: "canonical".to_string(),
+            draft: "draft".to_string(),
+        }
+    }
+}
+
+/// Configuration of a [`FrontMatterLoader`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrontMatterConfig {
+    /// Front matter keys read for each field
+    pub keys: FrontMatterKeys,
+    /// Base URL that relative image and canonical URLs are resolved against
+    pub metadata_base: Option<url::Url>,
+    /// Derive a description from the first paragraph of the body when the
+    /// front matter has none
+    pub description_from_body: bool,
+    /// Maximum length of a derived description, in characters
+    pub description_length: usize,
+    /// Mark drafts `noindex, nofollow`
+    pub noindex_drafts: bool,
+}
+
+impl Default for FrontMatterConfig {
+    fn default() -> Self {
+        Self {
+            keys: FrontMatterKeys::default(),
+            metadata_base: None,
+            description_from_body: true,
+            description_length: DEFAULT_DESCRIPTION_LENGTH,
+            noindex_drafts: true,
+        }
+    }
+}
+
+/// Format of a front matter block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontMatterFormat {
+    /// YAML between `---` lines
+    Yaml,
+    /// TOML between `+++` lines
+    Toml,
+}
+
+/// A Markdown document with its front matter mapped to metadata
+#[derive(Debug, Clone)]
+pub struct MarkdownDocument {
+    /// Format of the front matter, `None` if the document has none
+    pub format: Option<FrontMatterFormat>,
+    /// All front matter values, including keys without a mapped field
+    pub front_matter: Map<String, Value>,
+    /// Markdown body after the front matter
+    pub body: String,
+    /// Whether the front matter marks the document as a draft
+    pub draft: bool,
+    /// Number of words in the body, excluding code blocks
+    pub word_count: usize,
+    /// Page metadata, with `blog_posting` as its JSON-LD
+    pub metadata: Metadata,
+    /// Schema.org `BlogPosting` for the document
+    pub blog_posting: BlogPosting,
+}
+
+/// Loads page metadata from Markdown front matter
+#[derive(Debug, Clone, Default)]
+pub struct FrontMatterLoader {
+    config: FrontMatterConfig,
+}
+
+impl FrontMatterLoader {
+    /// Create a loader with the default keys and settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a loader with a custom configuration
+    pub fn with_config(config: FrontMatterConfig) -> Self {
+        Self { config }
+    }
+
+    /// The loader configuration
+    pub fn config(&self) -> &FrontMatterConfig {
+        &self.config
+    }
+
+    /// Parse a Markdown document
+    ///
+    /// Documents without front matter are accepted; their metadata only holds
+    /// what the body supplies.
+    pub fn parse(&self, source: &str) -> Result<MarkdownDocument> {
+        let (format, front_matter, body) = split_front_matter(source)?;
+        let keys = &self.config.keys;
+
+        let draft = front_matter
+            .get(&keys.draft)
+            .is_some_and(|draft| draft.as_bool() == Some(true) || draft.as_str() == Some("true"));
+        let word_count = word_count(body);
+
+        let title = string(&front_matter, &keys.title);
+        let description = string(&front_matter, &keys.description).or_else(|| {
+            self.config
+                .description_from_body
+                .then(|| excerpt(body, self.config.description_length))
+                .flatten()
+        });
+        let date = string(&front_matter, &keys.date);
+        let updated = string(&front_matter, &keys.updated);
+        let tags = tags(front_matter.get(&keys.tags));
+        let authors = authors(front_matter.get(&keys.authors))?;
+        let image = string(&front_matter, &keys.image);
+        let canonical = string(&front_matter, &keys.canonical);
+
+        let mut metadata = Metadata {
+            metadata_base: self.config.metadata_base.clone(),
+            title: title.clone().map(Title::Static),
+            description: description.clone(),
+            canonical: canonical.clone(),
+            ..Default::default()
+        };
+        if !tags.is_empty() {
+            metadata.keywords = Some(Keywords::Multiple(tags.clone()));
+        }
+        metadata.authors = match authors.len() {
+            0 => None,
+            1 => authors.first().cloned().map(Authors::Single),
+            _ => Some(Authors::Multiple(authors.clone())),
+        };
+        if draft && self.config.noindex_drafts {
+            metadata.robots = Some(Robots::none());
+        }
+
+        metadata.open_graph = Some(OpenGraph {
+            title: title.clone(),
+            description: description.clone(),
+            r#type: Some(OgType::Article),
+            url: canonical.clone(),
+            images: image
+                .iter()
+                .map(|url| OgImage::from(url.as_str()))
+                .collect(),
+            article: Some(Article {
+                published_time: date.clone(),
+                modified_time: updated.clone(),
+                author: authors
+                    .first()
+                    .map(|author| author.url.clone().unwrap_or_else(|| author.name.clone())),
+                tag: (!tags.is_empty()).then(|| tags.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let mut blog_posting = BlogPosting {
+            context: Some("https://schema.org".to_string()),
+            type_: Some("BlogPosting".to_string()),
+            headline: title,
+            description,
+            author: match authors.len() {
+                0 => None,
+                1 => Some(person(&authors[0])),
+                _ => Some(Value::Array(authors.iter().map(person).collect())),
+            },
+            date_published: date,
+            date_modified: updated,
+            image: image.map(Value::String),
+            url: canonical,
+            word_count: i32::try_from(word_count).ok(),
+            additional: HashMap::new(),
+        };
+        if !tags.is_empty() {
+            blog_posting
+                .additional
+                .insert("keywords".to_string(), Value::String(tags.join(", ")));
+        }
+
+        metadata.json_ld = Some(
+            serde_json::to_value(&blog_posting)
+                .map_err(|e| Error::SerializationError(e.to_string()))?,
+        );
+        // Resolve relative URLs so the JSON-LD, which is not rewritten when
+        // rendering, matches the Open Graph tags
+        if let Some(ref base) = self.config.metadata_base {
+            metadata.resolve_relative_urls(Some(base));
+            blog_posting.image = blog_posting.image.map(|image| resolve_value(base, image));
+            blog_posting.url = blog_posting.url.map(|url| resolve(base, &url));
+            metadata.json_ld = Some(
+                serde_json::to_value(&blog_posting)
+                    .map_err(|e| Error::SerializationError(e.to_string()))?,
+            );
+        }
+
+        Ok(MarkdownDocument {
+            format,
+            front_matter,
+            body: body.to_string(),
+            draft,
+            word_count,
+            metadata,
+            blog_posting,
+        })
+    }
+
+    /// Read and parse a Markdown file
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<MarkdownDocument> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+        self.parse(&source).map_err(|e| match e {
+            Error::SerializationError(message) => {
+                Error::SerializationError(format!("{}: {}", path.display(), message))
+            }
+            e => e,
+        })
+    }
+}
+
+/// Split a document into its front matter values and body
+fn split_front_matter(
+    source: &str,
+) -> Result<(Option<FrontMatterFormat>, Map<String, Value>, &str)> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+    let (format, delimiter) = if source.starts_with("---") {
+        (FrontMatterFormat::Yaml, "---")
+    } else if source.starts_with("+++") {
+        (FrontMatterFormat::Toml, "+++")
+    } else {
+        return Ok((None, Map::new(), source));
+    };
+
+    let mut lines = source.split_inclusive('\n');
+    if lines.next().map(str::trim_end) != Some(delimiter) {
+        return Ok((None, Map::new(), source));
+    }
+
+    let start = delimiter.len() + source[delimiter.len()..].find('\n').map_or(0, |i| i + 1);
+    let mut offset = start;
+    for line in lines {
+        if line.trim_end() == delimiter {
+            let block = &source[start..offset];
+            let body = source[offset + line.len()..].trim_start_matches(['\r', '\n']);
+            return Ok((Some(format), parse_block(format, block)?, body));
+        }
+        offset += line.len();
+    }
+
+    Err(Error::SerializationError(format!(
+        "Front matter is not closed by a `{}` line",
+        delimiter
+    )))
+}
+
+/// Parse a front matter block into JSON values
+fn parse_block(format: FrontMatterFormat, block: &str) -> Result<Map<String, Value>> {
+    if block.trim().is_empty() {
+        return Ok(Map::new());
+    }
+
+    let value = match format {
+        // The block starts on the second line of the document, so parse it
+        // after a newline to get document line numbers from serde_yaml
+        FrontMatterFormat::Yaml => {
+            serde_yaml::from_str::<Value>(&format!("\n{}", block)).map_err(|e| {
+                let message = e.to_string();
+                match e.location() {
+                    Some(l) => {
+                        let location = format!(" at line {} column {}", l.line(), l.column());
+                        Error::SerializationError(format!(
+                            "Invalid YAML front matter{}: {}",
+                            location,
+                            message.replacen(&location, "", 1)
+                        ))
+                    }
+                    None => {
+                        Error::SerializationError(format!("Invalid YAML front matter: {}", message))
+                    }
+                }
+            })?
+        }
+        FrontMatterFormat::Toml => toml::from_str::<toml::Table>(block)
+            .map(|table| toml_to_json(toml::Value::Table(table)))
+            .map_err(|e| {
+                Error::SerializationError(format!("Invalid TOML front matter: {}", e.message()))
+            })?,
+    };
+
+    match value {
+        Value::Object(map) => Ok(map),
+        Value::Null => Ok(Map::new()),
+        _ => Err(Error::SerializationError(
+            "Front matter must be a map of keys to values".to_string(),
+        )),
+    }
+}
+
+/// Convert TOML to JSON, writing dates as their TOML string form
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => Value::from(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(items) => Value::Array(items.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// A string value, with numbers and booleans written as strings
+///
+/// YAML reads an unquoted `2025-09-01` as a string but `2025` as a number.
+fn string(front_matter: &Map<String, Value>, key: &str) -> Option<String> {
+    match front_matter.get(key)? {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Tags from a list or a comma-separated string
+fn tags(value: Option<&Value>) -> Vec<String> {
+    let tags: Vec<&str> = match value {
+        Some(Value::String(tags)) => tags.split(',').collect(),
+        Some(Value::Array(tags)) => tags.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    tags.into_iter()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Authors from a name, an object or a list of either
+fn authors(value: Option<&Value>) -> Result<Vec<Author>> {
+    let values = match value {
+        None | Some(Value::Null) => return Ok(Vec::new()),
+        Some(Value::Array(values)) => values.iter().collect(),
+        Some(value) => vec![value],
+    };
+
+    values
+        .into_iter()
+        .map(|value| match value {
+            Value::String(name) => Ok(Author {
+                name: name.clone(),
+                url: None,
+                email: None,
+            }),
+            Value::Object(author) => Ok(Author {
+                name: string(author, "name").ok_or_else(|| {
+                    Error::InvalidMetadata("Front matter author has no name".to_string())
+                })?,
+                url: string(author, "url"),
+                email: string(author, "email"),
+            }),
+            _ => Err(Error::InvalidMetadata(format!(
+                "Front matter author must be a name or an object, got {}",
+                value
+            ))),
+        })
+        .collect()
+}
+
+/// Schema.org `Person` for an author
+fn person(author: &Author) -> Value {
+    let mut person = Map::new();
+    person.insert("@type".to_string(), Value::from("Person"));
+    person.insert("name".to_string(), Value::from(author.name.as_str()));
+    if let Some(ref url) = author.url {
+        person.insert("url".to_string(), Value::from(url.as_str()));
+    }
+    Value::Object(person)
+}
+
+/// Resolve a URL like the Open Graph and canonical URLs, so they agree
+fn resolve(base: &url::Url, url: &str) -> String {
+    resolve_url(Some(base), url).unwrap_or_else(|| url.to_string())
+}
+
+fn resolve_value(base: &url::Url, value: Value) -> Value {
+    match value {
+        Value::String(url) => Value::String(resolve(base, &url)),
+        value => value,
+    }
+}
+
+/// Lines of the body outside fenced code blocks
+fn prose_lines(body: &str) -> impl Iterator<Item = &str> {
+    let mut in_fence = false;
+    body.lines().filter(move |line| {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            return false;
+        }
+        !in_fence
+    })
+}
+
+/// Number of words in the body, excluding code blocks and markup
+fn word_count(body: &str) -> usize {
+    prose_lines(body)
+        .map(|line| {
+            strip_markdown(line)
+                .split_whitespace()
+                .filter(|word| word.chars().any(char::is_alphanumeric))
+                .count()
+        })
+        .sum()
+}
+
+/// Plain text of the first paragraph, cut at a word boundary
+fn excerpt(body: &str, max_length: usize) -> Option<String> {
+    let mut paragraph = Vec::new();
+    for line in prose_lines(body) {
+        let trimmed = line.trim();
+        let is_block = trimmed.starts_with('#')
+            || trimmed.starts_with('<')
+            || trimmed.starts_with("![")
+            || trimmed.starts_with('|')
+            || trimmed.starts_with("---");
+        if trimmed.is_empty() || is_block {
+            if !paragraph.is_empty() {
+                break;
+            }
+            continue;
+        }
+        paragraph.push(strip_markdown(trimmed));
+    }
+
+    let text = paragraph.join(" ");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    if text.chars().count() <= max_length {
+        return Some(text);
+    }
+
+    let cut: String = text.chars().take(max_length.saturating_sub(1)).collect();
+    let cut = match cut.rfind(' ') {
+        Some(i) if i > 0 => &cut[..i],
+        _ => cut.as_str(),
+    };
+    Some(format!("{}…", cut.trim_end_matches([',', ';', ':', '.'])))
+}
+
+/// Remove inline Markdown: emphasis, code spans, links and list or quote markers
+fn strip_markdown(line: &str) -> String {
+    let line = line.trim_start();
+    let line = line
+        .strip_prefix("> ")
+        .or_else(|| line.strip_prefix("- "))
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+        .unwrap_or(line);
+
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '*' | '_' | '`' => {}
+            '!' if chars.peek() == Some(&'[') => {}
+            '[' => {
+                // Keep link text, drop `(url)`
+                let label: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                text.push_str(&strip_markdown(&label));
+                if chars.peek() == Some(&'(') {
+                    for c in chars.by_ref() {
+                        if c == ')' {
+                            break;
+                        }
+                    }
+                }
+            }
+            _ => text.push(ch),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_front_matter() {
+        let (format, front_matter, body) =
+            split_front_matter("---\ntitle: Hello\n---\n\nBody\n").unwrap();
+        assert_eq!(format, Some(FrontMatterFormat::Yaml));
+        assert_eq!(front_matter["title"], "Hello");
+        assert_eq!(body, "Body\n");
+
+        let (format, _, body) = split_front_matter("# Just markdown\n").unwrap();
+        assert_eq!(format, None);
+        assert_eq!(body, "# Just markdown\n");
+
+        assert!(split_front_matter("---\ntitle: Hello\n").is_err());
+    }
+
+    #[test]
+    fn test_excerpt_and_word_count() {
+        let body = "# Heading\n\nSee [the *docs*](https://x.dev) for `cargo` usage.\nSecond line.\n\n```rust\nlet ignored = 1;\n```\n\nLast.";
+        assert_eq!(
+            excerpt(body, 160).as_deref(),
+            Some("See the docs for cargo usage. Second line.")
+        );
+        assert_eq!(excerpt(body, 20).as_deref(), Some("See the docs for…"));
+        assert_eq!(word_count(body), 10);
+        assert_eq!(excerpt("```\ncode\n```", 160), None);
+    }
+}
@@ -39,6 +39,11 @@
 //! - `og-images` - Open Graph image generation (default)
 //! - `file-conventions` - File-based metadata conventions (default)
 //! - `json-ld` - JSON-LD structured data support
+//! - `manifest` - Web App Manifest generation and parsing (default)
+//! - `diff` - Structural diffs between metadata values (default)
+//! - `nextjs` - Conversion from and to the Next.js metadata JSON shape (default)
+//! - `front-matter` - Metadata from Markdown front matter (default)
+//! - `site-config` - Site-wide defaults from `metadata.toml` or `metadata.yaml` (default)
 //! - `caching` - Advanced caching with LRU and TTL
 //! - `http` - HTTP client for external metadata fetching
 //! - `debug` - Debug logging and validation
@@ -48,24 +53,33 @@
 //! - [`metadata`] - Core metadata types and traits
 //! - [`og_image`] - Open Graph image generation
 //! - [`json_ld`] - JSON-LD structured data
+//! - [`manifest`] - Web App Manifest generation
+//! - [`front_matter`] - Metadata from Markdown front matter
+//! - [`site_config`] - Site-wide defaults from a config file
 //! - [`conventions`] - File convention scanning
 //! - [`macros`] - Procedural macros for metadata
 //! - [`utils`] - Utility functions and helpers
 
 pub mod body;
-pub mod components;
 pub mod competitive_analysis;
+pub mod components;
 pub mod enhanced_title;
+#[cfg(feature = "front-matter")]
+pub mod front_matter;
 pub mod hashed_stylesheet;
 pub mod html;
 pub mod json_ld;
 pub mod macros;
+#[cfg(feature = "manifest")]
+pub mod manifest;
 pub mod meta_tags;
 pub mod metadata;
 
 // Server-only modules (exclude from WASM)
 #[cfg(not(target_arch = "wasm32"))]
 pub mod og_image;
+#[cfg(feature = "site-config")]
+pub mod site_config;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod conventions;
@@ -86,9 +100,10 @@ pub mod utils;
 /// Re-exports for common use cases
 pub mod prelude {
     pub use crate::metadata::{
-        AlternateLink, Article, Authors, CanonicalUrl, ColorScheme, Description, FormatDetection,
-        Keywords, Metadata, OpenGraph, Profile, ReferrerPolicy, Robots, ThemeColor, Title, Twitter,
-        TwitterCard, Viewport,
+        AlternateLink, Alternates, AppLinks, AppleWebApp, Article, Authors, CanonicalUrl,
+        ColorScheme, Description, FormatDetection, IconDescriptor, Icons, Itunes, Keywords,
+        Metadata, OgType, OpenGraph, Profile, ReferrerPolicy, Robots, ThemeColor, Title, Twitter,
+        TwitterCard, Verification, Viewport, ViewportConfig,
     };
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -157,7 +172,8 @@ pub mod prelude {
     pub use crate::html::Html;
     pub use crate::meta_tags::MetaTags;
     pub use crate::metadata::context::{
-        provide_metadata_context, MetadataContext, MetadataProvider,
+        provide_metadata_context, provide_metadata_context_with_config, register_metadata,
+        use_metadata_context, MetadataContext, MetadataProvider,
     };
 
     // Competitive analysis
@@ -190,6 +206,7 @@ pub use meta_tags::MetaTags;
 pub use metadata::{
     Authors, CanonicalUrl, ColorScheme, Description, FormatDetection, Keywords, Metadata,
     OpenGraph, ReferrerPolicy, Robots, ThemeColor, Title, Twitter, TwitterCard, Viewport,
+    ViewportConfig,
 };
 #[cfg(not(target_arch = "wasm32"))]
 pub use og_image::{GeneratedOgImage, OgImageGenerator, OgImageParams};
@@ -235,6 +252,9 @@ pub enum Error {
 
     #[error("Validation error: {0}")]
     ValidationError(String),
+
+    #[error("Cannot resolve relative URL \"{url}\" in `{field}`: no metadata_base is set")]
+    UnresolvedUrl { field: String, url: String },
 }
 
 /// Configuration for the metadata system
@@ -254,6 +274,9 @@ pub struct MetadataConfig {
 
     /// Resource limits
     pub limits: LimitConfig,
+
+    /// Default base URL for resolving relative URLs when metadata sets none
+    pub metadata_base: Option<url::Url>,
 }
 
 /// Cache configuration
@@ -0,0 +1,760 @@
+//! Web App Manifest generation and parsing
+//!
+//! [`WebAppManifest`] models the [Web App Manifest](https://www.w3.org/TR/appmanifest/)
+//! that makes a site installable as a Progressive Web App. A manifest is written
+//! to `manifest.webmanifest` and linked from the document head with
+//! [`Metadata::manifest`](crate::metadata::Metadata::manifest). Existing manifests
+//! found by the [`ConventionScanner`](crate::conventions::ConventionScanner) can
+//! be parsed back into a [`WebAppManifest`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::manifest::{DisplayMode, ManifestIcon, WebAppManifest};
+//!
+//! let manifest = WebAppManifest::new("Acme Dashboard")
+//!     .short_name("Acme")
+//!     .start_url("/")
+//!     .display(DisplayMode::Standalone)
+//!     .theme_color("#0f172a")
+//!     .background_color("#ffffff")
+//!     .icon(ManifestIcon::new("/icon-192.png", "192x192"))
+//!     .icon(ManifestIcon::new("/icon-512.png", "512x512"));
+//!
+//! assert!(manifest.validate().is_valid());
+//! let json = manifest.to_json().unwrap();
+//! assert!(json.contains(r#""short_name": "Acme""#));
+//! ```
+
+use crate::metadata::serde_impl::one_or_many;
+use crate::metadata::validation::{
+    ValidationError, ValidationErrorCode, ValidationResult, ValidationWarning,
+    ValidationWarningCode,
+};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// File name of a generated manifest
+pub const MANIFEST_FILE_NAME: &str = "manifest.webmanifest";
+
+/// Icon sizes a manifest needs for browsers to offer installation
+pub const REQUIRED_ICON_SIZES: [&str; 2] = ["192x192", "512x512"];
+
+/// Longest `short_name` shown without truncation on most home screens
+const SHORT_NAME_MAX_LENGTH: usize = 12;
+
+/// Web App Manifest
+///
+/// Field names serialize as in the manifest specification (`short_name`,
+/// `start_url`, ...). Members without a typed field are kept in `other` so
+/// parsing and re-serializing an existing manifest does not drop them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WebAppManifest {
+    /// Identity of the application, defaults to `start_url` in browsers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Full application name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Short name used where space is limited, such as the home screen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+
+    /// Application description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// URL loaded when the application is launched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_url: Option<String>,
+
+    /// Navigation scope of the application
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// Preferred display mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<DisplayMode>,
+
+    /// Display modes to try, in order, before `display`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub display_override: Vec<DisplayMode>,
+
+    /// Default orientation, such as `portrait` or `landscape`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<String>,
+
+    /// Theme color of the application window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<String>,
+
+    /// Background color of the splash screen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+
+    /// Primary language of the manifest members
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// Text direction of the manifest members (`ltr`, `rtl` or `auto`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+
+    /// Application icons
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<ManifestIcon>,
+
+    /// Shortcuts offered from the application icon
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shortcuts: Vec<ManifestShortcut>,
+
+    /// Screenshots shown in the install dialog
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub screenshots: Vec<ManifestScreenshot>,
+
+    /// Registration as a target of the system share dialog
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub share_target: Option<ShareTarget>,
+
+    /// Store categories, such as `productivity`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+
+    /// Other manifest members
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Display mode of an installed application
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DisplayMode {
+    /// Uses the whole screen without any browser UI
+    Fullscreen,
+    /// Looks like a standalone native application
+    Standalone,
+    /// Standalone with a minimal set of navigation controls
+    MinimalUi,
+    /// Opens in a regular browser tab
+    Browser,
+    /// Draws into the title bar area (`display_override` only)
+    WindowControlsOverlay,
+    /// Shows application tabs (`display_override` only)
+    Tabbed,
+}
+
+impl DisplayMode {
+    /// Manifest value of the display mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DisplayMode::Fullscreen => "fullscreen",
+            DisplayMode::Standalone => "standalone",
+            DisplayMode::MinimalUi => "minimal-ui",
+            DisplayMode::Browser => "browser",
+            DisplayMode::WindowControlsOverlay => "window-controls-overlay",
+            DisplayMode::Tabbed => "tabbed",
+        }
+    }
+
+    /// Whether browsers offer to install an application with this display mode
+    pub fn is_installable(&self) -> bool {
+        matches!(
+            self,
+            DisplayMode::Fullscreen | DisplayMode::Standalone | DisplayMode::MinimalUi
+        )
+    }
+}
+
+impl fmt::Display for DisplayMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Manifest icon
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ManifestIcon {
+    /// Icon URL
+    pub src: String,
+
+    /// Space-separated sizes, such as `192x192` or `any`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sizes: Option<String>,
+
+    /// MIME type
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+
+    /// Space-separated purposes: `any`, `maskable` or `monochrome`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+}
+
+impl ManifestIcon {
+    /// Create an icon with the given sizes
+    pub fn new(src: impl Into<String>, sizes: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            sizes: Some(sizes.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the MIME type
+    pub fn type_(mut self, type_: impl Into<String>) -> Self {
+        self.type_ = Some(type_.into());
+        self
+    }
+
+    /// Set the purpose
+    pub fn purpose(mut self, purpose: impl Into<String>) -> Self {
+        self.purpose = Some(purpose.into());
+        self
+    }
+
+    /// Whether the icon is declared for `size`, or scales to any size
+    pub fn has_size(&self, size: &str) -> bool {
+        self.sizes
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .any(|s| s.eq_ignore_ascii_case(size) || s == "any")
+    }
+}
+
+/// Application shortcut
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ManifestShortcut {
+    /// Shortcut name
+    pub name: String,
+
+    /// Short name used where space is limited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+
+    /// Shortcut description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// URL opened by the shortcut
+    pub url: String,
+
+    /// Shortcut icons
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icons: Vec<ManifestIcon>,
+}
+
+impl ManifestShortcut {
+    /// Create a shortcut
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Screenshot shown in the install dialog
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ManifestScreenshot {
+    /// Screenshot URL
+    pub src: String,
+
+    /// Space-separated sizes, such as `1280x720`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sizes: Option<String>,
+
+    /// MIME type
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+
+    /// Form factor the screenshot applies to: `wide` or `narrow`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form_factor: Option<String>,
+
+    /// Accessible label
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl ManifestScreenshot {
+    /// Create a screenshot with the given sizes
+    pub fn new(src: impl Into<String>, sizes: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            sizes: Some(sizes.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Registration as a share target
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShareTarget {
+    /// URL that receives the shared data
+    pub action: String,
+
+    /// HTTP method, `GET` (default) or `POST`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+
+    /// Encoding of `POST` requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enctype: Option<String>,
+
+    /// Names of the query or form parameters
+    #[serde(default)]
+    pub params: ShareTargetParams,
+}
+
+/// Parameter names of a share target
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShareTargetParams {
+    /// Parameter for the shared title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Parameter for the shared text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Parameter for the shared URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Form fields for shared files
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub files: Vec<ShareTargetFile>,
+}
+
+/// Form field accepting shared files
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShareTargetFile {
+    /// Form field name
+    pub name: String,
+
+    /// Accepted MIME types or file extensions
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub accept: Vec<String>,
+}
+
+impl WebAppManifest {
+    /// Create a manifest with the given application name
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the short name
+    pub fn short_name(mut self, short_name: impl Into<String>) -> Self {
+        self.short_name = Some(short_name.into());
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the start URL
+    pub fn start_url(mut self, start_url: impl Into<String>) -> Self {
+        self.start_url = Some(start_url.into());
+        self
+    }
+
+    /// Set the navigation scope
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    /// Set the display mode
+    pub fn display(mut self, display: DisplayMode) -> Self {
+        self.display = Some(display);
+        self
+    }
+
+    /// Set the theme color
+    pub fn theme_color(mut self, color: impl Into<String>) -> Self {
+        self.theme_color = Some(color.into());
+        self
+    }
+
+    /// Set the background color
+    pub fn background_color(mut self, color: impl Into<String>) -> Self {
+        self.background_color = Some(color.into());
+        self
+    }
+
+    /// Add an icon
+    pub fn icon(mut self, icon: ManifestIcon) -> Self {
+        self.icons.push(icon);
+        self
+    }
+
+    /// Add a shortcut
+    pub fn shortcut(mut self, shortcut: ManifestShortcut) -> Self {
+        self.shortcuts.push(shortcut);
+        self
+    }
+
+    /// Add a screenshot
+    pub fn screenshot(mut self, screenshot: ManifestScreenshot) -> Self {
+        self.screenshots.push(screenshot);
+        self
+    }
+
+    /// Set the share target
+    pub fn share_target(mut self, share_target: ShareTarget) -> Self {
+        self.share_target = Some(share_target);
+        self
+    }
+
+    /// Serialize the manifest as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::SerializationError(e.to_string()))
+    }
+
+    /// Parse a manifest from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| Error::SerializationError(e.to_string()))
+    }
+
+    /// Read and parse a manifest file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)?;
+        serde_json::from_str(&json)
+            .map_err(|e| Error::SerializationError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Write the manifest to `manifest.webmanifest` in `dir`
+    ///
+    /// Returns the path of the written file.
+    pub fn write_to_dir(&self, dir: impl AsRef<Path>) -> Result<PathBuf> {
+        let path = dir.as_ref().join(MANIFEST_FILE_NAME);
+        std::fs::write(&path, self.to_json()?)?;
+        Ok(path)
+    }
+
+    /// Validate the manifest against the requirements for installation
+    ///
+    /// A name, a start URL, an installable display mode and icons of
+    /// [`REQUIRED_ICON_SIZES`] are required. Missing colors and overlong short
+    /// names are reported as warnings.
+    pub fn validate(&self) -> ValidationResult {
+        let mut result = ValidationResult::new();
+
+        if is_blank(&self.name) && is_blank(&self.short_name) {
+            result.add_error(missing("name", "Manifest needs a name or short_name"));
+        } else if is_blank(&self.short_name) {
+            result.add_warning(ValidationWarning {
+                code: ValidationWarningCode::MissingRecommended,
+                message: "Manifest has no short_name for the home screen".to_string(),
+                field: Some("shortName".to_string()),
+                suggestion: Some("Add a short_name of 12 characters or fewer".to_string()),
+            });
+        }
+
+        if let Some(ref short_name) = self.short_name {
+            if short_name.chars().count() > SHORT_NAME_MAX_LENGTH {
+                result.add_warning(ValidationWarning {
+                    code: ValidationWarningCode::CouldImprove,
+                    message: format!(
+                        "short_name is longer than {} characters and may be truncated",
+                        SHORT_NAME_MAX_LENGTH
+                    ),
+                    field: Some("shortName".to_string()),
+                    suggestion: Some("Shorten the short_name".to_string()),
+                });
+            }
+        }
+
+        if is_blank(&self.start_url) {
+            result.add_error(missing("startUrl", "Manifest needs a start_url"));
+        }
+
+        match self.display {
+            None => result.add_error(missing("display", "Manifest needs a display mode")),
+            Some(display) if !display.is_installable() => result.add_error(ValidationError {
+                code: ValidationErrorCode::InvalidFormat,
+                message: format!("Display mode '{}' is not installable", display),
+                field: Some("display".to_string()),
+                suggestion: Some("Use standalone, fullscreen or minimal-ui".to_string()),
+            }),
+            Some(_) => {}
+        }
+
+        for size in REQUIRED_ICON_SIZES {
+            if !self.icons.iter().any(|icon| icon.has_size(size)) {
+                result.add_error(missing("icons", &format!("Manifest needs a {} icon", size)));
+            }
+        }
+
+        let icons = self
+            .icons
+            .iter()
+            .enumerate()
+            .map(|(i, icon)| (format!("icons[{}]", i), icon));
+        let shortcut_icons = self.shortcuts.iter().enumerate().flat_map(|(i, shortcut)| {
+            shortcut
+                .icons
+                .iter()
+                .enumerate()
+                .map(move |(j, icon)| (format!("shortcuts[{}].icons[{}]", i, j), icon))
+        });
+        for (field, icon) in icons.chain(shortcut_icons) {
+            if icon.src.trim().is_empty() {
+                result.add_error(missing(&format!("{}.src", field), "Icon needs a src"));
+            }
+        }
+
+        for (i, shortcut) in self.shortcuts.iter().enumerate() {
+            if shortcut.name.trim().is_empty() {
+                result.add_error(missing(
+                    &format!("shortcuts[{}].name", i),
+                    "Shortcut needs a name",
+                ));
+            }
+            if shortcut.url.trim().is_empty() {
+                result.add_error(missing(
+                    &format!("shortcuts[{}].url", i),
+                    "Shortcut needs a url",
+                ));
+            }
+        }
+
+        for (i, screenshot) in self.screenshots.iter().enumerate() {
+            if screenshot.src.trim().is_empty() {
+                result.add_error(missing(
+                    &format!("screenshots[{}].src", i),
+                    "Screenshot needs a src",
+                ));
+            }
+        }
+
+        if let Some(ref share_target) = self.share_target {
+            validate_share_target(share_target, &mut result);
+        }
+
+        for (field, color) in [
+            ("themeColor", &self.theme_color),
+            ("backgroundColor", &self.background_color),
+        ] {
+            if is_blank(color) {
+                result.add_warning(ValidationWarning {
+                    code: ValidationWarningCode::MissingRecommended,
+                    message: format!("Manifest has no {}", field),
+                    field: Some(field.to_string()),
+                    suggestion: Some(
+                        "Set a color to match the splash screen and window".to_string(),
+                    ),
+                });
+            }
+        }
+
+        result
+    }
+}
+
+fn validate_share_target(share_target: &ShareTarget, result: &mut ValidationResult) {
+    if share_target.action.trim().is_empty() {
+        result.add_error(missing(
+            "shareTarget.action",
+            "Share target needs an action",
+        ));
+    }
+
+    if share_target.params.files.is_empty() {
+        return;
+    }
+
+    let is_post = share_target
+        .method
+        .as_deref()
+        .is_some_and(|method| method.eq_ignore_ascii_case("POST"));
+    let is_multipart = share_target.enctype.as_deref() == Some("multipart/form-data");
+    if !is_post || !is_multipart {
+        result.add_error(ValidationError {
+            code: ValidationErrorCode::InvalidFormat,
+            message: "Sharing files requires a POST share target with multipart/form-data"
+                .to_string(),
+            field: Some("shareTarget.method".to_string()),
+            suggestion: Some("Set method to POST and enctype to multipart/form-data".to_string()),
+        });
+    }
+}
+
+fn is_blank(value: &Option<String>) -> bool {
+    value.as_deref().map_or(true, |value| value.trim().is_empty())
+}
+
+fn missing(field: &str, message: &str) -> ValidationError {
+    ValidationError {
+        code: ValidationErrorCode::MissingRequired,
+        message: message.to_string(),
+        field: Some(field.to_string()),
+        suggestion: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installable() -> WebAppManifest {
+        WebAppManifest::new("Acme Dashboard")
+            .short_name("Acme")
+            .start_url("/")
+            .display(DisplayMode::Standalone)
+            .theme_color("#0f172a")
+            .background_color("#ffffff")
+            .icon(ManifestIcon::new("/icon-192.png", "192x192").type_("image/png"))
+            .icon(ManifestIcon::new("/icon-512.png", "512x512").purpose("any maskable"))
+    }
+
+    #[test]
+    fn test_serializes_spec_member_names() {
+        let manifest = installable()
+            .shortcut(ManifestShortcut::new("New invoice", "/invoices/new"))
+            .share_target(ShareTarget {
+                action: "/share".to_string(),
+                params: ShareTargetParams {
+                    url: Some("link".to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+
+        let json: serde_json::Value = serde_json::from_str(&manifest.to_json().unwrap()).unwrap();
+        assert_eq!(json["short_name"], "Acme");
+        assert_eq!(json["start_url"], "/");
+        assert_eq!(json["display"], "standalone");
+        assert_eq!(json["background_color"], "#ffffff");
+        assert_eq!(json["icons"][0]["type"], "image/png");
+        assert_eq!(json["shortcuts"][0]["url"], "/invoices/new");
+        assert_eq!(json["share_target"]["params"]["url"], "link");
+        assert!(json.get("screenshots").is_none());
+        assert!(json.get("description").is_none());
+    }
+
+    #[test]
+    fn test_parse_keeps_unknown_members() {
+        let manifest = WebAppManifest::from_json(
+            r#"{
+                "name": "Acme",
+                "display": "minimal-ui",
+                "display_override": ["window-controls-overlay"],
+                "prefer_related_applications": false,
+                "share_target": {
+                    "action": "/share",
+                    "method": "POST",
+                    "enctype": "multipart/form-data",
+                    "params": { "files": { "name": "media", "accept": "image/*" } }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.display, Some(DisplayMode::MinimalUi));
+        assert_eq!(
+            manifest.display_override,
+            vec![DisplayMode::WindowControlsOverlay]
+        );
+        let files = &manifest.share_target.as_ref().unwrap().params.files;
+        assert_eq!(files[0].accept, vec!["image/*"]);
+        assert_eq!(
+            manifest.other.get("prefer_related_applications"),
+            Some(&serde_json::Value::Bool(false))
+        );
+
+        let round_trip = WebAppManifest::from_json(&manifest.to_json().unwrap()).unwrap();
+        assert_eq!(round_trip, manifest);
+
+        assert!(WebAppManifest::from_json("{").is_err());
+    }
+
+    #[test]
+    fn test_write_and_read_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = installable();
+
+        let path = manifest.write_to_dir(dir.path()).unwrap();
+        assert_eq!(path.file_name().unwrap(), MANIFEST_FILE_NAME);
+        assert_eq!(WebAppManifest::from_file(&path).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_validate_installable() {
+        let result = installable().validate();
+        assert!(result.is_valid(), "{:?}", result.errors);
+        assert!(!result.has_warnings());
+    }
+
+    #[test]
+    fn test_validate_required_fields() {
+        let result = WebAppManifest::default()
+            .display(DisplayMode::Browser)
+            .icon(ManifestIcon::new("/icon.svg", "any"))
+            .validate();
+
+        let fields: Vec<_> = result
+            .errors
+            .iter()
+            .filter_map(|e| e.field.as_deref())
+            .collect();
+        assert_eq!(fields, vec!["name", "startUrl", "display"]);
+
+        let result = WebAppManifest::new("Acme")
+            .start_url("/")
+            .display(DisplayMode::Standalone)
+            .icon(ManifestIcon::new("/icon-192.png", "192x192"))
+            .validate();
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.contains("512x512"));
+    }
+
+    #[test]
+    fn test_validate_warnings_and_share_target() {
+        let mut manifest = installable().short_name("Acme Dashboard Pro");
+        manifest.theme_color = None;
+        let result = manifest.validate();
+        assert!(result.is_valid());
+        let fields: Vec<_> = result
+            .warnings
+            .iter()
+            .filter_map(|w| w.field.as_deref())
+            .collect();
+        assert_eq!(fields, vec!["shortName", "themeColor"]);
+
+        let result = installable()
+            .share_target(ShareTarget {
+                action: "/share".to_string(),
+                params: ShareTargetParams {
+                    files: vec![ShareTargetFile {
+                        name: "media".to_string(),
+                        accept: vec!["image/*".to_string()],
+                    }],
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .validate();
+        assert_eq!(
+            result.errors[0].field.as_deref(),
+            Some("shareTarget.method")
+        );
+    }
+}
@@ -1,29 +1,93 @@
+use crate::metadata::context::HEAD_MARKER;
 use leptos::prelude::*;
+use leptos::tachys::{
+    html::attribute::any_attribute::AnyAttribute,
+    hydration::Cursor,
+    view::{Position, PositionState, Render, RenderHtml},
+};
 
 /// A component that injects meta tags into the document head during server-side rendering.
 ///
-/// This component is essential for SSR applications as it ensures that all metadata
-/// added by other components (Title, Meta, Link, etc.) is properly injected into
-/// the HTML head section.
+/// Components register their metadata with [`register_metadata`](crate::metadata::context::register_metadata),
+/// and `<MetaTags/>` marks the place in the shell where the merged result belongs.
+/// After rendering, the server replaces the marker with the actual tags using
+/// [`MetadataContext::inject_head`](crate::metadata::context::MetadataContext::inject_head)
+/// or [`MetadataContext::inject_head_stream`](crate::metadata::context::MetadataContext::inject_head_stream).
+///
+/// This component belongs in the application shell, which is only rendered on the server.
 ///
 /// # Example
 ///
 /// ```rust
 /// use leptos::prelude::*;
-/// use leptos_meta::{Title, Meta, provide_meta_context};
-/// use leptos_next_metadata::prelude::MetaTags;
+/// use leptos_next_metadata::prelude::*;
+///
+/// #[component]
+/// fn Shell() -> impl IntoView {
+///     provide_metadata_context();
+///     view! {
+///         <head><MetaTags /></head>
+///         <body><Page /></body>
+///     }
+/// }
 ///
 /// #[component]
-/// fn App() -> impl IntoView {
-///     provide_meta_context();
-///     view! { <MetaTags /> }
+/// fn Page() -> impl IntoView {
+///     register_metadata(Metadata::with_title("My Page"));
+///     view! { <h1>"My Page"</h1> }
 /// }
 /// ```
 #[component]
 pub fn MetaTags() -> impl IntoView {
-    // Simple MetaTags component - just a placeholder for now
-    view! {
-        <></>
+    MetaTagsView
+}
+
+/// View that writes the head marker during server-side rendering
+#[derive(Debug)]
+struct MetaTagsView;
+
+impl Render for MetaTagsView {
+    type State = ();
+
+    fn build(self) -> Self::State {}
+
+    fn rebuild(self, _state: &mut Self::State) {}
+}
+
+leptos::tachys::no_attrs!(MetaTagsView);
+
+impl RenderHtml for MetaTagsView {
+    type AsyncOutput = Self;
+    type Owned = Self;
+
+    const MIN_LENGTH: usize = HEAD_MARKER.len();
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn to_html_with_buf(
+        self,
+        buf: &mut String,
+        _position: &mut Position,
+        _escape: bool,
+        _mark_branches: bool,
+        _extra_attrs: Vec<AnyAttribute>,
+    ) {
+        buf.push_str(HEAD_MARKER);
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(
+        self,
+        _cursor: &Cursor,
+        _position: &PositionState,
+    ) -> Self::State {
+    }
+
+    fn into_owned(self) -> Self::Owned {
+        self
     }
 }
 
@@ -36,4 +100,35 @@ mod tests {
 
         // Test passes if component compiles
     }
+
+    #[test]
+    fn test_meta_tags_renders_collected_metadata() {
+        use crate::metadata::context::{provide_metadata_context, register_metadata};
+        use crate::metadata::Metadata;
+        use leptos::prelude::*;
+
+        #[component]
+        fn Page() -> impl IntoView {
+            register_metadata(Metadata::with_title("Nested Page"));
+            view! { <h1>"Page"</h1> }
+        }
+
+        let owner = Owner::new();
+        let html = owner.with(|| {
+            let context = provide_metadata_context();
+            register_metadata(Metadata::default().description("Site description"));
+
+            let html = view! {
+                <head><crate::MetaTags /></head>
+                <body><Page /></body>
+            }
+            .to_html();
+
+            context.inject_head(&html)
+        });
+
+        assert!(html.contains("<head><title>Nested Page</title>"));
+        assert!(html.contains(r#"<meta name="description" content="Site description">"#));
+        assert!(!html.contains(crate::metadata::context::HEAD_MARKER));
+    }
 }
@@ -4,7 +4,6 @@
 //! metadata objects with a fluent API.
 
 use super::types::*;
-use std::collections::HashMap;
 
 impl Metadata {
     /// Create a new empty metadata instance
@@ -80,12 +79,76 @@ impl Metadata {
         self
     }
 
+    /// Set the base URL used to resolve relative URLs
+    pub fn metadata_base(mut self, metadata_base: url::Url) -> Self {
+        self.metadata_base = Some(metadata_base);
+        self
+    }
+
+    /// Set the icons
+    pub fn icons(mut self, icons: Icons) -> Self {
+        self.icons = Some(icons);
+        self
+    }
+
+    /// Set the web app manifest URL
+    pub fn manifest(mut self, manifest: impl Into<String>) -> Self {
+        self.manifest = Some(manifest.into());
+        self
+    }
+
+    /// Set the site verification tokens
+    pub fn verification(mut self, verification: Verification) -> Self {
+        self.verification = Some(verification);
+        self
+    }
+
+    /// Set the Apple web app settings
+    pub fn apple_web_app(mut self, apple_web_app: AppleWebApp) -> Self {
+        self.apple_web_app = Some(apple_web_app);
+        self
+    }
+
+    /// Set the iTunes Smart App Banner
+    pub fn itunes(mut self, itunes: Itunes) -> Self {
+        self.itunes = Some(itunes);
+        self
+    }
+
+    /// Set the App Links
+    pub fn app_links(mut self, app_links: AppLinks) -> Self {
+        self.app_links = Some(app_links);
+        self
+    }
+
     /// Set the canonical URL
     pub fn canonical(mut self, canonical: impl Into<String>) -> Self {
         self.canonical = Some(canonical.into());
         self
     }
 
+    /// Set the document language, such as `de-AT`
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Set the canonical URL and alternate versions of the page
+    pub fn alternates(mut self, alternates: Alternates) -> Self {
+        self.alternates = Some(alternates);
+        self
+    }
+
+    /// The canonical URL in effect
+    ///
+    /// `alternates.canonical` takes precedence over `canonical` when both are set.
+    pub fn canonical_url(&self) -> Option<&str> {
+        self.alternates
+            .as_ref()
+            .and_then(|alternates| alternates.canonical.as_deref())
+            .or(self.canonical.as_deref())
+    }
+
     /// Add an alternate language link
     pub fn alternate(mut self, hreflang: impl Into<String>, href: impl Into<String>) -> Self {
         let alternate = AlternateLink {
@@ -107,7 +170,7 @@ impl Metadata {
     }
 
     /// Set the theme color
-    pub fn theme_color(mut self, theme_color: impl Into<String>) -> Self {
+    pub fn theme_color(mut self, theme_color: impl Into<ThemeColor>) -> Self {
         self.theme_color = Some(theme_color.into());
         self
     }
@@ -137,14 +200,78 @@ impl Metadata {
     }
 }
 
+impl Viewport {
+    /// Create the default `width=device-width, initial-scale=1` viewport
+    pub fn device_width() -> Self {
+        Self {
+            width: Some(ViewportWidth::DeviceWidth),
+            initial_scale: Some(1.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl ThemeColor {
+    /// Create a theme color for all media
+    pub fn new(color: impl Into<String>) -> Self {
+        Self {
+            color: color.into(),
+            media: None,
+        }
+    }
+
+    /// Create a theme color for light mode
+    pub fn light(color: impl Into<String>) -> Self {
+        Self::new(color).media("(prefers-color-scheme: light)")
+    }
+
+    /// Create a theme color for dark mode
+    pub fn dark(color: impl Into<String>) -> Self {
+        Self::new(color).media("(prefers-color-scheme: dark)")
+    }
+
+    /// Limit the color to a media query
+    pub fn media(mut self, media: impl Into<String>) -> Self {
+        self.media = Some(media.into());
+        self
+    }
+}
+
+impl ViewportConfig {
+    /// Create a config with the default `width=device-width, initial-scale=1` viewport
+    pub fn new() -> Self {
+        Self {
+            viewport: Viewport::device_width(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the viewport
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Add a theme color
+    pub fn theme_color(mut self, theme_color: impl Into<ThemeColor>) -> Self {
+        self.theme_color.push(theme_color.into());
+        self
+    }
+
+    /// Set the color scheme
+    pub fn color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme = Some(color_scheme);
+        self
+    }
+}
+
 impl Robots {
     /// Create robots directive that allows all
     pub fn all() -> Self {
         Self {
             index: Some(true),
             follow: Some(true),
-            google_bot: None,
-            other: HashMap::new(),
+            ..Default::default()
         }
     }
 
@@ -153,8 +280,7 @@ impl Robots {
         Self {
             index: Some(false),
             follow: Some(false),
-            google_bot: None,
-            other: HashMap::new(),
+            ..Default::default()
         }
     }
 
@@ -163,10 +289,237 @@ impl Robots {
         Self {
             index: Some(false),
             follow: Some(true),
-            google_bot: None,
-            other: HashMap::new(),
+            ..Default::default()
         }
     }
+
+    /// Set the directives for a crawler, such as `bingbot`
+    pub fn bot(mut self, user_agent: impl Into<String>, directives: BotDirectives) -> Self {
+        self.bots.insert(user_agent.into(), directives);
+        self
+    }
+}
+
+impl BotDirectives {
+    /// Create crawler directives that allow all
+    pub fn all() -> Self {
+        Self {
+            index: Some(true),
+            follow: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Create crawler directives that block all
+    pub fn none() -> Self {
+        Self {
+            index: Some(false),
+            follow: Some(false),
+            ..Default::default()
+        }
+    }
+
+    /// Create crawler directives that block indexing
+    pub fn noindex() -> Self {
+        Self {
+            index: Some(false),
+            ..Default::default()
+        }
+    }
+}
+
+impl Icons {
+    /// Add a standard icon
+    pub fn icon(mut self, icon: impl Into<IconDescriptor>) -> Self {
+        self.icon.push(icon.into());
+        self
+    }
+
+    /// Add an Apple touch icon
+    pub fn apple(mut self, icon: impl Into<IconDescriptor>) -> Self {
+        self.apple.push(icon.into());
+        self
+    }
+
+    /// Add a shortcut icon
+    pub fn shortcut(mut self, icon: impl Into<IconDescriptor>) -> Self {
+        self.shortcut.push(icon.into());
+        self
+    }
+
+    /// Add another icon link
+    pub fn other(mut self, icon: impl Into<IconDescriptor>) -> Self {
+        self.other.push(icon.into());
+        self
+    }
+
+    /// Whether no icons are set
+    pub fn is_empty(&self) -> bool {
+        self.icon.is_empty()
+            && self.apple.is_empty()
+            && self.shortcut.is_empty()
+            && self.other.is_empty()
+    }
+}
+
+impl Alternates {
+    /// Language tag of the fallback page for unmatched languages
+    pub const X_DEFAULT: &'static str = "x-default";
+
+    /// Create alternates for every locale from a path pattern
+    ///
+    /// Each `{locale}` in `pattern` is replaced by the locale, so
+    /// `/{locale}/about` yields `/en/about`, `/de/about` and so on.
+    pub fn locales<I, S>(pattern: &str, locales: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let languages = locales
+            .into_iter()
+            .map(|locale| {
+                let locale = locale.into();
+                let url = pattern.replace("{locale}", &locale);
+                (locale, url)
+            })
+            .collect();
+
+        Self {
+            languages,
+            ..Default::default()
+        }
+    }
+
+    /// Set the canonical URL
+    pub fn canonical(mut self, canonical: impl Into<String>) -> Self {
+        self.canonical = Some(canonical.into());
+        self
+    }
+
+    /// Add a translated page
+    pub fn language(mut self, language: impl Into<String>, url: impl Into<String>) -> Self {
+        self.languages.insert(language.into(), url.into());
+        self
+    }
+
+    /// Set the page for languages without a translation (`x-default`)
+    pub fn x_default(self, url: impl Into<String>) -> Self {
+        self.language(Self::X_DEFAULT, url)
+    }
+
+    /// Add a version of the page for a media query
+    pub fn media(mut self, media: impl Into<String>, url: impl Into<String>) -> Self {
+        self.media.insert(media.into(), url.into());
+        self
+    }
+
+    /// Add a representation of the page with the given MIME type
+    pub fn type_(mut self, mime_type: impl Into<String>, alternate: AlternateDescriptor) -> Self {
+        self.types
+            .entry(mime_type.into())
+            .or_default()
+            .push(alternate);
+        self
+    }
+
+    /// Add a feed, such as `application/rss+xml` or `application/atom+xml`
+    pub fn feed(
+        self,
+        mime_type: impl Into<String>,
+        url: impl Into<String>,
+        title: Option<&str>,
+    ) -> Self {
+        self.type_(
+            mime_type,
+            AlternateDescriptor {
+                url: url.into(),
+                title: title.map(str::to_string),
+            },
+        )
+    }
+
+    /// Whether no canonical URL or alternates are set
+    pub fn is_empty(&self) -> bool {
+        self.canonical.is_none()
+            && self.languages.is_empty()
+            && self.media.is_empty()
+            && self.types.is_empty()
+    }
+}
+
+impl Verification {
+    /// Add a Google Search Console token
+    pub fn google(mut self, token: impl Into<String>) -> Self {
+        self.google.push(token.into());
+        self
+    }
+
+    /// Add a Yandex Webmaster token
+    pub fn yandex(mut self, token: impl Into<String>) -> Self {
+        self.yandex.push(token.into());
+        self
+    }
+
+    /// Add a Yahoo token
+    pub fn yahoo(mut self, token: impl Into<String>) -> Self {
+        self.yahoo.push(token.into());
+        self
+    }
+
+    /// Add an identity link
+    pub fn me(mut self, me: impl Into<String>) -> Self {
+        self.me.push(me.into());
+        self
+    }
+
+    /// Add a token for another service
+    pub fn other(mut self, name: impl Into<String>, token: impl Into<String>) -> Self {
+        self.other
+            .entry(name.into())
+            .or_default()
+            .push(token.into());
+        self
+    }
+}
+
+impl Itunes {
+    /// Create a Smart App Banner for an App Store app
+    pub fn new(app_id: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+            app_argument: None,
+        }
+    }
+}
+
+impl IconDescriptor {
+    /// Create a new icon with URL
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl OpenGraph {
+    /// Add an image
+    pub fn image(mut self, image: impl Into<OgImage>) -> Self {
+        self.images.push(image.into());
+        self
+    }
+
+    /// Add a video
+    pub fn video(mut self, video: impl Into<OgVideo>) -> Self {
+        self.videos.push(video.into());
+        self
+    }
+
+    /// Add an audio file
+    pub fn audio(mut self, audio: impl Into<OgAudio>) -> Self {
+        self.audio.push(audio.into());
+        self
+    }
 }
 
 impl OgImage {
@@ -174,10 +527,7 @@ impl OgImage {
     pub fn new(url: &str) -> Self {
         Self {
             url: url.to_string(),
-            width: None,
-            height: None,
-            alt: None,
-            type_: None,
+            ..Default::default()
         }
     }
 
@@ -187,8 +537,27 @@ impl OgImage {
             url: url.to_string(),
             width: Some(width),
             height: Some(height),
-            alt: None,
-            type_: None,
+            ..Default::default()
+        }
+    }
+}
+
+impl OgVideo {
+    /// Create a new OG video with URL
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl OgAudio {
+    /// Create a new OG audio file with URL
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            ..Default::default()
         }
     }
 }
@@ -1,11 +1,27 @@
 //! Leptos context integration for metadata management
 //!
 //! This module provides context management for sharing metadata across the component tree.
+//!
+//! During server-side rendering, components register their [`Metadata`] with the
+//! context and the [`MetaTags`](crate::meta_tags::MetaTags) component marks where
+//! the merged result belongs in the document head. Once the page has rendered,
+//! the server replaces that marker with the rendered tags using
+//! [`MetadataContext::inject_head`] or [`MetadataContext::inject_head_stream`].
+//! Metadata that resolves asynchronously is covered in [`streaming`](super::streaming).
 
+use crate::metadata::i18n::set_html_lang;
+use crate::metadata::merge::MergeConfig;
+use crate::metadata::registry::MetadataRegistry;
+use crate::metadata::streaming::{HeadStreaming, PendingMetadata, HEAD_END_MARKER};
 use crate::{Metadata, MetadataConfig};
-use parking_lot::RwLock;
+use futures::{Stream, StreamExt};
+use leptos::prelude::{provide_context, use_context};
+use parking_lot::{Mutex, RwLock};
 use std::sync::Arc;
 
+/// Marker written by `<MetaTags/>` during SSR and replaced with the rendered head tags
+pub const HEAD_MARKER: &str = "<!--leptos-next-metadata-->";
+
 /// Metadata context that holds the current metadata state
 #[derive(Clone)]
 pub struct MetadataContext {
@@ -17,6 +33,21 @@ pub struct MetadataContext {
 
     /// Parent metadata context (for nested contexts)
     pub parent: Option<Arc<MetadataContext>>,
+
+    /// Deep merge configuration; `None` uses the shallow Next.js-style merge
+    pub merge_config: Option<MergeConfig>,
+
+    /// Route metadata registry used by `load_route`
+    pub registry: Option<Arc<MetadataRegistry>>,
+
+    /// Metadata layers loaded from the registry for the current route
+    pub route_layers: Arc<RwLock<Vec<Metadata>>>,
+
+    /// How streamed responses handle pending async metadata
+    pub head_streaming: Arc<RwLock<HeadStreaming>>,
+
+    /// Async metadata that has not resolved yet
+    pub(crate) pending_metadata: Arc<Mutex<Vec<PendingMetadata>>>,
 }
 
 impl MetadataContext {
@@ -26,6 +57,11 @@ impl MetadataContext {
             config: Arc::new(MetadataConfig::default()),
             metadata_stack: Arc::new(RwLock::new(Vec::new())),
             parent: None,
+            merge_config: None,
+            registry: None,
+            route_layers: Arc::new(RwLock::new(Vec::new())),
+            head_streaming: Arc::new(RwLock::new(HeadStreaming::default())),
+            pending_metadata: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -35,6 +71,11 @@ impl MetadataContext {
             config: Arc::new(config),
             metadata_stack: Arc::new(RwLock::new(Vec::new())),
             parent: None,
+            merge_config: None,
+            registry: None,
+            route_layers: Arc::new(RwLock::new(Vec::new())),
+            head_streaming: Arc::new(RwLock::new(HeadStreaming::default())),
+            pending_metadata: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -43,10 +84,21 @@ impl MetadataContext {
         Self {
             config: parent.config.clone(),
             metadata_stack: Arc::new(RwLock::new(Vec::new())),
+            merge_config: parent.merge_config.clone(),
+            registry: None,
+            route_layers: Arc::new(RwLock::new(Vec::new())),
+            head_streaming: Arc::new(RwLock::new(parent.head_streaming())),
+            pending_metadata: Arc::new(Mutex::new(Vec::new())),
             parent: Some(parent),
         }
     }
 
+    /// Merge metadata layers field by field using the given merge configuration
+    pub fn with_merge_config(mut self, merge_config: MergeConfig) -> Self {
+        self.merge_config = Some(merge_config);
+        self
+    }
+
     /// Add metadata to the current context
     pub fn push_metadata(&self, metadata: Metadata) {
         let mut stack = self.metadata_stack.write();
@@ -60,27 +112,122 @@ impl MetadataContext {
     }
 
     /// Get the merged metadata from this context and all parent contexts
+    ///
+    /// Title templates are resolved across every level, including levels that
+    /// belong to parent contexts. Relative URLs are resolved against the
+    /// configured `metadata_base` unless a level sets its own.
     pub fn get_merged_metadata(&self) -> Metadata {
-        let mut result = Metadata::default();
-
-        // Start with parent metadata if it exists
-        if let Some(ref parent) = self.parent {
-            result = crate::metadata::merge::merge_metadata(result, parent.get_merged_metadata());
+        match self.merge_config {
+            Some(ref config) => {
+                crate::metadata::merge::merge_metadata_chain_with(self.layers(), config)
+            }
+            None => crate::metadata::merge::merge_metadata_chain(self.layers()),
         }
+    }
 
-        // Apply metadata from this context's stack
-        let stack = self.metadata_stack.read();
-        for metadata in stack.iter() {
-            result = crate::metadata::merge::merge_metadata(result, metadata.clone());
-        }
+    /// Collect the metadata layers from the outermost parent context to this one
+    fn layers(&self) -> Vec<Metadata> {
+        let mut layers = self.base_layers();
+        layers.extend(self.route_layers.read().iter().cloned());
+        layers.extend(self.metadata_stack.read().iter().cloned());
+        layers
+    }
 
-        result
+    /// Layers above this context's own: the configured defaults or the
+    /// layers of the parent contexts
+    pub(crate) fn base_layers(&self) -> Vec<Metadata> {
+        match self.parent {
+            Some(ref parent) => parent.layers(),
+            None => vec![Metadata {
+                metadata_base: self.config.metadata_base.clone(),
+                ..Default::default()
+            }],
+        }
     }
 
     /// Update the configuration for this context
     pub fn update_config(&mut self, config: MetadataConfig) {
         self.config = Arc::new(config);
     }
+
+    /// Render the merged metadata into `<head>` HTML
+    pub fn render_head(&self) -> String {
+        self.get_merged_metadata().to_head_html()
+    }
+
+    /// ETag of the merged metadata, for responses whose head depends on it
+    pub fn etag(&self) -> String {
+        self.get_merged_metadata().etag()
+    }
+
+    /// Replace the `<MetaTags/>` marker in a rendered HTML document with the head tags
+    ///
+    /// Call this after the whole page has been rendered, so that metadata from
+    /// every component has been registered. When the metadata sets
+    /// [`Metadata::lang`], it becomes the `lang` attribute of `<html>`. HTML
+    /// without a marker is returned unchanged.
+    pub fn inject_head(&self, html: &str) -> String {
+        self.inject_head_with(html, |head| head)
+    }
+
+    /// Inject the head tags, wrapped by `wrap`, and the document language
+    fn inject_head_with(&self, html: &str, wrap: impl FnOnce(String) -> String) -> String {
+        if !html.contains(HEAD_MARKER) {
+            return html.to_string();
+        }
+
+        let metadata = self.get_merged_metadata();
+        let html = html.replacen(HEAD_MARKER, &wrap(metadata.to_head_html()), 1);
+        match metadata.lang {
+            Some(ref lang) => set_html_lang(&html, lang),
+            None => html,
+        }
+    }
+
+    /// Replace the `<MetaTags/>` marker in a streamed HTML response
+    ///
+    /// Leptos renders the shell together with all synchronous content into the
+    /// first chunk of a stream, so the head tags are injected into that chunk and
+    /// the remaining chunks are passed through untouched. Async metadata still
+    /// pending at that point is handled according to the
+    /// [`HeadStreaming`] mode.
+    pub fn inject_head_stream<S>(self, stream: S) -> impl Stream<Item = String> + Send
+    where
+        S: Stream<Item = String> + Send + 'static,
+    {
+        let mut stream = Box::pin(stream);
+
+        futures::stream::once(async move {
+            let first = stream.next().await;
+            if !self.has_pending_metadata() {
+                let first = first.map(|chunk| self.inject_head(&chunk));
+                return futures::stream::iter(first).chain(stream).boxed();
+            }
+
+            match self.head_streaming() {
+                HeadStreaming::Block => {
+                    self.resolve_pending_metadata_timeout().await;
+                    let first = first.map(|chunk| self.inject_head(&chunk));
+                    futures::stream::iter(first).chain(stream).boxed()
+                }
+                HeadStreaming::Patch => {
+                    let first = first.map(|chunk| {
+                        self.inject_head_with(&chunk, |head| {
+                            format!("{}{}{}", HEAD_MARKER, head, HEAD_END_MARKER)
+                        })
+                    });
+                    let patch = futures::stream::once(async move {
+                        self.resolve_pending_metadata_timeout().await;
+                        self.head_patch_script()
+                    });
+                    futures::stream::iter(first)
+                        .chain(futures::stream::select(stream, patch))
+                        .boxed()
+                }
+            }
+        })
+        .flatten()
+    }
 }
 
 impl Default for MetadataContext {
@@ -89,12 +236,64 @@ impl Default for MetadataContext {
     }
 }
 
-/// Simplified metadata provider function (without component integration for now)
+/// Create a metadata context and register it with the current Leptos owner
+///
+/// Call this once near the root of the application, usually in the shell
+/// component that renders `<MetaTags/>`. The returned handle can be used on the
+/// server to inject the collected metadata into the rendered HTML.
 pub fn provide_metadata_context() -> MetadataContext {
-    MetadataContext::new()
+    let context = MetadataContext::new();
+    provide_context(context.clone());
+    context
+}
+
+/// Create a metadata context with custom configuration and register it with Leptos
+pub fn provide_metadata_context_with_config(config: MetadataConfig) -> MetadataContext {
+    let context = MetadataContext::with_config(config);
+    provide_context(context.clone());
+    context
 }
 
-/// Simple provider component - placeholder for future Leptos integration
+/// Get the metadata context provided by an ancestor component, if any
+pub fn use_metadata_context() -> Option<MetadataContext> {
+    use_context::<MetadataContext>()
+}
+
+/// Register metadata for the current component with the nearest metadata context
+///
+/// Metadata registered later (deeper in the tree) overrides earlier metadata
+/// when the context merges it.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos_next_metadata::prelude::*;
+///
+/// #[component]
+/// fn BlogPost() -> impl IntoView {
+///     register_metadata(Metadata::with_title_and_description(
+///         "My Blog Post",
+///         "A post about metadata",
+///     ));
+///
+///     view! { <article>"..."</article> }
+/// }
+/// ```
+pub fn register_metadata(metadata: Metadata) {
+    match use_metadata_context() {
+        Some(context) => context.push_metadata(metadata),
+        None => leptos::logging::debug_warn!(
+            "register_metadata called without a metadata context; call \
+             provide_metadata_context() in an ancestor component"
+        ),
+    }
+}
+
+/// Holds a metadata context created outside of the component tree
+///
+/// This is useful on the server, where the context is created per request
+/// before rendering and later used to inject the head tags.
 pub struct MetadataProvider {
     context: MetadataContext,
 }
@@ -123,4 +322,121 @@ impl MetadataProvider {
     pub fn get_context(&self) -> &MetadataContext {
         &self.context
     }
+
+    /// Register the held context with the current Leptos owner
+    pub fn provide(&self) -> MetadataContext {
+        provide_context(self.context.clone());
+        self.context.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::prelude::Owner;
+
+    #[test]
+    fn test_provide_and_register_metadata() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let context = provide_metadata_context();
+            register_metadata(Metadata::with_title("Layout"));
+            register_metadata(Metadata::default().description("Page description"));
+
+            let merged = use_metadata_context().unwrap().get_merged_metadata();
+            assert_eq!(
+                merged.title,
+                Some(crate::metadata::Title::Static("Layout".into()))
+            );
+            assert_eq!(merged.description.as_deref(), Some("Page description"));
+            assert_eq!(context.metadata_stack.read().len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_title_template_resolves_across_parent_context() {
+        let parent = Arc::new(MetadataContext::new());
+        parent.push_metadata(Metadata::default().title(crate::metadata::Title::Template {
+            template: "%s | Acme".into(),
+            default: "Acme".into(),
+        }));
+
+        let child = MetadataContext::with_parent(parent.clone());
+        assert_eq!(
+            child.get_merged_metadata().title,
+            Some(crate::metadata::Title::Static("Acme".into()))
+        );
+
+        child.push_metadata(Metadata::with_title("Pricing"));
+        assert_eq!(
+            child.get_merged_metadata().title,
+            Some(crate::metadata::Title::Static("Pricing | Acme".into()))
+        );
+    }
+
+    #[test]
+    fn test_inject_head() {
+        let context = MetadataContext::new();
+        context.push_metadata(Metadata::with_title("Injected"));
+
+        let html = format!("<html><head>{}</head><body></body></html>", HEAD_MARKER);
+        let injected = context.inject_head(&html);
+
+        assert_eq!(
+            injected,
+            "<html><head><title>Injected</title>\n</head><body></body></html>"
+        );
+        assert_eq!(context.inject_head("<p>no marker</p>"), "<p>no marker</p>");
+    }
+
+    #[test]
+    fn test_inject_head_stream_only_touches_first_chunk() {
+        let context = MetadataContext::new();
+        context.push_metadata(Metadata::with_title("Streamed"));
+
+        let chunks = vec![
+            format!("<head>{}</head><body>", HEAD_MARKER),
+            format!("<p>{}</p></body>", HEAD_MARKER),
+        ];
+        let output: Vec<String> = futures::executor::block_on(
+            context
+                .inject_head_stream(futures::stream::iter(chunks))
+                .collect(),
+        );
+
+        assert_eq!(output[0], "<head><title>Streamed</title>\n</head><body>");
+        assert_eq!(output[1], format!("<p>{}</p></body>", HEAD_MARKER));
+    }
+
+    #[test]
+    fn test_context_with_merge_config() {
+        let parent = Arc::new(MetadataContext::new().with_merge_config(MergeConfig::default()));
+        parent.push_metadata(Metadata::default().open_graph(crate::metadata::OpenGraph {
+            site_name: Some("Acme".into()),
+            ..Default::default()
+        }));
+
+        let child = MetadataContext::with_parent(parent);
+        child.push_metadata(Metadata::default().open_graph(crate::metadata::OpenGraph {
+            title: Some("About".into()),
+            ..Default::default()
+        }));
+
+        let og = child.get_merged_metadata().open_graph.unwrap();
+        assert_eq!(og.site_name.as_deref(), Some("Acme"));
+        assert_eq!(og.title.as_deref(), Some("About"));
+    }
+
+    #[test]
+    fn test_config_metadata_base_resolves_relative_urls() {
+        let context = MetadataContext::with_config(MetadataConfig {
+            metadata_base: Some("https://acme.com".parse().unwrap()),
+            ..Default::default()
+        });
+        context.push_metadata(Metadata::default().canonical("/about"));
+
+        assert!(context
+            .render_head()
+            .contains(r#"<link rel="canonical" href="https://acme.com/about">"#));
+    }
 }
@@ -0,0 +1,292 @@
+//! Structural diffs between metadata values
+//!
+//! [`MetadataDiff`] compares two [`Metadata`] values field by field, including
+//! nested Open Graph, Twitter, alternates and JSON-LD data, and reports each
+//! difference under a dotted path such as `open_graph.images[0].width`. Use it
+//! to review what a deploy changes or to make snapshot test failures readable.
+
+use super::types::Metadata;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Differences between two metadata values
+///
+/// Paths follow the serialized field names. Map keys containing `.` or `[` are
+/// quoted, e.g. `additional["msvalidate.01"]`. Enum variants are compared by
+/// their content, so a changed static title is reported at `title` rather than
+/// `title.Static`. A field that is `None` on one side is reported once, as a
+/// whole, rather than per nested field.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::diff::MetadataDiff;
+/// use leptos_next_metadata::metadata::Metadata;
+///
+/// let before = Metadata::with_title("Pricing").canonical("https://acme.com/pricing");
+/// let after = Metadata::with_title("Plans & pricing").description("Compare plans");
+///
+/// let diff = MetadataDiff::between(&before, &after);
+/// assert_eq!(diff.len(), 3);
+/// assert_eq!(diff.changed().count(), 1);
+/// assert_eq!(diff.at("canonical").count(), 1);
+///
+/// let report = diff.to_string();
+/// assert!(report.contains(r#"~ title: "Pricing" -> "Plans & pricing""#));
+/// assert!(report.contains(r#"+ description: "Compare plans""#));
+/// assert!(report.contains(r#"- canonical: "https://acme.com/pricing""#));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MetadataDiff {
+    /// Differences, in a stable order with list items by index
+    pub changes: Vec<Change>,
+}
+
+/// A single difference between two metadata values
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Change {
+    /// Dotted path of the field, e.g. `twitter.card`
+    pub path: String,
+    /// What changed at the path
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+}
+
+/// Kind of a difference, with the serialized values involved
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// The field is only set on the new side
+    Added { value: Value },
+    /// The field is only set on the old side
+    Removed { value: Value },
+    /// The field is set on both sides with different values
+    Changed { from: Value, to: Value },
+}
+
+impl MetadataDiff {
+    /// Compare `old` against `new`
+    pub fn between(old: &Metadata, new: &Metadata) -> Self {
+        let mut diff = Self::default();
+        // Metadata only contains plain data, so serialization cannot fail
+        let old = serde_json::to_value(old).unwrap_or_default();
+        let new = serde_json::to_value(new).unwrap_or_default();
+        diff.compare(String::new(), &old, &new, false);
+        diff
+    }
+
+    /// Whether both values are structurally equal
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Number of differences
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Fields only set on the new side
+    pub fn added(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change.kind, ChangeKind::Added { .. }))
+    }
+
+    /// Fields only set on the old side
+    pub fn removed(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change.kind, ChangeKind::Removed { .. }))
+    }
+
+    /// Fields set on both sides with different values
+    pub fn changed(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change.kind, ChangeKind::Changed { .. }))
+    }
+
+    /// Differences at `path` or nested below it
+    ///
+    /// `at("open_graph")` matches `open_graph.title` and `open_graph.images[0]`,
+    /// but not `open_graph_extra`.
+    pub fn at<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a Change> + 'a {
+        self.changes.iter().filter(move |change| {
+            change.path.strip_prefix(path).is_some_and(|rest| {
+                rest.is_empty() || rest.starts_with('.') || rest.starts_with('[')
+            })
+        })
+    }
+
+    /// Machine-readable report
+    ///
+    /// Each change is an object with `path`, `kind` (`added`, `removed` or
+    /// `changed`) and either `value` or `from` and `to`.
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    /// Compare two values at `path`; `is_enum` marks a field holding an
+    /// externally tagged enum
+    fn compare(&mut self, path: String, old: &Value, new: &Value, is_enum: bool) {
+        if old == new {
+            return;
+        }
+
+        let unwrap = |value: &Value| {
+            if is_enum {
+                unwrap_variant(value)
+            } else {
+                value.clone()
+            }
+        };
+        match (old, new) {
+            (Value::Null, value) => self.push(
+                path,
+                ChangeKind::Added {
+                    value: unwrap(value),
+                },
+            ),
+            (value, Value::Null) => self.push(
+                path,
+                ChangeKind::Removed {
+                    value: unwrap(value),
+                },
+            ),
+            (Value::Object(old), Value::Object(new)) if !is_enum => {
+                for key in old
+                    .keys()
+                    .chain(new.keys().filter(|k| !old.contains_key(*k)))
+                {
+                    self.compare(
+                        join(&path, key),
+                        old.get(key).unwrap_or(&Value::Null),
+                        new.get(key).unwrap_or(&Value::Null),
+                        is_enum_field(&path, key),
+                    );
+                }
+            }
+            (Value::Object(old), Value::Object(new)) => {
+                match (enum_variant(old), enum_variant(new)) {
+                    (Some((old_tag, old)), Some((new_tag, new))) if old_tag == new_tag => {
+                        self.compare(path, old, new, false)
+                    }
+                    // Different variants are reported with their tags
+                    _ => self.push(
+                        path,
+                        ChangeKind::Changed {
+                            from: Value::Object(old.clone()),
+                            to: Value::Object(new.clone()),
+                        },
+                    ),
+                }
+            }
+            (Value::Array(old_items), Value::Array(new_items)) => {
+                for i in 0..old_items.len().max(new_items.len()) {
+                    self.compare(
+                        format!("{}[{}]", path, i),
+                        old_items.get(i).unwrap_or(&Value::Null),
+                        new_items.get(i).unwrap_or(&Value::Null),
+                        false,
+                    );
+                }
+            }
+            _ => self.push(
+                path,
+                ChangeKind::Changed {
+                    from: unwrap(old),
+                    to: unwrap(new),
+                },
+            ),
+        }
+    }
+
+    fn push(&mut self, path: String, kind: ChangeKind) {
+        self.changes.push(Change { path, kind });
+    }
+}
+
+impl Metadata {
+    /// Compare this metadata against a newer version
+    ///
+    /// Shorthand for [`MetadataDiff::between`].
+    pub fn diff(&self, new: &Metadata) -> MetadataDiff {
+        MetadataDiff::between(self, new)
+    }
+}
+
+impl fmt::Display for MetadataDiff {
+    /// One line per change: `+` added, `-` removed, `~` changed
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ChangeKind::Added { ref value } => write!(f, "+ {}: {}", self.path, value),
+            ChangeKind::Removed { ref value } => write!(f, "- {}: {}", self.path, value),
+            ChangeKind::Changed { ref from, ref to } => {
+                write!(f, "~ {}: {} -> {}", self.path, from, to)
+            }
+        }
+    }
+}
+
+/// Append a map key to a path, quoting keys that would make it ambiguous
+fn join(path: &str, key: &str) -> String {
+    if key.contains(['.', '[', ']', '"']) || key.is_empty() {
+        format!("{}[{:?}]", path, key)
+    } else if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Fields of [`Metadata`] holding externally tagged enums with content, as
+/// parent path and field name; `*` stands for every key of a map
+const ENUM_FIELDS: &[(&str, &str)] = &[
+    ("", "title"),
+    ("", "keywords"),
+    ("", "authors"),
+    ("viewport", "width"),
+    ("viewport", "height"),
+    ("additional", "*"),
+];
+
+/// Whether the field `key` of the object at `path` holds an enum
+///
+/// Decided by the schema rather than the serialized shape, since a user-keyed
+/// map with a single entry looks just like a variant.
+fn is_enum_field(path: &str, key: &str) -> bool {
+    ENUM_FIELDS
+        .iter()
+        .any(|&(parent, field)| parent == path && (field == "*" || field == key))
+}
+
+/// Tag and content of an externally tagged enum variant such as
+/// `{"Static": "Home"}`
+fn enum_variant(map: &Map<String, Value>) -> Option<(&str, &Value)> {
+    let mut entries = map.iter();
+    match (entries.next(), entries.next()) {
+        (Some((tag, content)), None) => Some((tag.as_str(), content)),
+        _ => None,
+    }
+}
+
+/// Report scalar variants such as `{"Static": "Home"}` by their content
+fn unwrap_variant(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => match enum_variant(map) {
+            Some((_, content)) if !content.is_object() && !content.is_array() => content.clone(),
+            _ => value.clone(),
+        },
+        _ => value.clone(),
+    }
+}
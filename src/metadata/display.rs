@@ -9,7 +9,7 @@ use std::fmt;
 impl fmt::Display for Title {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Title::Static(s) => write!(f, "{}", s),
+            Title::Static(s) | Title::Absolute(s) => write!(f, "{}", s),
             Title::Template { template, default } => {
                 write!(f, "Template: {} (default: {})", template, default)
             }
@@ -52,24 +52,63 @@ impl fmt::Display for Robots {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut directives = Vec::new();
 
-        if self.index.unwrap_or(false) {
-            directives.push("index".to_string());
-        } else {
-            directives.push("noindex".to_string());
+        // Unset values are left out; crawlers default to index, follow
+        if let Some(index) = self.index {
+            directives.push(if index { "index" } else { "noindex" }.to_string());
+        }
+        if let Some(follow) = self.follow {
+            directives.push(if follow { "follow" } else { "nofollow" }.to_string());
         }
+        push_directives(&self.directives, &mut directives);
 
-        if self.follow.unwrap_or(false) {
-            directives.push("follow".to_string());
-        } else {
-            directives.push("nofollow".to_string());
+        let mut other: Vec<_> = self.other.iter().collect();
+        other.sort();
+        for (name, value) in other {
+            if value.is_empty() {
+                directives.push(name.clone());
+            } else {
+                directives.push(format!("{}:{}", name, value));
+            }
+        }
+
+        write!(f, "{}", directives.join(", "))
+    }
+}
+
+impl fmt::Display for BotDirectives {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut directives = Vec::new();
+
+        if let Some(index) = self.index {
+            directives.push(if index { "index" } else { "noindex" }.to_string());
         }
+        if let Some(follow) = self.follow {
+            directives.push(if follow { "follow" } else { "nofollow" }.to_string());
+        }
+        push_directives(&self.directives, &mut directives);
 
-        // Note: Additional robots directives removed as they don't exist in current struct
+        write!(f, "{}", directives.join(", "))
+    }
+}
 
+impl fmt::Display for RobotsDirectives {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut directives = Vec::new();
+        push_directives(self, &mut directives);
         write!(f, "{}", directives.join(", "))
     }
 }
 
+impl fmt::Display for MaxImagePreview {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaxImagePreview::None => write!(f, "none"),
+            MaxImagePreview::Standard => write!(f, "standard"),
+            MaxImagePreview::Large => write!(f, "large"),
+        }
+    }
+}
+
 impl fmt::Display for TwitterCard {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -91,6 +130,12 @@ impl fmt::Display for ColorScheme {
     }
 }
 
+impl fmt::Display for ThemeColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.color)
+    }
+}
+
 impl fmt::Display for ReferrerPolicy {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -137,6 +182,28 @@ impl fmt::Display for OgAudio {
     }
 }
 
+impl fmt::Display for OgType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for AppleStatusBarStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for Itunes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "app-id={}", self.app_id)?;
+        if let Some(ref argument) = self.app_argument {
+            write!(f, ", app-argument={}", argument)?;
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Display for Article {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(ref published) = self.published_time {
@@ -268,3 +335,35 @@ impl fmt::Display for FormatDetection {
         write!(f, "{}", parts.join(", "))
     }
 }
+
+/// Append the directives that are set, in a stable order
+fn push_directives(robots: &RobotsDirectives, directives: &mut Vec<String>) {
+    let flags = [
+        ("noarchive", robots.noarchive),
+        ("nosnippet", robots.nosnippet),
+        ("notranslate", robots.notranslate),
+        ("noimageindex", robots.noimageindex),
+        ("nocache", robots.nocache),
+    ];
+    for (name, value) in flags {
+        if value == Some(true) {
+            directives.push(name.to_string());
+        }
+    }
+
+    if let Some(ref unavailable_after) = robots.unavailable_after {
+        directives.push(format!(
+            "unavailable_after:{}",
+            unavailable_after.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        ));
+    }
+    if let Some(max_snippet) = robots.max_snippet {
+        directives.push(format!("max-snippet:{}", max_snippet));
+    }
+    if let Some(ref max_image_preview) = robots.max_image_preview {
+        directives.push(format!("max-image-preview:{}", max_image_preview));
+    }
+    if let Some(max_video_preview) = robots.max_video_preview {
+        directives.push(format!("max-video-preview:{}", max_video_preview));
+    }
+}
@@ -0,0 +1,526 @@
+//! Stable content fingerprints for caches and HTTP ETags
+//!
+//! A fingerprint is the hex-encoded SHA-256 digest of a canonical
+//! serialization of a value. Map entries and struct fields are sorted by key,
+//! struct fields that are `None` or empty are left out, and so are map entries
+//! that are `None`, as in structs with `#[serde(flatten)]` fields that
+//! serialize as maps. A fingerprint doesn't depend on `HashMap` iteration
+//! order, the process or the Rust release, and adding an `Option` field to a
+//! type keeps existing fingerprints.
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::metadata::fingerprint::etag_matches;
+//! use leptos_next_metadata::metadata::{Metadata, OgImage, OpenGraph};
+//!
+//! let page = |image: &str| {
+//!     Metadata::with_title("Pricing").open_graph(OpenGraph::default().image(OgImage::new(image)))
+//! };
+//!
+//! assert_eq!(page("/a.png").fingerprint(), page("/a.png").fingerprint());
+//! assert_ne!(page("/a.png").fingerprint(), page("/b.png").fingerprint());
+//!
+//! let etag = page("/a.png").etag();
+//! assert!(etag_matches(&format!("W/{}, \"other\"", etag), &etag));
+//! ```
+
+use super::types::Metadata;
+use crate::{Error, Result};
+use serde::ser::{self, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::{self, Write};
+
+/// Fingerprint of any serializable value
+///
+/// Fails only if the `Serialize` implementation of `value` reports an error.
+pub fn fingerprint<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let bytes = Canonical::encode(value).map_err(|e| Error::SerializationError(e.0))?;
+    let digest = Sha256::digest(&bytes);
+    Ok(digest
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        }))
+}
+
+/// Strong ETag header value for a fingerprint, i.e. the fingerprint in quotes
+pub fn etag(fingerprint: &str) -> String {
+    format!("\"{}\"", fingerprint)
+}
+
+/// Whether an `If-None-Match` header matches `etag`
+///
+/// Uses the weak comparison the header calls for, so `W/"abc"` matches `"abc"`.
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    if_none_match
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+impl Metadata {
+    /// Stable fingerprint of the metadata, for cache keys
+    ///
+    /// Cannot fail: the canonical encoding accepts every serde data type, and
+    /// metadata only holds plain data, such as strings, numbers, URLs, dates
+    /// and JSON values, whose serialization never reports an error.
+    pub fn fingerprint(&self) -> String {
+        fingerprint(self).expect("metadata serializes without errors")
+    }
+
+    /// Strong ETag for the metadata
+    pub fn etag(&self) -> String {
+        etag(&self.fingerprint())
+    }
+}
+
+/// Error reported by a `Serialize` implementation
+#[derive(Debug)]
+struct CanonicalError(String);
+
+impl fmt::Display for CanonicalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CanonicalError {}
+
+impl ser::Error for CanonicalError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Writes the canonical encoding of a value
+///
+/// Every value starts with a tag byte, and strings are length-prefixed, so
+/// the encoding of a sequence of values is unambiguous.
+#[derive(Default)]
+struct Canonical {
+    out: Vec<u8>,
+}
+
+impl Canonical {
+    fn encode<T: Serialize + ?Sized>(value: &T) -> std::result::Result<Vec<u8>, CanonicalError> {
+        let mut canonical = Canonical::default();
+        value.serialize(&mut canonical)?;
+        Ok(canonical.out)
+    }
+
+    fn bytes(&mut self, tag: u8, bytes: &[u8]) {
+        self.out.push(tag);
+        self.out
+            .extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        self.out.extend_from_slice(bytes);
+    }
+
+    fn integer(&mut self, value: impl fmt::Display) {
+        self.bytes(b'i', value.to_string().as_bytes());
+    }
+
+    fn variant(&mut self, variant: &str) {
+        self.bytes(b'v', variant.as_bytes());
+    }
+
+    fn entries(&mut self, skip_empty: bool) -> Entries<'_> {
+        Entries {
+            canonical: self,
+            entries: Vec::new(),
+            key: None,
+            skip_empty,
+        }
+    }
+}
+
+type Encoded = std::result::Result<(), CanonicalError>;
+
+impl<'a> ser::Serializer for &'a mut Canonical {
+    type Ok = ();
+    type Error = CanonicalError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Entries<'a>;
+    type SerializeStruct = Entries<'a>;
+    type SerializeStructVariant = Entries<'a>;
+
+    fn serialize_bool(self, v: bool) -> Encoded {
+        self.out.extend_from_slice(&[b'b', v as u8]);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_i16(self, v: i16) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_i128(self, v: i128) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_u16(self, v: u16) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Encoded {
+        self.integer(v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Encoded {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Encoded {
+        self.out.push(b'f');
+        self.out.extend_from_slice(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Encoded {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Encoded {
+        self.bytes(b's', v.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Encoded {
+        self.bytes(b'y', v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Encoded {
+        self.out.push(b'n');
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Encoded {
+        self.out.push(b'o');
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Encoded {
+        self.out.push(b'u');
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Encoded {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Encoded {
+        self.variant(variant);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Encoded {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Encoded {
+        self.variant(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self, CanonicalError> {
+        self.out.push(b'[');
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, len: usize) -> std::result::Result<Self, CanonicalError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> std::result::Result<Self, CanonicalError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> std::result::Result<Self, CanonicalError> {
+        self.variant(variant);
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(
+        self,
+        _len: Option<usize>,
+    ) -> std::result::Result<Entries<'a>, CanonicalError> {
+        Ok(self.entries(false))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> std::result::Result<Entries<'a>, CanonicalError> {
+        Ok(self.entries(true))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> std::result::Result<Entries<'a>, CanonicalError> {
+        self.variant(variant);
+        Ok(self.entries(true))
+    }
+}
+
+impl ser::SerializeSeq for &mut Canonical {
+    type Ok = ();
+    type Error = CanonicalError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Encoded {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Encoded {
+        self.out.push(b']');
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut Canonical {
+    type Ok = ();
+    type Error = CanonicalError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Encoded {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Encoded {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut Canonical {
+    type Ok = ();
+    type Error = CanonicalError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Encoded {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Encoded {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut Canonical {
+    type Ok = ();
+    type Error = CanonicalError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Encoded {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Encoded {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Map entries or struct fields, written sorted by their encoded key
+struct Entries<'a> {
+    canonical: &'a mut Canonical,
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+    key: Option<Vec<u8>>,
+    /// Also leave out empty values besides `None`, used for struct fields
+    skip_empty: bool,
+}
+
+impl Entries<'_> {
+    fn entry<T: Serialize + ?Sized>(&mut self, key: Vec<u8>, value: &T) -> Encoded {
+        let value = Canonical::encode(value)?;
+        let skip = match value.as_slice() {
+            b"n" => true,
+            b"[]" | b"{}" => self.skip_empty,
+            _ => false,
+        };
+        if !skip {
+            self.entries.push((key, value));
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Encoded {
+        self.entries.sort();
+        let out = &mut self.canonical.out;
+        out.push(b'{');
+        for (key, value) in self.entries {
+            out.extend_from_slice(&key);
+            out.extend_from_slice(&value);
+        }
+        out.push(b'}');
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for Entries<'_> {
+    type Ok = ();
+    type Error = CanonicalError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Encoded {
+        self.key = Some(Canonical::encode(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Encoded {
+        let key = self.key.take().unwrap_or_default();
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Encoded {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for Entries<'_> {
+    type Ok = ();
+    type Error = CanonicalError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Encoded {
+        let key = Canonical::encode(key)?;
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Encoded {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for Entries<'_> {
+    type Ok = ();
+    type Error = CanonicalError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Encoded {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Encoded {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_map_order_does_not_matter() {
+        let a: HashMap<String, u32> = (0..50).map(|i| (i.to_string(), i)).collect();
+        let b: HashMap<String, u32> = (0..50).rev().map(|i| (i.to_string(), i)).collect();
+        assert_eq!(fingerprint(&a).unwrap(), fingerprint(&b).unwrap());
+        assert_eq!(fingerprint(&a).unwrap().len(), 64);
+    }
+
+    #[test]
+    fn test_encoding_is_unambiguous() {
+        let joined = fingerprint(&("ab", "c")).unwrap();
+        assert_ne!(joined, fingerprint(&("a", "bc")).unwrap());
+        assert_ne!(fingerprint(&Some(1)).unwrap(), fingerprint(&1).unwrap());
+        // A known value keeps the fingerprint stable across releases
+        assert_eq!(
+            fingerprint("leptos").unwrap(),
+            "f3324e537c931376bb155d4867c3f0fa019d980adab396b421b1e236537c7eda"
+        );
+    }
+
+    #[test]
+    fn test_none_map_entries_are_left_out() {
+        #[derive(serde::Serialize)]
+        struct Flags {
+            noarchive: Option<bool>,
+        }
+        #[derive(serde::Serialize)]
+        struct Old {
+            index: Option<bool>,
+        }
+        #[derive(serde::Serialize)]
+        struct New {
+            index: Option<bool>,
+            #[serde(flatten)]
+            flags: Flags,
+        }
+
+        // `New` serializes as a map because of the flattened field
+        let old = Old { index: Some(true) };
+        let new = New {
+            index: Some(true),
+            flags: Flags { noarchive: None },
+        };
+        assert_eq!(fingerprint(&old).unwrap(), fingerprint(&new).unwrap());
+    }
+
+    #[test]
+    fn test_etag_matches() {
+        assert!(etag_matches("\"a\", W/\"b\"", "\"b\""));
+        assert!(etag_matches("*", "\"b\""));
+        assert!(!etag_matches("\"a\"", "\"b\""));
+    }
+}
@@ -0,0 +1,233 @@
+//! Async metadata generation with access to the request and parent metadata
+//!
+//! This is the equivalent of Next.js `generateMetadata({ params, searchParams },
+//! parent)`. A [`MetadataGenerator`] receives a [`MetadataRequest`] with the
+//! route parameters, query, headers and locale, and a [`ParentMetadata`]
+//! future that resolves to the merged metadata of every level above it. A page
+//! can await the parent to build on it, such as appending to its Open Graph
+//! images; generators that don't await it run without waiting for their parents.
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::metadata::generator::{
+//!     MetadataGenerator, MetadataRequest, ParentMetadata,
+//! };
+//! use leptos_next_metadata::metadata::{Metadata, OgImage, OpenGraph};
+//!
+//! struct ProductMetadata;
+//!
+//! #[async_trait::async_trait]
+//! impl MetadataGenerator for ProductMetadata {
+//!     async fn generate(&self, request: MetadataRequest, parent: ParentMetadata) -> Metadata {
+//!         let id = request.params.get("id").cloned().unwrap_or_default();
+//!         let mut images = parent
+//!             .await
+//!             .open_graph
+//!             .map(|og| og.images)
+//!             .unwrap_or_default();
+//!         images.insert(0, OgImage::new(&format!("/products/{}.png", id)));
+//!
+//!         Metadata::with_title(format!("Product {}", id)).open_graph(OpenGraph {
+//!             images,
+//!             ..Default::default()
+//!         })
+//!     }
+//! }
+//! ```
+
+use super::route::RouteParams;
+use super::types::Metadata;
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Request data passed to metadata generators
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetadataRequest {
+    /// Request path without the query string
+    pub path: String,
+    /// Parameters captured by the route pattern
+    pub params: RouteParams,
+    /// Query string parameters
+    pub query: HashMap<String, String>,
+    /// Request headers, with lowercase names
+    pub headers: HashMap<String, String>,
+    /// Locale of the request, e.g. `de-AT`
+    pub locale: Option<String>,
+}
+
+impl MetadataRequest {
+    /// Create a request for a path with an optional query string
+    pub fn new(url: &str) -> Self {
+        let url = url.split('#').next().unwrap_or_default();
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        Self {
+            path: path.to_string(),
+            query: parse_query(query),
+            ..Default::default()
+        }
+    }
+
+    /// Add a request header
+    ///
+    /// An `Accept-Language` header also sets the locale, unless one is already
+    /// set, to its first language.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into().to_ascii_lowercase();
+        let value = value.into();
+        if name == "accept-language" && self.locale.is_none() {
+            self.locale = preferred_language(&value);
+        }
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Set the locale of the request
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Value of a header, looked up case-insensitively
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+}
+
+/// Future resolving to the merged metadata of the levels above a generator
+///
+/// It can be cloned and awaited any number of times; the parent levels are
+/// resolved only once.
+#[derive(Clone)]
+pub struct ParentMetadata(Shared<BoxFuture<'static, Metadata>>);
+
+impl ParentMetadata {
+    /// Parent metadata that resolves from a future
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = Metadata> + Send + 'static,
+    {
+        Self(future.boxed().shared())
+    }
+
+    /// Parent metadata that is already resolved
+    pub fn resolved(metadata: Metadata) -> Self {
+        Self::new(futures::future::ready(metadata))
+    }
+}
+
+impl Default for ParentMetadata {
+    fn default() -> Self {
+        Self::resolved(Metadata::default())
+    }
+}
+
+impl Future for ParentMetadata {
+    type Output = Metadata;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Metadata> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+impl std::fmt::Debug for ParentMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ParentMetadata")
+            .field(&self.0.peek())
+            .finish()
+    }
+}
+
+/// Generates metadata for a request, optionally building on the parent's
+///
+/// Closures taking a [`MetadataRequest`] and [`ParentMetadata`] and returning
+/// a future implement this trait.
+#[async_trait::async_trait]
+pub trait MetadataGenerator: Send + Sync {
+    /// Generate the metadata of this level
+    async fn generate(&self, request: MetadataRequest, parent: ParentMetadata) -> Metadata;
+}
+
+#[async_trait::async_trait]
+impl<F, Fut> MetadataGenerator for F
+where
+    F: Fn(MetadataRequest, ParentMetadata) -> Fut + Send + Sync,
+    Fut: Future<Output = Metadata> + Send,
+{
+    async fn generate(&self, request: MetadataRequest, parent: ParentMetadata) -> Metadata {
+        self(request, parent).await
+    }
+}
+
+/// First language of an `Accept-Language` header, ignoring `*`
+fn preferred_language(header: &str) -> Option<String> {
+    let mut languages: Vec<(&str, f32)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let tag = parts.next()?.trim();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.parse().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty() && tag != "*").then_some((tag, quality))
+        })
+        .collect();
+    // Stable sort keeps header order for equal weights
+    languages.sort_by(|a, b| b.1.total_cmp(&a.1));
+    languages.first().map(|(tag, _)| tag.to_string())
+}
+
+/// Parse a query string, decoding `+` and percent escapes
+fn parse_query(query: &str) -> HashMap<String, String> {
+    let decode = |value: &str| {
+        let value = value.replace('+', " ");
+        urlencoding::decode(&value)
+            .map(|decoded| decoded.into_owned())
+            .unwrap_or(value)
+    };
+
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() {
+        let request = MetadataRequest::new("/search?q=rust+wasm&page=2&empty&tag=a%26b#results");
+        assert_eq!(request.path, "/search");
+        assert_eq!(request.query["q"], "rust wasm");
+        assert_eq!(request.query["page"], "2");
+        assert_eq!(request.query["empty"], "");
+        assert_eq!(request.query["tag"], "a&b");
+    }
+
+    #[test]
+    fn test_headers_and_locale() {
+        let request = MetadataRequest::new("/")
+            .header("User-Agent", "Googlebot")
+            .header("Accept-Language", "fr;q=0.8, de-AT, *;q=0.1");
+        assert_eq!(request.header_value("user-agent"), Some("Googlebot"));
+        assert_eq!(request.locale.as_deref(), Some("de-AT"));
+
+        let request = MetadataRequest::new("/")
+            .locale("en")
+            .header("accept-language", "de");
+        assert_eq!(request.locale.as_deref(), Some("en"));
+        assert_eq!(preferred_language("*"), None);
+    }
+}
@@ -0,0 +1,403 @@
+//! Localized metadata with locale fallback chains
+//!
+//! [`LocalizedMetadata`] holds the metadata of a page for every locale it is
+//! served in. [`LocalizedMetadata::resolve`] picks the best match for a
+//! requested locale and fills in the tags that depend on the locale set:
+//!
+//! - `og:locale`, and `og:locale:alternate` for every other locale
+//! - `<link rel="alternate" hreflang>` for every locale, once a URL pattern is set
+//! - [`Metadata::lang`], which [`MetadataContext::inject_head`](super::MetadataContext::inject_head)
+//!   writes to `<html lang>`
+//!
+//! Fields a locale leaves unset fall back along its chain: `de-AT` falls back
+//! to `de` and then to the default locale. Strings can also come from a message
+//! catalog, such as Fluent or gettext, through a [`MessageLookup`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::metadata::i18n::LocalizedMetadata;
+//! use leptos_next_metadata::metadata::{Metadata, Title};
+//!
+//! let pricing = LocalizedMetadata::new("en")
+//!     .locale("en", Metadata::with_title("Pricing").description("Plans for teams"))
+//!     .locale("de", Metadata::with_title("Preise").description("Tarife für Teams"))
+//!     .locale("de-AT", Metadata::default().description("Tarife für Teams in Österreich"))
+//!     .alternate_urls("/{locale}/pricing");
+//!
+//! let metadata = pricing.resolve("de-AT");
+//! assert_eq!(metadata.title, Some(Title::Static("Preise".into())));
+//! assert_eq!(metadata.lang.as_deref(), Some("de-AT"));
+//!
+//! let og = metadata.open_graph.unwrap();
+//! assert_eq!(og.locale.as_deref(), Some("de_AT"));
+//! assert_eq!(og.alternate_locale, ["de", "en"]);
+//!
+//! // Unknown regions fall back to their language
+//! assert_eq!(pricing.negotiate("de-CH"), "de");
+//! ```
+
+use super::generator::{MetadataGenerator, MetadataRequest, ParentMetadata};
+use super::merge::{merge_metadata_with, MergeConfig};
+use super::parse::html_tag;
+use super::render::escape_attr;
+use super::types::{Alternates, Metadata, OpenGraph, Title, Twitter};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Message ids looked up for every locale, and the fields they set
+///
+/// With a prefix such as `pricing`, the ids become `pricing-title` and so on.
+pub const MESSAGE_IDS: [&str; 6] = [
+    "title",
+    "description",
+    "og-title",
+    "og-description",
+    "twitter-title",
+    "twitter-description",
+];
+
+/// A catalog of translated strings, such as Fluent bundles or gettext files
+///
+/// Implemented for closures and for maps from locale to message id to text.
+pub trait MessageLookup: Send + Sync {
+    /// The message `id` in exactly `locale`, without any fallback
+    fn message(&self, locale: &str, id: &str) -> Option<String>;
+}
+
+impl<F> MessageLookup for F
+where
+    F: Fn(&str, &str) -> Option<String> + Send + Sync,
+{
+    fn message(&self, locale: &str, id: &str) -> Option<String> {
+        self(locale, id)
+    }
+}
+
+impl MessageLookup for HashMap<String, HashMap<String, String>> {
+    fn message(&self, locale: &str, id: &str) -> Option<String> {
+        self.get(locale)?.get(id).cloned()
+    }
+}
+
+/// Metadata of a page for every locale it is served in
+#[derive(Clone)]
+pub struct LocalizedMetadata {
+    default_locale: String,
+    common: Metadata,
+    locales: BTreeMap<String, Metadata>,
+    fallbacks: HashMap<String, String>,
+    alternate_urls: Option<String>,
+    messages: Option<(Arc<dyn MessageLookup>, String)>,
+}
+
+impl LocalizedMetadata {
+    /// Create a bundle whose chains all end in `default_locale`
+    pub fn new(default_locale: &str) -> Self {
+        Self {
+            default_locale: canonical_locale(default_locale),
+            common: Metadata::default(),
+            locales: BTreeMap::new(),
+            fallbacks: HashMap::new(),
+            alternate_urls: None,
+            messages: None,
+        }
+    }
+
+    /// Set metadata shared by every locale, such as images or `metadata_base`
+    pub fn common(mut self, metadata: Metadata) -> Self {
+        self.common = metadata;
+        self
+    }
+
+    /// Set the metadata of `locale`, which only needs the fields that differ
+    /// from its fallbacks
+    pub fn locale(mut self, locale: &str, metadata: Metadata) -> Self {
+        self.locales.insert(canonical_locale(locale), metadata);
+        self
+    }
+
+    /// Serve `locales` even without metadata of their own
+    ///
+    /// Use this for locales whose strings all come from a catalog set with
+    /// [`messages`](Self::messages). Only locales of the bundle take part in
+    /// negotiation, `og:locale:alternate` and hreflang alternates.
+    pub fn serve_locales<I>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for locale in locales {
+            self.locales
+                .entry(canonical_locale(locale.as_ref()))
+                .or_default();
+        }
+        self
+    }
+
+    /// Fall back from `locale` to `fallback` before the default locale
+    ///
+    /// For example, `pt-BR` to `pt-PT`. The fallback is tried right after
+    /// `locale`, before the language of `locale`.
+    pub fn fallback(mut self, locale: &str, fallback: &str) -> Self {
+        self.fallbacks
+            .insert(canonical_locale(locale), canonical_locale(fallback));
+        self
+    }
+
+    /// Add hreflang alternates from a path pattern such as `/{locale}/pricing`
+    ///
+    /// The default locale's URL also serves as `x-default`.
+    pub fn alternate_urls(mut self, pattern: impl Into<String>) -> Self {
+        self.alternate_urls = Some(pattern.into());
+        self
+    }
+
+    /// Look up [`MESSAGE_IDS`] under `prefix` in a message catalog
+    ///
+    /// Messages fill the fields a locale leaves unset, and follow the same
+    /// fallback chain. An empty prefix looks up the bare ids. Catalog locales
+    /// without metadata of their own need [`serve_locales`](Self::serve_locales).
+    pub fn messages(mut self, lookup: impl MessageLookup + 'static, prefix: &str) -> Self {
+        self.messages = Some((Arc::new(lookup), prefix.to_string()));
+        self
+    }
+
+    /// Every locale of the bundle, in order, including the default locale
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.locales.keys().map(String::as_str).collect();
+        if !self.locales.contains_key(&self.default_locale) {
+            let index = locales
+                .binary_search(&self.default_locale.as_str())
+                .unwrap_or_else(|index| index);
+            locales.insert(index, &self.default_locale);
+        }
+        locales
+    }
+
+    /// Locales to try for `locale`, from the most to the least specific
+    ///
+    /// `de-AT` yields `de-AT` and `de`, each followed by its configured
+    /// fallback, and the default locale last.
+    pub fn fallback_chain(&self, locale: &str) -> Vec<String> {
+        let mut chain = Vec::new();
+        self.extend_chain(&mut chain, canonical_locale(locale));
+        if !chain.contains(&self.default_locale) {
+            chain.push(self.default_locale.clone());
+        }
+        chain
+    }
+
+    fn extend_chain(&self, chain: &mut Vec<String>, locale: String) {
+        let mut tag = locale.as_str();
+        let mut prefixes = Vec::new();
+        loop {
+            prefixes.push(tag.to_string());
+            match tag.rfind('-') {
+                Some(index) => tag = &tag[..index],
+                None => break,
+            }
+        }
+
+        for prefix in prefixes {
+            if chain.contains(&prefix) {
+                continue;
+            }
+            chain.push(prefix.clone());
+            if let Some(fallback) = self.fallbacks.get(&prefix) {
+                self.extend_chain(chain, fallback.clone());
+            }
+        }
+    }
+
+    /// The locale of the bundle that serves a request for `locale`
+    pub fn negotiate(&self, locale: &str) -> &str {
+        self.fallback_chain(locale)
+            .iter()
+            .find_map(|candidate| self.locales.get_key_value(candidate))
+            .map_or(&self.default_locale, |(locale, _)| locale)
+    }
+
+    /// Metadata for a request in `locale`
+    ///
+    /// The negotiated locale's metadata is merged over its fallbacks, with
+    /// nested objects such as `open_graph` merged field by field.
+    pub fn resolve(&self, locale: &str) -> Metadata {
+        let locale = self.negotiate(locale).to_string();
+        let config = MergeConfig::default();
+
+        let mut metadata = self.common.clone();
+        for candidate in self.fallback_chain(&locale).iter().rev() {
+            let layers = [
+                self.messages_layer(candidate),
+                self.locales.get(candidate).cloned(),
+            ];
+            for mut layer in layers.into_iter().flatten() {
+                if layer.metadata_base.is_none() {
+                    layer.metadata_base = metadata.metadata_base.clone();
+                }
+                metadata = merge_metadata_with(metadata, layer, &config);
+            }
+        }
+
+        self.apply_locale(&mut metadata, &locale);
+        metadata
+    }
+
+    /// Fill in the tags derived from the locale set
+    fn apply_locale(&self, metadata: &mut Metadata, locale: &str) {
+        let locales = self.locales();
+        metadata.lang = Some(locale.to_string());
+
+        let og = metadata.open_graph.get_or_insert_with(OpenGraph::default);
+        og.locale = Some(og_locale(locale));
+        og.alternate_locale = locales
+            .iter()
+            .filter(|other| **other != locale)
+            .map(|other| og_locale(other))
+            .collect();
+
+        if let Some(ref pattern) = self.alternate_urls {
+            let alternates = metadata.alternates.get_or_insert_with(Alternates::default);
+            for other in &locales {
+                alternates
+                    .languages
+                    .entry(other.to_string())
+                    .or_insert_with(|| pattern.replace("{locale}", other));
+            }
+            alternates
+                .languages
+                .entry(Alternates::X_DEFAULT.to_string())
+                .or_insert_with(|| pattern.replace("{locale}", &self.default_locale));
+        }
+    }
+
+    /// Metadata built from the catalog messages of exactly `locale`
+    fn messages_layer(&self, locale: &str) -> Option<Metadata> {
+        let (lookup, prefix) = self.messages.as_ref()?;
+        let message = |id: &str| match prefix.as_str() {
+            "" => lookup.message(locale, id),
+            prefix => lookup.message(locale, &format!("{}-{}", prefix, id)),
+        };
+        let [title, description, og_title, og_description, twitter_title, twitter_description] =
+            MESSAGE_IDS.map(message);
+
+        let mut metadata = Metadata {
+            title: title.map(Title::Static),
+            description,
+            ..Default::default()
+        };
+        if og_title.is_some() || og_description.is_some() {
+            metadata.open_graph = Some(OpenGraph {
+                title: og_title,
+                description: og_description,
+                ..Default::default()
+            });
+        }
+        if twitter_title.is_some() || twitter_description.is_some() {
+            metadata.twitter = Some(Twitter {
+                title: twitter_title,
+                description: twitter_description,
+                ..Default::default()
+            });
+        }
+        Some(metadata)
+    }
+}
+
+/// Resolves the bundle for the `locale` route parameter, or else the request locale
+#[async_trait::async_trait]
+impl MetadataGenerator for LocalizedMetadata {
+    async fn generate(&self, request: MetadataRequest, _parent: ParentMetadata) -> Metadata {
+        let locale = request
+            .params
+            .get("locale")
+            .or(request.locale.as_ref())
+            .map_or(self.default_locale.as_str(), String::as_str);
+        self.resolve(locale)
+    }
+}
+
+/// Canonical casing of a BCP 47 tag: `de-AT`, `zh-Hant-TW`, `es-419`
+///
+/// Underscores, as in `de_AT`, are accepted as separators.
+fn canonical_locale(locale: &str) -> String {
+    locale
+        .trim()
+        .split(['-', '_'])
+        .enumerate()
+        .map(|(index, subtag)| match subtag.len() {
+            _ if index == 0 => subtag.to_ascii_lowercase(),
+            2 => subtag.to_ascii_uppercase(),
+            4 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                let mut chars = subtag.chars();
+                let first = chars.next().map(|c| c.to_ascii_uppercase());
+                first
+                    .into_iter()
+                    .chain(chars.map(|c| c.to_ascii_lowercase()))
+                    .collect()
+            }
+            _ => subtag.to_ascii_lowercase(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Open Graph spelling of a locale, `de_AT` for `de-AT`
+fn og_locale(locale: &str) -> String {
+    locale.replace('-', "_")
+}
+
+/// Set the `lang` attribute of the `<html>` start tag in `html`
+///
+/// HTML without an `<html>` tag is returned unchanged.
+pub(crate) fn set_html_lang(html: &str, lang: &str) -> String {
+    let Some((range, tag)) = html_tag(html) else {
+        return html.to_string();
+    };
+
+    let mut start_tag = format!("<html lang=\"{}\"", escape_attr(lang));
+    for (name, value) in tag.attributes.iter().filter(|(name, _)| name != "lang") {
+        let _ = write!(start_tag, " {}=\"{}\"", name, escape_attr(value));
+    }
+    start_tag.push('>');
+    format!(
+        "{}{}{}",
+        &html[..range.start],
+        start_tag,
+        &html[range.end..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_locale() {
+        assert_eq!(canonical_locale("de_at"), "de-AT");
+        assert_eq!(canonical_locale("ZH-hant-tw"), "zh-Hant-TW");
+        assert_eq!(canonical_locale("es-419"), "es-419");
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let bundle = LocalizedMetadata::new("en").fallback("pt-BR", "pt-PT");
+        assert_eq!(bundle.fallback_chain("de-AT"), ["de-AT", "de", "en"]);
+        assert_eq!(
+            bundle.fallback_chain("pt-BR"),
+            ["pt-BR", "pt-PT", "pt", "en"]
+        );
+        assert_eq!(bundle.fallback_chain("en-GB"), ["en-GB", "en"]);
+    }
+
+    #[test]
+    fn test_set_html_lang() {
+        assert_eq!(
+            set_html_lang("<!DOCTYPE html><html lang=\"en\" dir=ltr><head>", "de-AT"),
+            "<!DOCTYPE html><html lang=\"de-AT\" dir=\"ltr\"><head>"
+        );
+        assert_eq!(set_html_lang("<head></head>", "de"), "<head></head>");
+    }
+}
@@ -3,14 +3,21 @@
 //! This module provides the logic for merging metadata from different
 //! levels in the component hierarchy, following Next.js-style inheritance rules.
 
+use super::resolve::resolve_layer_urls;
 use super::*;
 use crate::Result;
 
 /// Simple merge function used by the metadata context
+///
+/// Relative URLs in `other` are resolved against its own `metadata_base`, or
+/// the one inherited from `base`.
 pub fn merge_metadata(base: Metadata, other: Metadata) -> Metadata {
-    let mut merged = base;
+    let (mut merged, other) = prepare_urls(base, other);
+    clear_inherited_canonical(&mut merged, &other);
 
     // Merge primitive fields (replace if present)
+    merged.metadata_base = other.metadata_base;
+
     if other.title.is_some() {
         merged.title = other.title;
     }
@@ -55,6 +62,34 @@ pub fn merge_metadata(base: Metadata, other: Metadata) -> Metadata {
         merged.format_detection = other.format_detection;
     }
 
+    if other.icons.is_some() {
+        merged.icons = other.icons;
+    }
+
+    if other.manifest.is_some() {
+        merged.manifest = other.manifest;
+    }
+
+    if other.verification.is_some() {
+        merged.verification = other.verification;
+    }
+
+    if other.apple_web_app.is_some() {
+        merged.apple_web_app = other.apple_web_app;
+    }
+
+    if other.itunes.is_some() {
+        merged.itunes = other.itunes;
+    }
+
+    if other.app_links.is_some() {
+        merged.app_links = other.app_links;
+    }
+
+    if other.lang.is_some() {
+        merged.lang = other.lang;
+    }
+
     // Merge objects (replace entirely, not deep merge)
     if other.open_graph.is_some() {
         merged.open_graph = other.open_graph;
@@ -73,6 +108,10 @@ pub fn merge_metadata(base: Metadata, other: Metadata) -> Metadata {
         merged.alternate_links = other.alternate_links;
     }
 
+    if other.alternates.is_some() {
+        merged.alternates = other.alternates;
+    }
+
     // Merge additional fields
     for (key, value) in other.additional {
         merged.additional.insert(key, value);
@@ -81,6 +120,101 @@ pub fn merge_metadata(base: Metadata, other: Metadata) -> Metadata {
     merged
 }
 
+/// Placeholder in a title template that is replaced with the child title
+pub const TITLE_PLACEHOLDER: &str = "%s";
+
+/// Merge a chain of metadata layers, resolving title templates along the way
+///
+/// Layers are ordered from the outermost level (root layout) to the innermost
+/// (page). Titles are resolved the way Next.js does it:
+/// - a `Title::Template` wraps the titles of all following layers until another
+///   template replaces it, and its `default` is used when they provide no title
+/// - a `Title::Static` is wrapped by the nearest preceding template
+/// - a `Title::Absolute` is never wrapped
+///
+/// The merged metadata holds the resolved title as a `Title::Static`.
+pub fn merge_metadata_chain<I>(layers: I) -> Metadata
+where
+    I: IntoIterator<Item = Metadata>,
+{
+    resolve_chain(layers, merge_metadata)
+}
+
+/// Merge a chain of metadata layers using the given merge configuration
+///
+/// Title templates are resolved exactly as in [`merge_metadata_chain`]; all other
+/// fields are combined with [`merge_metadata_with`].
+pub fn merge_metadata_chain_with<I>(layers: I, config: &MergeConfig) -> Metadata
+where
+    I: IntoIterator<Item = Metadata>,
+{
+    resolve_chain(layers, |base, other| {
+        merge_metadata_with(base, other, config)
+    })
+}
+
+fn resolve_chain<I, F>(layers: I, merge: F) -> Metadata
+where
+    I: IntoIterator<Item = Metadata>,
+    F: Fn(Metadata, Metadata) -> Metadata,
+{
+    let mut merged = Metadata::default();
+    let mut template: Option<String> = None;
+
+    for layer in layers {
+        let title = layer.title.clone();
+        merged = merge(merged, layer);
+
+        if let Some(title) = title {
+            merged.title = Some(Title::Static(title.resolve(template.as_deref())));
+            if let Some(child_template) = title.child_template() {
+                template = Some(child_template.to_string());
+            }
+        }
+    }
+
+    merged
+}
+
+impl Title {
+    /// Resolve the text of this title at its own level
+    ///
+    /// `parent_template` is the template inherited from the levels above. It is
+    /// applied to static titles and template defaults, but not to absolute titles.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leptos_next_metadata::metadata::Title;
+    ///
+    /// let title = Title::Static("About".into());
+    /// assert_eq!(title.resolve(Some("%s | Acme")), "About | Acme");
+    ///
+    /// let title = Title::Absolute("Acme".into());
+    /// assert_eq!(title.resolve(Some("%s | Acme")), "Acme");
+    /// ```
+    pub fn resolve(&self, parent_template: Option<&str>) -> String {
+        let text = match self {
+            Title::Absolute(s) => return s.clone(),
+            Title::Static(s) => s,
+            Title::Template { default, .. } => default,
+        };
+
+        match parent_template {
+            Some(template) => template.replace(TITLE_PLACEHOLDER, text),
+            None => text.clone(),
+        }
+    }
+
+    /// Get the template this title provides to the levels below it
+    pub fn child_template(&self) -> Option<&str> {
+        match self {
+            Title::Template { template, .. } => Some(template),
+            Title::Static(_) | Title::Absolute(_) => None,
+        }
+    }
+}
+
 impl Metadata {
     /// Merge this metadata with another metadata instance
     ///
@@ -138,6 +272,636 @@ impl Default for MergeConfig {
     }
 }
 
+impl Metadata {
+    /// Merge this metadata with another metadata instance using a merge configuration
+    ///
+    /// Unlike [`Metadata::merge`], nested objects such as `open_graph`, `twitter`,
+    /// `robots` and JSON-LD are merged field by field, so a child only needs to set
+    /// the fields it wants to change. Arrays are combined according to
+    /// [`MergeConfig::array_merge_strategy`], and objects nested deeper than
+    /// [`MergeConfig::max_depth`] are replaced as a whole.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leptos_next_metadata::metadata::{MergeConfig, Metadata, OpenGraph};
+    ///
+    /// let parent = Metadata::default().open_graph(OpenGraph {
+    ///     site_name: Some("Acme".into()),
+    ///     ..Default::default()
+    /// });
+    /// let child = Metadata::default().open_graph(OpenGraph {
+    ///     title: Some("About".into()),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// let merged = parent.merge_with(&child, &MergeConfig::default());
+    /// let og = merged.open_graph.unwrap();
+    /// assert_eq!(og.site_name.as_deref(), Some("Acme"));
+    /// assert_eq!(og.title.as_deref(), Some("About"));
+    /// ```
+    pub fn merge_with(&self, other: &Metadata, config: &MergeConfig) -> Metadata {
+        merge_metadata_with(self.clone(), other.clone(), config)
+    }
+}
+
+/// Merge two metadata instances using a merge configuration
+///
+/// When `preserve_parent` is disabled, `other` replaces `base` entirely.
+pub fn merge_metadata_with(base: Metadata, other: Metadata, config: &MergeConfig) -> Metadata {
+    let (mut merged, other) = prepare_urls(base, other);
+    if !config.preserve_parent {
+        return other;
+    }
+
+    DeepMerger { config }.merge_metadata(&mut merged, other);
+    merged
+}
+
+/// Resolve the relative URLs of both sides before merging them
+fn prepare_urls(mut base: Metadata, mut other: Metadata) -> (Metadata, Metadata) {
+    let metadata_base = base.metadata_base.clone();
+    base.resolve_relative_urls(metadata_base.as_ref());
+    resolve_layer_urls(&base, &mut other);
+    (base, other)
+}
+
+/// Drop the canonical URL of `base` when `other` sets one through either field
+///
+/// Without this, a page setting `canonical` would lose to an
+/// `alternates.canonical` inherited from its layout.
+fn clear_inherited_canonical(base: &mut Metadata, other: &Metadata) {
+    if other.canonical_url().is_none() {
+        return;
+    }
+
+    base.canonical = None;
+    if let Some(ref mut alternates) = base.alternates {
+        alternates.canonical = None;
+    }
+}
+
+/// Replace each robots directive the other side sets
+fn merge_robots_directives(base: &mut RobotsDirectives, other: RobotsDirectives) {
+    replace(&mut base.noarchive, other.noarchive);
+    replace(&mut base.nosnippet, other.nosnippet);
+    replace(&mut base.notranslate, other.notranslate);
+    replace(&mut base.noimageindex, other.noimageindex);
+    replace(&mut base.nocache, other.nocache);
+    replace(&mut base.unavailable_after, other.unavailable_after);
+    replace(&mut base.max_snippet, other.max_snippet);
+    replace(&mut base.max_image_preview, other.max_image_preview);
+    replace(&mut base.max_video_preview, other.max_video_preview);
+}
+
+/// Replace a value if the other side provides one
+fn replace<T>(base: &mut Option<T>, other: Option<T>) {
+    if other.is_some() {
+        *base = other;
+    }
+}
+
+/// Field-by-field merging driven by a [`MergeConfig`]
+///
+/// Depth counts nesting below the `Metadata` root: `open_graph` is at depth 1,
+/// `open_graph.article` at depth 2, and so on.
+struct DeepMerger<'a> {
+    config: &'a MergeConfig,
+}
+
+impl DeepMerger<'_> {
+    fn merge_metadata(&self, base: &mut Metadata, other: Metadata) {
+        clear_inherited_canonical(base, &other);
+        base.metadata_base = other.metadata_base;
+        replace(&mut base.title, other.title);
+        replace(&mut base.description, other.description);
+        self.merge_keywords(&mut base.keywords, other.keywords);
+        self.merge_authors(&mut base.authors, other.authors);
+        self.merge_nested(&mut base.robots, other.robots, 1, Self::merge_robots);
+        replace(&mut base.canonical, other.canonical);
+        self.merge_nested(&mut base.viewport, other.viewport, 1, Self::merge_viewport);
+        replace(&mut base.theme_color, other.theme_color);
+        replace(&mut base.color_scheme, other.color_scheme);
+        replace(&mut base.referrer, other.referrer);
+        self.merge_nested(
+            &mut base.format_detection,
+            other.format_detection,
+            1,
+            Self::merge_format_detection,
+        );
+        self.merge_nested(&mut base.icons, other.icons, 1, Self::merge_icons);
+        replace(&mut base.manifest, other.manifest);
+        self.merge_nested(
+            &mut base.verification,
+            other.verification,
+            1,
+            Self::merge_verification,
+        );
+        self.merge_nested(
+            &mut base.apple_web_app,
+            other.apple_web_app,
+            1,
+            Self::merge_apple_web_app,
+        );
+        replace(&mut base.itunes, other.itunes);
+        self.merge_nested(
+            &mut base.app_links,
+            other.app_links,
+            1,
+            Self::merge_app_links,
+        );
+        replace(&mut base.lang, other.lang);
+        self.merge_nested(
+            &mut base.open_graph,
+            other.open_graph,
+            1,
+            Self::merge_open_graph,
+        );
+        self.merge_nested(&mut base.twitter, other.twitter, 1, Self::merge_twitter);
+
+        #[cfg(feature = "json-ld")]
+        self.merge_nested(&mut base.json_ld, other.json_ld, 1, Self::merge_json);
+
+        let alternates = std::mem::take(&mut base.alternate_links);
+        base.alternate_links = self.merge_array(alternates, other.alternate_links, |link| {
+            link.hreflang.clone().unwrap_or_else(|| link.url.clone())
+        });
+        self.merge_nested(
+            &mut base.alternates,
+            other.alternates,
+            1,
+            Self::merge_alternates,
+        );
+
+        base.additional.extend(other.additional);
+    }
+
+    /// Merge a nested object, or replace it when the depth limit is reached
+    fn merge_nested<T>(
+        &self,
+        base: &mut Option<T>,
+        other: Option<T>,
+        depth: usize,
+        merge: impl Fn(&Self, &mut T, T, usize),
+    ) {
+        let Some(other) = other else {
+            return;
+        };
+
+        match base {
+            Some(existing) if depth <= self.config.max_depth => merge(self, existing, other, depth),
+            _ => *base = Some(other),
+        }
+    }
+
+    /// Combine two arrays according to the configured strategy
+    ///
+    /// With [`ArrayMergeStrategy::Merge`], items of `other` replace the items of
+    /// `base` with the same key in place and are appended otherwise.
+    fn merge_array<T, K: PartialEq>(
+        &self,
+        base: Vec<T>,
+        other: Vec<T>,
+        key: impl Fn(&T) -> K,
+    ) -> Vec<T> {
+        self.merge_keyed_array(base, other, key, |existing, item| *existing = item)
+    }
+
+    /// Combine two arrays, merging items with the same key using `merge_item`
+    fn merge_keyed_array<T, K: PartialEq>(
+        &self,
+        mut base: Vec<T>,
+        other: Vec<T>,
+        key: impl Fn(&T) -> K,
+        merge_item: impl Fn(&mut T, T),
+    ) -> Vec<T> {
+        match self.config.array_merge_strategy {
+            ArrayMergeStrategy::Replace => {
+                if other.is_empty() {
+                    base
+                } else {
+                    other
+                }
+            }
+            ArrayMergeStrategy::Concat => {
+                base.extend(other);
+                base
+            }
+            ArrayMergeStrategy::Merge => {
+                for item in other {
+                    let item_key = key(&item);
+                    match base.iter().position(|existing| key(existing) == item_key) {
+                        Some(index) => merge_item(&mut base[index], item),
+                        None => base.push(item),
+                    }
+                }
+                base
+            }
+        }
+    }
+
+    /// Combine Open Graph media arrays keyed by URL
+    ///
+    /// Entries with the same URL are merged field by field unless the depth
+    /// limit is reached, in which case they are replaced.
+    fn merge_media<T>(
+        &self,
+        base: Vec<T>,
+        other: Vec<T>,
+        depth: usize,
+        url: impl Fn(&T) -> &str,
+        merge_fields: impl Fn(&mut T, T),
+    ) -> Vec<T> {
+        let key = |item: &T| url(item).to_string();
+        if depth <= self.config.max_depth {
+            self.merge_keyed_array(base, other, key, merge_fields)
+        } else {
+            self.merge_array(base, other, key)
+        }
+    }
+
+    fn merge_optional_array<T, K: PartialEq>(
+        &self,
+        base: &mut Option<Vec<T>>,
+        other: Option<Vec<T>>,
+        key: impl Fn(&T) -> K,
+    ) {
+        let Some(other) = other else {
+            return;
+        };
+
+        *base = Some(match base.take() {
+            Some(existing) => self.merge_array(existing, other, key),
+            None => other,
+        });
+    }
+
+    fn merge_keywords(&self, base: &mut Option<Keywords>, other: Option<Keywords>) {
+        let to_vec = |keywords: Keywords| match keywords {
+            Keywords::Single(keyword) => vec![keyword],
+            Keywords::Multiple(keywords) => keywords,
+        };
+
+        match (base.take(), other) {
+            (Some(existing), Some(other))
+                if self.config.array_merge_strategy != ArrayMergeStrategy::Replace =>
+            {
+                let merged = self.merge_array(to_vec(existing), to_vec(other), String::clone);
+                *base = Some(Keywords::Multiple(merged));
+            }
+            (existing, other) => *base = other.or(existing),
+        }
+    }
+
+    fn merge_authors(&self, base: &mut Option<Authors>, other: Option<Authors>) {
+        let to_vec = |authors: Authors| match authors {
+            Authors::Single(author) => vec![author],
+            Authors::Multiple(authors) => authors,
+        };
+
+        match (base.take(), other) {
+            (Some(existing), Some(other))
+                if self.config.array_merge_strategy != ArrayMergeStrategy::Replace =>
+            {
+                let merged = self.merge_array(to_vec(existing), to_vec(other), |a| a.name.clone());
+                *base = Some(Authors::Multiple(merged));
+            }
+            (existing, other) => *base = other.or(existing),
+        }
+    }
+
+    fn merge_robots(&self, base: &mut Robots, other: Robots, depth: usize) {
+        replace(&mut base.index, other.index);
+        replace(&mut base.follow, other.follow);
+        merge_robots_directives(&mut base.directives, other.directives);
+        self.merge_nested(
+            &mut base.google_bot,
+            other.google_bot,
+            depth + 1,
+            Self::merge_bot_directives,
+        );
+        for (user_agent, directives) in other.bots {
+            let mut merged = base.bots.remove(&user_agent);
+            self.merge_nested(
+                &mut merged,
+                Some(directives),
+                depth + 1,
+                Self::merge_bot_directives,
+            );
+            if let Some(merged) = merged {
+                base.bots.insert(user_agent, merged);
+            }
+        }
+        base.other.extend(other.other);
+    }
+
+    fn merge_bot_directives(&self, base: &mut BotDirectives, other: BotDirectives, _depth: usize) {
+        replace(&mut base.index, other.index);
+        replace(&mut base.follow, other.follow);
+        merge_robots_directives(&mut base.directives, other.directives);
+    }
+
+    fn merge_viewport(&self, base: &mut Viewport, other: Viewport, _depth: usize) {
+        replace(&mut base.width, other.width);
+        replace(&mut base.height, other.height);
+        replace(&mut base.initial_scale, other.initial_scale);
+        replace(&mut base.minimum_scale, other.minimum_scale);
+        replace(&mut base.maximum_scale, other.maximum_scale);
+        replace(&mut base.user_scalable, other.user_scalable);
+        replace(&mut base.viewport_fit, other.viewport_fit);
+    }
+
+    fn merge_format_detection(
+        &self,
+        base: &mut FormatDetection,
+        other: FormatDetection,
+        _depth: usize,
+    ) {
+        replace(&mut base.email, other.email);
+        replace(&mut base.telephone, other.telephone);
+        replace(&mut base.address, other.address);
+        replace(&mut base.date, other.date);
+    }
+
+    fn merge_icons(&self, base: &mut Icons, other: Icons, _depth: usize) {
+        let url = |icon: &IconDescriptor| icon.url.clone();
+        base.icon = self.merge_array(std::mem::take(&mut base.icon), other.icon, url);
+        base.apple = self.merge_array(std::mem::take(&mut base.apple), other.apple, url);
+        base.shortcut = self.merge_array(std::mem::take(&mut base.shortcut), other.shortcut, url);
+        base.other = self.merge_array(std::mem::take(&mut base.other), other.other, url);
+    }
+
+    fn merge_alternates(&self, base: &mut Alternates, other: Alternates, _depth: usize) {
+        replace(&mut base.canonical, other.canonical);
+        base.languages.extend(other.languages);
+        base.media.extend(other.media);
+        for (mime_type, descriptors) in other.types {
+            let existing = base.types.remove(&mime_type).unwrap_or_default();
+            let merged = self.merge_array(existing, descriptors, |d| d.url.clone());
+            base.types.insert(mime_type, merged);
+        }
+    }
+
+    fn merge_verification(&self, base: &mut Verification, other: Verification, _depth: usize) {
+        let token = |token: &String| token.clone();
+        base.google = self.merge_array(std::mem::take(&mut base.google), other.google, token);
+        base.yandex = self.merge_array(std::mem::take(&mut base.yandex), other.yandex, token);
+        base.yahoo = self.merge_array(std::mem::take(&mut base.yahoo), other.yahoo, token);
+        base.me = self.merge_array(std::mem::take(&mut base.me), other.me, token);
+        for (name, tokens) in other.other {
+            let existing = base.other.remove(&name).unwrap_or_default();
+            base.other
+                .insert(name, self.merge_array(existing, tokens, token));
+        }
+    }
+
+    fn merge_apple_web_app(&self, base: &mut AppleWebApp, other: AppleWebApp, _depth: usize) {
+        replace(&mut base.capable, other.capable);
+        replace(&mut base.title, other.title);
+        replace(&mut base.status_bar_style, other.status_bar_style);
+        base.startup_image = self.merge_array(
+            std::mem::take(&mut base.startup_image),
+            other.startup_image,
+            |image| image.url.clone(),
+        );
+    }
+
+    fn merge_app_links(&self, base: &mut AppLinks, other: AppLinks, _depth: usize) {
+        let apple = |app: &AppLinksApple| app.url.clone();
+        let windows = |app: &AppLinksWindows| app.url.clone();
+        base.ios = self.merge_array(std::mem::take(&mut base.ios), other.ios, apple);
+        base.iphone = self.merge_array(std::mem::take(&mut base.iphone), other.iphone, apple);
+        base.ipad = self.merge_array(std::mem::take(&mut base.ipad), other.ipad, apple);
+        base.android = self.merge_array(std::mem::take(&mut base.android), other.android, |app| {
+            app.package.clone()
+        });
+        base.windows_phone = self.merge_array(
+            std::mem::take(&mut base.windows_phone),
+            other.windows_phone,
+            windows,
+        );
+        base.windows = self.merge_array(std::mem::take(&mut base.windows), other.windows, windows);
+        base.windows_universal = self.merge_array(
+            std::mem::take(&mut base.windows_universal),
+            other.windows_universal,
+            windows,
+        );
+        base.web = self.merge_array(std::mem::take(&mut base.web), other.web, |web| {
+            web.url.clone()
+        });
+    }
+
+    fn merge_open_graph(&self, base: &mut OpenGraph, other: OpenGraph, depth: usize) {
+        replace(&mut base.title, other.title);
+        replace(&mut base.description, other.description);
+        replace(&mut base.url, other.url);
+        replace(&mut base.site_name, other.site_name);
+        replace(&mut base.locale, other.locale);
+        let alternate_locale = std::mem::take(&mut base.alternate_locale);
+        base.alternate_locale =
+            self.merge_array(alternate_locale, other.alternate_locale, |locale| {
+                locale.clone()
+            });
+        replace(&mut base.r#type, other.r#type);
+
+        // Media entries describe the same resource only when their URLs match
+        let images = std::mem::take(&mut base.images);
+        base.images = self.merge_media(
+            images,
+            other.images,
+            depth + 1,
+            |i| &i.url,
+            |base, other| {
+                replace(&mut base.secure_url, other.secure_url);
+                replace(&mut base.width, other.width);
+                replace(&mut base.height, other.height);
+                replace(&mut base.alt, other.alt);
+                replace(&mut base.type_, other.type_);
+            },
+        );
+        let videos = std::mem::take(&mut base.videos);
+        base.videos = self.merge_media(
+            videos,
+            other.videos,
+            depth + 1,
+            |v| &v.url,
+            |base, other| {
+                replace(&mut base.secure_url, other.secure_url);
+                replace(&mut base.width, other.width);
+                replace(&mut base.height, other.height);
+                replace(&mut base.type_, other.type_);
+                replace(&mut base.alt, other.alt);
+            },
+        );
+        let audio = std::mem::take(&mut base.audio);
+        base.audio = self.merge_media(
+            audio,
+            other.audio,
+            depth + 1,
+            |a| &a.url,
+            |base, other| {
+                replace(&mut base.secure_url, other.secure_url);
+                replace(&mut base.type_, other.type_);
+            },
+        );
+
+        self.merge_nested(
+            &mut base.article,
+            other.article,
+            depth + 1,
+            |m, base, other, _| {
+                replace(&mut base.published_time, other.published_time);
+                replace(&mut base.modified_time, other.modified_time);
+                replace(&mut base.expiration_time, other.expiration_time);
+                replace(&mut base.author, other.author);
+                replace(&mut base.section, other.section);
+                m.merge_optional_array(&mut base.tag, other.tag, String::clone);
+            },
+        );
+        self.merge_nested(
+            &mut base.profile,
+            other.profile,
+            depth + 1,
+            |_, base, other, _| {
+                replace(&mut base.first_name, other.first_name);
+                replace(&mut base.last_name, other.last_name);
+                replace(&mut base.username, other.username);
+                replace(&mut base.gender, other.gender);
+            },
+        );
+        self.merge_nested(
+            &mut base.book,
+            other.book,
+            depth + 1,
+            |m, base, other, _| {
+                replace(&mut base.author, other.author);
+                replace(&mut base.isbn, other.isbn);
+                replace(&mut base.release_date, other.release_date);
+                m.merge_optional_array(&mut base.tag, other.tag, String::clone);
+            },
+        );
+        self.merge_nested(
+            &mut base.music,
+            other.music,
+            depth + 1,
+            |m, base, other, _| {
+                replace(&mut base.duration, other.duration);
+                m.merge_optional_array(&mut base.album, other.album, |a| a.url.clone());
+                m.merge_optional_array(&mut base.song, other.song, |s| s.url.clone());
+                m.merge_optional_array(&mut base.musician, other.musician, String::clone);
+                replace(&mut base.release_date, other.release_date);
+                replace(&mut base.creator, other.creator);
+            },
+        );
+        self.merge_nested(
+            &mut base.video_info,
+            other.video_info,
+            depth + 1,
+            |m, base, other, _| {
+                m.merge_optional_array(&mut base.actor, other.actor, |a| a.url.clone());
+                m.merge_optional_array(&mut base.director, other.director, String::clone);
+                m.merge_optional_array(&mut base.writer, other.writer, String::clone);
+                replace(&mut base.duration, other.duration);
+                replace(&mut base.release_date, other.release_date);
+                m.merge_optional_array(&mut base.tag, other.tag, String::clone);
+                replace(&mut base.series, other.series);
+            },
+        );
+        self.merge_nested(
+            &mut base.product,
+            other.product,
+            depth + 1,
+            |_, base, other, _| {
+                replace(&mut base.price, other.price);
+                replace(&mut base.availability, other.availability);
+                replace(&mut base.condition, other.condition);
+                replace(&mut base.brand, other.brand);
+                replace(&mut base.retailer_item_id, other.retailer_item_id);
+            },
+        );
+
+        base.other.extend(other.other);
+    }
+
+    fn merge_twitter(&self, base: &mut Twitter, other: Twitter, _depth: usize) {
+        replace(&mut base.card, other.card);
+        replace(&mut base.site, other.site);
+        replace(&mut base.site_id, other.site_id);
+        replace(&mut base.creator, other.creator);
+        replace(&mut base.creator_id, other.creator_id);
+        replace(&mut base.title, other.title);
+        replace(&mut base.description, other.description);
+        replace(&mut base.image, other.image);
+        replace(&mut base.image_alt, other.image_alt);
+        replace(&mut base.player, other.player);
+        replace(&mut base.player_width, other.player_width);
+        replace(&mut base.player_height, other.player_height);
+        replace(&mut base.player_stream, other.player_stream);
+        replace(&mut base.app_name_iphone, other.app_name_iphone);
+        replace(&mut base.app_id_iphone, other.app_id_iphone);
+        replace(&mut base.app_url_iphone, other.app_url_iphone);
+        replace(&mut base.app_name_ipad, other.app_name_ipad);
+        replace(&mut base.app_id_ipad, other.app_id_ipad);
+        replace(&mut base.app_url_ipad, other.app_url_ipad);
+        replace(&mut base.app_name_googleplay, other.app_name_googleplay);
+        replace(&mut base.app_id_googleplay, other.app_id_googleplay);
+        replace(&mut base.app_url_googleplay, other.app_url_googleplay);
+        base.other.extend(other.other);
+    }
+
+    /// Merge JSON-LD values
+    ///
+    /// Objects are merged key by key and arrays follow the array strategy, with
+    /// array items keyed by their `@id` when they have one. Nodes with a
+    /// different `@type` or `@id`, such as a layout's `Organization` and a
+    /// page's `BlogPosting`, are different entities, so `other` replaces `base`.
+    /// Nested objects obey the depth limit like the other nested fields.
+    #[cfg(feature = "json-ld")]
+    fn merge_json(&self, base: &mut serde_json::Value, other: serde_json::Value, depth: usize) {
+        use serde_json::Value;
+
+        match (base, other) {
+            (Value::Object(base_map), Value::Object(other_map)) => {
+                let differs = |key: &str| {
+                    matches!(
+                        (base_map.get(key), other_map.get(key)),
+                        (Some(base), Some(other)) if base != other
+                    )
+                };
+                if differs("@type") || differs("@id") {
+                    *base_map = other_map;
+                    return;
+                }
+
+                for (key, value) in other_map {
+                    match base_map.get_mut(&key) {
+                        Some(existing) => {
+                            let mut merged = Some(existing.take());
+                            self.merge_nested(
+                                &mut merged,
+                                Some(value),
+                                depth + 1,
+                                Self::merge_json,
+                            );
+                            *existing = merged.unwrap_or_default();
+                        }
+                        None => {
+                            base_map.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (Value::Array(base_items), Value::Array(other_items)) => {
+                let items = std::mem::take(base_items);
+                *base_items = self.merge_array(items, other_items, |item| {
+                    item.get("@id").cloned().unwrap_or_else(|| item.clone())
+                });
+            }
+            (base, other) => *base = other,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +925,445 @@ mod tests {
         assert!(merged.title.is_some());
         assert!(merged.description.is_some());
     }
+
+    fn template(template: &str, default: &str) -> Title {
+        Title::Template {
+            template: template.to_string(),
+            default: default.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_chain_applies_parent_template_to_child_title() {
+        let merged = merge_metadata_chain(vec![
+            Metadata::default().title(template("%s | Acme", "Acme")),
+            Metadata::with_title("About"),
+        ]);
+
+        assert_eq!(merged.title, Some(Title::Static("About | Acme".into())));
+    }
+
+    #[test]
+    fn test_chain_uses_default_when_child_has_no_title() {
+        let merged = merge_metadata_chain(vec![
+            Metadata::default().title(template("%s | Acme", "Acme")),
+            Metadata::default().description("No title here"),
+        ]);
+
+        assert_eq!(merged.title, Some(Title::Static("Acme".into())));
+    }
+
+    #[test]
+    fn test_chain_absolute_title_ignores_parent_template() {
+        let merged = merge_metadata_chain(vec![
+            Metadata::default().title(template("%s | Acme", "Acme")),
+            Metadata::default().title(Title::Absolute("Welcome to Acme".into())),
+        ]);
+
+        assert_eq!(merged.title, Some(Title::Static("Welcome to Acme".into())));
+    }
+
+    #[test]
+    fn test_chain_nested_templates() {
+        let merged = merge_metadata_chain(vec![
+            Metadata::default().title(template("%s | Acme", "Acme")),
+            Metadata::default().title(template("%s - Blog", "Blog")),
+            Metadata::with_title("Hello World"),
+        ]);
+        assert_eq!(
+            merged.title,
+            Some(Title::Static("Hello World - Blog".into()))
+        );
+
+        // The blog layout's default is itself wrapped by the root template
+        let merged = merge_metadata_chain(vec![
+            Metadata::default().title(template("%s | Acme", "Acme")),
+            Metadata::default().title(template("%s - Blog", "Blog")),
+        ]);
+        assert_eq!(merged.title, Some(Title::Static("Blog | Acme".into())));
+    }
+
+    #[test]
+    fn test_chain_template_does_not_apply_to_its_own_level() {
+        let merged = merge_metadata_chain(vec![
+            Metadata::default().title(template("%s | Acme", "Acme"))
+        ]);
+
+        assert_eq!(merged.title, Some(Title::Static("Acme".into())));
+    }
+
+    fn og(title: Option<&str>, images: Vec<OgImage>) -> OpenGraph {
+        OpenGraph {
+            title: title.map(Into::into),
+            images,
+            ..Default::default()
+        }
+    }
+
+    fn alternate(url: &str, hreflang: &str) -> AlternateLink {
+        AlternateLink {
+            url: url.into(),
+            hreflang: Some(hreflang.into()),
+            media: None,
+            type_: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_with_deep_merges_nested_objects() {
+        let mut parent = Metadata::default().open_graph(OpenGraph {
+            site_name: Some("Acme".into()),
+            ..og(Some("Home"), vec![])
+        });
+        parent.robots = Some(
+            Robots {
+                directives: RobotsDirectives {
+                    noarchive: Some(true),
+                    ..Default::default()
+                },
+                ..Robots::all()
+            }
+            .bot("bingbot", BotDirectives::noindex()),
+        );
+
+        let mut child = Metadata::default().open_graph(og(Some("About"), vec![]));
+        child.robots = Some(
+            Robots {
+                index: Some(false),
+                ..Default::default()
+            }
+            .bot(
+                "bingbot",
+                BotDirectives {
+                    follow: Some(false),
+                    ..Default::default()
+                },
+            ),
+        );
+
+        let merged = parent.merge_with(&child, &MergeConfig::default());
+        let og = merged.open_graph.unwrap();
+        assert_eq!(og.title.as_deref(), Some("About"));
+        assert_eq!(og.site_name.as_deref(), Some("Acme"));
+
+        let robots = merged.robots.unwrap();
+        assert_eq!(robots.index, Some(false));
+        assert_eq!(robots.follow, Some(true));
+        assert_eq!(robots.directives.noarchive, Some(true));
+        assert_eq!(robots.bots["bingbot"].to_string(), "noindex, nofollow");
+    }
+
+    #[test]
+    fn test_merge_with_images_keyed_by_url() {
+        let parent = Metadata::default().open_graph(og(
+            None,
+            vec![
+                OgImage::with_dimensions("https://acme.com/default.png", 1200, 630),
+                OgImage::with_dimensions("https://acme.com/og.png", 800, 600),
+            ],
+        ));
+        let child = Metadata::default().open_graph(og(
+            None,
+            vec![
+                OgImage {
+                    alt: Some("About us".into()),
+                    ..OgImage::new("https://acme.com/og.png")
+                },
+                OgImage::new("https://acme.com/team.png"),
+            ],
+        ));
+
+        let replaced = parent.merge_with(&child, &MergeConfig::default());
+        assert_eq!(
+            replaced.open_graph.unwrap().images,
+            child.open_graph.clone().unwrap().images
+        );
+
+        let merged = parent.merge_with(
+            &child,
+            &MergeConfig {
+                array_merge_strategy: ArrayMergeStrategy::Merge,
+                ..Default::default()
+            },
+        );
+        let images = merged.open_graph.unwrap().images;
+        let urls: Vec<_> = images.iter().map(|image| image.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://acme.com/default.png",
+                "https://acme.com/og.png",
+                "https://acme.com/team.png"
+            ]
+        );
+
+        // Same image URL: fields are merged
+        assert_eq!(images[1].width, Some(800));
+        assert_eq!(images[1].alt.as_deref(), Some("About us"));
+    }
+
+    #[test]
+    fn test_merge_with_array_strategies() {
+        let mut parent = Metadata::default().keywords(Keywords::Multiple(vec!["rust".into()]));
+        parent.alternate_links = vec![
+            alternate("https://example.com/en", "en"),
+            alternate("https://example.com/de", "de"),
+        ];
+        let mut child = Metadata::default().keywords(Keywords::Single("leptos".into()));
+        child.alternate_links = vec![
+            alternate("https://example.com/de-de", "de"),
+            alternate("https://example.com/fr", "fr"),
+        ];
+
+        let replace = parent.merge_with(&child, &MergeConfig::default());
+        assert_eq!(replace.alternate_links, child.alternate_links);
+        assert_eq!(replace.keywords, Some(Keywords::Single("leptos".into())));
+
+        let concat = parent.merge_with(
+            &child,
+            &MergeConfig {
+                array_merge_strategy: ArrayMergeStrategy::Concat,
+                ..Default::default()
+            },
+        );
+        assert_eq!(concat.alternate_links.len(), 4);
+        assert_eq!(
+            concat.keywords,
+            Some(Keywords::Multiple(vec!["rust".into(), "leptos".into()]))
+        );
+
+        let keyed = parent.merge_with(
+            &child,
+            &MergeConfig {
+                array_merge_strategy: ArrayMergeStrategy::Merge,
+                ..Default::default()
+            },
+        );
+        let urls: Vec<_> = keyed
+            .alternate_links
+            .iter()
+            .map(|l| l.url.as_str())
+            .collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/en",
+                "https://example.com/de-de",
+                "https://example.com/fr"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_with_respects_max_depth() {
+        let parent = Metadata::default().open_graph(OpenGraph {
+            site_name: Some("Acme".into()),
+            ..Default::default()
+        });
+        let child = Metadata::default().open_graph(og(Some("About"), vec![]));
+
+        let shallow = parent.merge_with(
+            &child,
+            &MergeConfig {
+                max_depth: 0,
+                ..Default::default()
+            },
+        );
+        let og = shallow.open_graph.unwrap();
+        assert_eq!(og.title.as_deref(), Some("About"));
+        assert_eq!(og.site_name, None);
+    }
+
+    #[test]
+    fn test_merge_with_without_preserving_parent() {
+        let parent = Metadata::with_title("Parent").description("Parent description");
+        let child = Metadata::with_title("Child");
+
+        let merged = parent.merge_with(
+            &child,
+            &MergeConfig {
+                preserve_parent: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(merged.description, None);
+    }
+
+    #[cfg(feature = "json-ld")]
+    #[test]
+    fn test_merge_with_json_ld() {
+        let parent = Metadata::default().json_ld(serde_json::json!({
+            "@type": "Organization",
+            "name": "Acme",
+            "address": { "addressLocality": "Berlin", "postalCode": "10115" },
+            "sameAs": ["https://github.com/acme"]
+        }));
+        let child = Metadata::default().json_ld(serde_json::json!({
+            "address": { "postalCode": "10117" },
+            "sameAs": ["https://x.com/acme"]
+        }));
+
+        let merged = parent.merge_with(
+            &child,
+            &MergeConfig {
+                array_merge_strategy: ArrayMergeStrategy::Concat,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            merged.json_ld,
+            Some(serde_json::json!({
+                "@type": "Organization",
+                "name": "Acme",
+                "address": { "addressLocality": "Berlin", "postalCode": "10117" },
+                "sameAs": ["https://github.com/acme", "https://x.com/acme"]
+            }))
+        );
+    }
+
+    #[cfg(feature = "json-ld")]
+    #[test]
+    fn test_merge_with_json_ld_of_another_type() {
+        let parent = Metadata::default().json_ld(serde_json::json!({
+            "@type": "Organization",
+            "name": "Acme",
+            "logo": "https://acme.com/logo.png"
+        }));
+        let child = Metadata::default().json_ld(serde_json::json!({
+            "@type": "BlogPosting",
+            "headline": "Launch day"
+        }));
+
+        let merged = parent.merge_with(&child, &MergeConfig::default());
+        assert_eq!(merged.json_ld, child.json_ld);
+
+        // Nested nodes with different ids are replaced too
+        let parent = Metadata::default().json_ld(serde_json::json!({
+            "author": { "@id": "#ana", "name": "Ana" }
+        }));
+        let child = Metadata::default().json_ld(serde_json::json!({
+            "author": { "@id": "#ben", "url": "https://acme.com/ben" }
+        }));
+        let merged = parent.merge_with(&child, &MergeConfig::default());
+        assert_eq!(merged.json_ld, child.json_ld);
+    }
+
+    #[cfg(feature = "json-ld")]
+    #[test]
+    fn test_merge_with_json_ld_respects_max_depth() {
+        let parent = Metadata::default()
+            .open_graph(OpenGraph {
+                article: Some(Article {
+                    section: Some("News".into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .json_ld(serde_json::json!({
+                "address": { "addressLocality": "Berlin", "postalCode": "10115" }
+            }));
+        let child = Metadata::default()
+            .open_graph(OpenGraph {
+                article: Some(Article {
+                    author: Some("Ana".into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .json_ld(serde_json::json!({ "address": { "postalCode": "10117" } }));
+
+        // `open_graph.article` and `json_ld.address` are both at depth 2
+        for (max_depth, merges) in [(1, false), (2, true)] {
+            let merged = parent.merge_with(
+                &child,
+                &MergeConfig {
+                    max_depth,
+                    ..Default::default()
+                },
+            );
+            let article = merged.open_graph.unwrap().article.unwrap();
+            assert_eq!(article.section.is_some(), merges, "max_depth {}", max_depth);
+            let address = &merged.json_ld.unwrap()["address"];
+            assert_eq!(
+                address.get("addressLocality").is_some(),
+                merges,
+                "max_depth {}",
+                max_depth
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_icons() {
+        let layout = Metadata::default().icons(Icons::default().icon("/favicon.ico"));
+        let page = Metadata::default().icons(Icons::default().apple("/apple-touch-icon.png"));
+
+        // The shallow merge replaces the icons of the parent
+        let merged = merge_metadata(layout.clone(), page.clone());
+        assert_eq!(merged.icons, page.icons);
+
+        let merged = layout.merge_with(
+            &page,
+            &MergeConfig {
+                array_merge_strategy: ArrayMergeStrategy::Merge,
+                ..Default::default()
+            },
+        );
+        let icons = merged.icons.unwrap();
+        assert_eq!(icons.icon[0].url, "/favicon.ico");
+        assert_eq!(icons.apple[0].url, "/apple-touch-icon.png");
+    }
+
+    #[test]
+    fn test_merge_verification_and_apple_web_app() {
+        let layout = Metadata::default()
+            .verification(Verification::default().google("site-token"))
+            .apple_web_app(AppleWebApp {
+                capable: Some(true),
+                title: Some("Acme".to_string()),
+                ..Default::default()
+            });
+        let page = Metadata::default()
+            .verification(Verification::default().other("msvalidate.01", "bing-token"))
+            .apple_web_app(AppleWebApp {
+                title: Some("Acme Docs".to_string()),
+                ..Default::default()
+            });
+
+        let merged = merge_metadata(layout.clone(), page.clone());
+        assert_eq!(merged.verification, page.verification);
+
+        let merged = layout.merge_with(&page, &MergeConfig::default());
+        let verification = merged.verification.unwrap();
+        assert_eq!(verification.google, vec!["site-token"]);
+        assert_eq!(verification.other["msvalidate.01"], vec!["bing-token"]);
+        let apple_web_app = merged.apple_web_app.unwrap();
+        assert_eq!(apple_web_app.capable, Some(true));
+        assert_eq!(apple_web_app.title.as_deref(), Some("Acme Docs"));
+    }
+
+    #[test]
+    fn test_merge_alternates() {
+        let layout = Metadata::default().alternates(
+            Alternates::locales("/{locale}", ["en", "de"])
+                .canonical("/")
+                .feed("application/rss+xml", "/feed.xml", None),
+        );
+        let page = Metadata::default()
+            .canonical("/pricing")
+            .alternates(Alternates::default().language("fr", "/fr/pricing"));
+
+        // The shallow merge replaces the alternates of the parent
+        let merged = merge_metadata(layout.clone(), page.clone());
+        assert_eq!(merged.alternates, page.alternates);
+        assert_eq!(merged.canonical_url(), Some("/pricing"));
+
+        let merged = layout.merge_with(&page, &MergeConfig::default());
+        let alternates = merged.alternates.as_ref().unwrap();
+        assert_eq!(alternates.languages.len(), 3);
+        assert_eq!(alternates.types["application/rss+xml"].len(), 1);
+        // The page canonical wins over the inherited alternates.canonical
+        assert_eq!(alternates.canonical, None);
+        assert_eq!(merged.canonical_url(), Some("/pricing"));
+    }
 }
@@ -9,8 +9,22 @@ pub mod validation;
 
 // Core modules
 pub mod builder;
+#[cfg(feature = "diff")]
+pub mod diff;
 pub mod display;
+pub mod fingerprint;
+pub mod generator;
+pub mod i18n;
+#[cfg(feature = "nextjs")]
+pub mod nextjs;
+pub mod parse;
+pub mod registry;
+pub mod render;
+pub mod resolve;
+pub mod route;
 pub mod serde_impl;
+pub mod strict;
+pub mod streaming;
 pub mod types;
 
 // Re-export everything from the submodules
@@ -0,0 +1,1682 @@
+//! Interop with the Next.js `metadata` JSON shape
+//!
+//! Next.js metadata objects use camelCase keys (`openGraph`, `metadataBase`,
+//! `robots.googleBot`) and accept shorthands such as a string or an object for
+//! `title`, a string or an array for `keywords`, and a URL string for an image.
+//! [`Metadata::from_nextjs_json`] reads that shape and
+//! [`Metadata::to_nextjs_json`] writes it, so metadata exported from a Next.js
+//! app can be used as is.
+//!
+//! The module can also be used with `#[serde(with = "...")]` to embed metadata
+//! in the Next.js shape in other types.
+//!
+//! Fields without a Next.js equivalent, such as `json_ld`, product details or
+//! crawler overrides other than `googleBot`, are not written. Where Next.js
+//! allows several values and [`Metadata`] holds one, such as Twitter images or
+//! article authors, the first value is used.
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::metadata::{Keywords, Metadata, Title};
+//!
+//! let metadata = Metadata::from_nextjs_json(r#"{
+//!     "metadataBase": "https://acme.com",
+//!     "title": { "template": "%s | Acme", "default": "Acme" },
+//!     "keywords": ["rust", "leptos"],
+//!     "openGraph": { "siteName": "Acme", "images": "/og.png" },
+//!     "robots": { "index": true, "googleBot": { "max-snippet": -1 } },
+//!     "alternates": { "languages": { "de-DE": "/de" } }
+//! }"#).unwrap();
+//!
+//! assert!(matches!(metadata.title, Some(Title::Template { .. })));
+//! assert_eq!(
+//!     metadata.keywords,
+//!     Some(Keywords::Multiple(vec!["rust".into(), "leptos".into()]))
+//! );
+//! assert_eq!(metadata.open_graph.as_ref().unwrap().images[0].url, "/og.png");
+//!
+//! let json = metadata.to_nextjs_json().unwrap();
+//! assert!(json.contains(r#""siteName":"Acme""#));
+//! assert_eq!(Metadata::from_nextjs_json(&json).unwrap(), metadata);
+//! ```
+
+use super::parse::{parse_bot_directives, parse_referrer, parse_robots, parse_viewport};
+use super::types::*;
+use crate::{Error, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Next.js fields rendered as `<meta name>` tags, with their tag names
+///
+/// They are kept in [`Metadata::additional`] under the tag name, the way
+/// [`Metadata::from_html`] reads them.
+const NAMED_FIELDS: [(&str, &str); 6] = [
+    ("applicationName", "application-name"),
+    ("generator", "generator"),
+    ("creator", "creator"),
+    ("publisher", "publisher"),
+    ("category", "category"),
+    ("classification", "classification"),
+];
+
+impl Metadata {
+    /// Parse metadata from a Next.js `metadata` JSON object
+    pub fn from_nextjs_json(json: &str) -> Result<Self> {
+        let metadata: NextMetadata =
+            serde_json::from_str(json).map_err(|e| Error::SerializationError(e.to_string()))?;
+        metadata.into_metadata()
+    }
+
+    /// Convert a Next.js `metadata` JSON value
+    pub fn from_nextjs_value(value: Value) -> Result<Self> {
+        let metadata: NextMetadata =
+            serde_json::from_value(value).map_err(|e| Error::SerializationError(e.to_string()))?;
+        metadata.into_metadata()
+    }
+
+    /// Serialize as a Next.js `metadata` JSON object
+    pub fn to_nextjs_json(&self) -> Result<String> {
+        serde_json::to_string(&self.to_nextjs_value())
+            .map_err(|e| Error::SerializationError(e.to_string()))
+    }
+
+    /// Convert to a Next.js `metadata` JSON value
+    ///
+    /// Unset fields are left out rather than written as `null`.
+    pub fn to_nextjs_value(&self) -> Value {
+        let mut value = serde_json::to_value(NextMetadata::from(self)).unwrap_or_default();
+        strip_empty(&mut value);
+        value
+    }
+}
+
+/// Serialize metadata in the Next.js shape, for `#[serde(with)]`
+pub fn serialize<S: Serializer>(
+    metadata: &Metadata,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    metadata.to_nextjs_value().serialize(serializer)
+}
+
+/// Deserialize metadata from the Next.js shape, for `#[serde(with)]`
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Metadata, D::Error> {
+    NextMetadata::deserialize(deserializer)?
+        .into_metadata()
+        .map_err(de::Error::custom)
+}
+
+/// Remove `null` values and empty objects and arrays
+fn strip_empty(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.values_mut().for_each(strip_empty);
+            map.retain(|_, value| match value {
+                Value::Null => false,
+                Value::Object(map) => !map.is_empty(),
+                Value::Array(items) => !items.is_empty(),
+                _ => true,
+            });
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_empty),
+        _ => {}
+    }
+}
+
+/// A value or a list of values
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(value) => vec![value],
+            OneOrMany::Many(values) => values,
+        }
+    }
+
+    fn first(self) -> Option<T> {
+        self.into_vec().into_iter().next()
+    }
+}
+
+/// A list, written as a single value when it has one item
+fn one_or_many<T>(mut values: Vec<T>) -> Option<OneOrMany<T>> {
+    match values.len() {
+        0 => None,
+        1 => values.pop().map(OneOrMany::One),
+        _ => Some(OneOrMany::Many(values)),
+    }
+}
+
+/// A string, also accepting numbers such as app IDs or image sizes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Text {
+    String(String),
+    Number(serde_json::Number),
+}
+
+impl Text {
+    fn into_string(self) -> String {
+        match self {
+            Text::String(value) => value,
+            Text::Number(value) => value.to_string(),
+        }
+    }
+
+    fn to_u32(&self, field: &str) -> Result<u32> {
+        let parsed = match self {
+            Text::String(value) => value.parse().ok(),
+            Text::Number(value) => value.as_u64().and_then(|value| value.try_into().ok()),
+        };
+        parsed.ok_or_else(|| invalid(field, self))
+    }
+}
+
+fn invalid(field: &str, value: impl std::fmt::Debug) -> Error {
+    Error::InvalidMetadata(format!("Invalid Next.js `{}` value: {:?}", field, value))
+}
+
+fn size(value: Option<Text>, field: &str) -> Result<Option<u32>> {
+    value.map(|value| value.to_u32(field)).transpose()
+}
+
+fn strings(values: Option<OneOrMany<String>>) -> Vec<String> {
+    values.map(OneOrMany::into_vec).unwrap_or_default()
+}
+
+fn optional_list(values: Vec<String>) -> Option<Vec<String>> {
+    (!values.is_empty()).then_some(values)
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextMetadata {
+    metadata_base: Option<url::Url>,
+    title: Option<NextTitle>,
+    description: Option<String>,
+    application_name: Option<String>,
+    generator: Option<String>,
+    creator: Option<String>,
+    publisher: Option<String>,
+    category: Option<String>,
+    classification: Option<String>,
+    keywords: Option<OneOrMany<String>>,
+    authors: Option<OneOrMany<NextAuthor>>,
+    robots: Option<NextRobots>,
+    alternates: Option<NextAlternates>,
+    open_graph: Option<NextOpenGraph>,
+    twitter: Option<NextTwitter>,
+    viewport: Option<NextViewport>,
+    theme_color: Option<OneOrMany<ThemeColor>>,
+    color_scheme: Option<NextColorScheme>,
+    referrer: Option<String>,
+    format_detection: Option<FormatDetection>,
+    icons: Option<NextIcons>,
+    manifest: Option<String>,
+    verification: Option<NextVerification>,
+    apple_web_app: Option<NextAppleWebApp>,
+    itunes: Option<NextItunes>,
+    app_links: Option<AppLinks>,
+    #[serde(default)]
+    other: HashMap<String, OneOrMany<Text>>,
+}
+
+impl NextMetadata {
+    fn into_metadata(self) -> Result<Metadata> {
+        let mut additional = HashMap::new();
+        let named = [
+            self.application_name,
+            self.generator,
+            self.creator,
+            self.publisher,
+            self.category,
+            self.classification,
+        ];
+        for ((_, name), value) in NAMED_FIELDS.iter().zip(named) {
+            if let Some(value) = value {
+                additional.insert(name.to_string(), AdditionalValue::String(value));
+            }
+        }
+        for (name, value) in self.other {
+            let value = match value {
+                OneOrMany::One(value) => AdditionalValue::String(value.into_string()),
+                OneOrMany::Many(values) => {
+                    AdditionalValue::Array(values.into_iter().map(Text::into_string).collect())
+                }
+            };
+            additional.insert(name, value);
+        }
+
+        Ok(Metadata {
+            metadata_base: self.metadata_base,
+            title: self.title.map(NextTitle::into_title),
+            description: self.description,
+            keywords: self.keywords.map(|keywords| match keywords {
+                OneOrMany::One(keyword) => Keywords::Single(keyword),
+                OneOrMany::Many(keywords) => Keywords::Multiple(keywords),
+            }),
+            authors: self.authors.map(|authors| match authors {
+                OneOrMany::One(author) => Authors::Single(author.into()),
+                OneOrMany::Many(authors) => {
+                    Authors::Multiple(authors.into_iter().map(Author::from).collect())
+                }
+            }),
+            robots: self.robots.map(NextRobots::into_robots).transpose()?,
+            alternates: self.alternates.map(NextAlternates::into_alternates),
+            open_graph: self
+                .open_graph
+                .map(NextOpenGraph::into_open_graph)
+                .transpose()?,
+            twitter: self.twitter.map(NextTwitter::into_twitter).transpose()?,
+            viewport: self.viewport.map(NextViewport::into_viewport).transpose()?,
+            theme_color: self.theme_color.and_then(OneOrMany::first),
+            color_scheme: self.color_scheme.map(ColorScheme::from),
+            referrer: self
+                .referrer
+                .map(|referrer| {
+                    parse_referrer(&referrer).ok_or_else(|| invalid("referrer", referrer))
+                })
+                .transpose()?,
+            format_detection: self.format_detection,
+            icons: self.icons.map(NextIcons::into_icons),
+            manifest: self.manifest,
+            verification: self.verification.map(Verification::from),
+            apple_web_app: self.apple_web_app.map(AppleWebApp::from),
+            itunes: self.itunes.map(|itunes| Itunes {
+                app_id: itunes.app_id.into_string(),
+                app_argument: itunes.app_argument,
+            }),
+            app_links: self.app_links,
+            additional,
+            ..Default::default()
+        })
+    }
+}
+
+impl From<&Metadata> for NextMetadata {
+    fn from(metadata: &Metadata) -> Self {
+        let named = |name: &str| match metadata.additional.get(name) {
+            Some(AdditionalValue::String(value)) => Some(value.clone()),
+            _ => None,
+        };
+        let other = metadata
+            .additional
+            .iter()
+            .filter(|(name, _)| !NAMED_FIELDS.iter().any(|(_, tag)| tag == name))
+            .filter_map(|(name, value)| {
+                let value = match value {
+                    AdditionalValue::String(value) => OneOrMany::One(Text::String(value.clone())),
+                    AdditionalValue::Array(values) => {
+                        OneOrMany::Many(values.iter().cloned().map(Text::String).collect())
+                    }
+                    AdditionalValue::Object(_) => return None,
+                };
+                Some((name.clone(), value))
+            })
+            .collect();
+
+        NextMetadata {
+            metadata_base: metadata.metadata_base.clone(),
+            title: metadata.title.as_ref().map(NextTitle::from),
+            description: metadata.description.clone(),
+            application_name: named("application-name"),
+            generator: named("generator"),
+            creator: named("creator"),
+            publisher: named("publisher"),
+            category: named("category"),
+            classification: named("classification"),
+            keywords: metadata.keywords.as_ref().map(|keywords| match keywords {
+                Keywords::Single(keyword) => OneOrMany::One(keyword.clone()),
+                Keywords::Multiple(keywords) => OneOrMany::Many(keywords.clone()),
+            }),
+            authors: metadata.authors.as_ref().map(|authors| match authors {
+                Authors::Single(author) => OneOrMany::One(author.into()),
+                Authors::Multiple(authors) => {
+                    OneOrMany::Many(authors.iter().map(NextAuthor::from).collect())
+                }
+            }),
+            robots: metadata.robots.as_ref().map(NextRobots::from),
+            alternates: NextAlternates::from_metadata(metadata),
+            open_graph: metadata.open_graph.as_ref().map(NextOpenGraph::from),
+            twitter: metadata.twitter.as_ref().map(NextTwitter::from),
+            viewport: metadata.viewport.as_ref().map(NextViewport::from),
+            theme_color: metadata.theme_color.clone().map(OneOrMany::One),
+            color_scheme: metadata.color_scheme.as_ref().map(NextColorScheme::from),
+            referrer: metadata.referrer.as_ref().map(ToString::to_string),
+            format_detection: metadata.format_detection.clone(),
+            icons: metadata.icons.as_ref().map(NextIcons::from),
+            manifest: metadata.manifest.clone(),
+            verification: metadata.verification.as_ref().map(NextVerification::from),
+            apple_web_app: metadata.apple_web_app.as_ref().map(NextAppleWebApp::from),
+            itunes: metadata.itunes.as_ref().map(|itunes| NextItunes {
+                app_id: Text::String(itunes.app_id.clone()),
+                app_argument: itunes.app_argument.clone(),
+            }),
+            app_links: metadata.app_links.clone(),
+            other,
+        }
+    }
+}
+
+/// `"Title"` or `{ "template", "default" }` or `{ "absolute" }`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextTitle {
+    Plain(String),
+    Object {
+        default: Option<String>,
+        template: Option<String>,
+        absolute: Option<String>,
+    },
+}
+
+impl NextTitle {
+    fn into_title(self) -> Title {
+        match self {
+            NextTitle::Plain(title) => Title::Static(title),
+            NextTitle::Object {
+                absolute: Some(absolute),
+                ..
+            } => Title::Absolute(absolute),
+            NextTitle::Object {
+                template: Some(template),
+                default,
+                ..
+            } => Title::Template {
+                template,
+                default: default.unwrap_or_default(),
+            },
+            NextTitle::Object { default, .. } => Title::Static(default.unwrap_or_default()),
+        }
+    }
+}
+
+impl From<&Title> for NextTitle {
+    fn from(title: &Title) -> Self {
+        match title {
+            Title::Static(title) => NextTitle::Plain(title.clone()),
+            Title::Template { template, default } => NextTitle::Object {
+                default: Some(default.clone()),
+                template: Some(template.clone()),
+                absolute: None,
+            },
+            Title::Absolute(title) => NextTitle::Object {
+                default: None,
+                template: None,
+                absolute: Some(title.clone()),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NextAuthor {
+    name: Option<String>,
+    url: Option<String>,
+}
+
+impl From<NextAuthor> for Author {
+    fn from(author: NextAuthor) -> Self {
+        Author {
+            name: author.name.unwrap_or_default(),
+            url: author.url,
+            email: None,
+        }
+    }
+}
+
+impl From<&Author> for NextAuthor {
+    fn from(author: &Author) -> Self {
+        NextAuthor {
+            name: Some(author.name.clone()).filter(|name| !name.is_empty()),
+            url: author.url.clone(),
+        }
+    }
+}
+
+/// `"noindex, nofollow"` or an object of directives
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextRobots {
+    Content(String),
+    Info(NextRobotsInfo),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NextRobotsInfo {
+    index: Option<bool>,
+    follow: Option<bool>,
+    noarchive: Option<bool>,
+    nosnippet: Option<bool>,
+    noimageindex: Option<bool>,
+    nocache: Option<bool>,
+    notranslate: Option<bool>,
+    unavailable_after: Option<String>,
+    #[serde(default, rename = "max-snippet")]
+    max_snippet: Option<i32>,
+    #[serde(default, rename = "max-image-preview")]
+    max_image_preview: Option<String>,
+    #[serde(default, rename = "max-video-preview")]
+    max_video_preview: Option<Text>,
+    #[serde(default, rename = "googleBot", skip_serializing_if = "Option::is_none")]
+    google_bot: Option<Box<NextRobots>>,
+    /// Other directives, such as `indexifembedded: true`
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl NextRobots {
+    fn into_robots(self) -> Result<Robots> {
+        let mut info = match self {
+            NextRobots::Content(content) => return Ok(parse_robots(&content, None)),
+            NextRobots::Info(info) => info,
+        };
+
+        let mut robots = Robots {
+            google_bot: info
+                .google_bot
+                .take()
+                .map(|google_bot| google_bot.into_bot_directives())
+                .transpose()?,
+            ..Default::default()
+        };
+        for (name, value) in &info.other {
+            match value {
+                Value::Bool(true) => robots.other.insert(name.clone(), String::new()),
+                Value::String(value) => robots.other.insert(name.clone(), value.clone()),
+                Value::Number(value) => robots.other.insert(name.clone(), value.to_string()),
+                _ => None,
+            };
+        }
+        let bot = info.into_bot_directives()?;
+        robots.index = bot.index;
+        robots.follow = bot.follow;
+        robots.directives = bot.directives;
+        Ok(robots)
+    }
+
+    fn into_bot_directives(self) -> Result<BotDirectives> {
+        match self {
+            NextRobots::Content(content) => Ok(parse_bot_directives(&content)),
+            NextRobots::Info(info) => info.into_bot_directives(),
+        }
+    }
+}
+
+impl NextRobotsInfo {
+    fn into_bot_directives(self) -> Result<BotDirectives> {
+        let unavailable_after = self
+            .unavailable_after
+            .map(|value| {
+                parse_date_time(&value).ok_or_else(|| invalid("robots.unavailable_after", value))
+            })
+            .transpose()?;
+        let max_image_preview = self
+            .max_image_preview
+            .map(|value| match value.as_str() {
+                "none" => Ok(MaxImagePreview::None),
+                "standard" => Ok(MaxImagePreview::Standard),
+                "large" => Ok(MaxImagePreview::Large),
+                _ => Err(invalid("robots.max-image-preview", value)),
+            })
+            .transpose()?;
+        let max_video_preview = self
+            .max_video_preview
+            .map(|value| match value {
+                Text::Number(ref number) => number
+                    .as_i64()
+                    .and_then(|number| number.try_into().ok())
+                    .ok_or_else(|| invalid("robots.max-video-preview", &value)),
+                Text::String(ref text) => text
+                    .parse()
+                    .map_err(|_| invalid("robots.max-video-preview", &value)),
+            })
+            .transpose()?;
+
+        Ok(BotDirectives {
+            index: self.index,
+            follow: self.follow,
+            directives: RobotsDirectives {
+                noarchive: self.noarchive,
+                nosnippet: self.nosnippet,
+                notranslate: self.notranslate,
+                noimageindex: self.noimageindex,
+                nocache: self.nocache,
+                unavailable_after,
+                max_snippet: self.max_snippet,
+                max_image_preview,
+                max_video_preview,
+            },
+        })
+    }
+
+    fn from_directives(
+        index: Option<bool>,
+        follow: Option<bool>,
+        directives: &RobotsDirectives,
+    ) -> Self {
+        NextRobotsInfo {
+            index,
+            follow,
+            noarchive: directives.noarchive,
+            nosnippet: directives.nosnippet,
+            noimageindex: directives.noimageindex,
+            nocache: directives.nocache,
+            notranslate: directives.notranslate,
+            unavailable_after: directives.unavailable_after.map(|time| time.to_rfc3339()),
+            max_snippet: directives.max_snippet,
+            max_image_preview: directives
+                .max_image_preview
+                .as_ref()
+                .map(ToString::to_string),
+            max_video_preview: directives
+                .max_video_preview
+                .map(|length| Text::Number(length.into())),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&Robots> for NextRobots {
+    fn from(robots: &Robots) -> Self {
+        let mut info =
+            NextRobotsInfo::from_directives(robots.index, robots.follow, &robots.directives);
+        info.google_bot = robots.google_bot.as_ref().map(|google_bot| {
+            Box::new(NextRobots::Info(NextRobotsInfo::from_directives(
+                google_bot.index,
+                google_bot.follow,
+                &google_bot.directives,
+            )))
+        });
+        info.other = robots
+            .other
+            .iter()
+            .map(|(name, value)| {
+                let value = match value.as_str() {
+                    "" => Value::Bool(true),
+                    value => Value::String(value.to_string()),
+                };
+                (name.clone(), value)
+            })
+            .collect();
+        NextRobots::Info(info)
+    }
+}
+
+/// RFC 3339 date-time, or a date at midnight UTC
+fn parse_date_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.to_utc())
+        .ok()
+        .or_else(|| {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+            Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+        })
+}
+
+/// A URL string or a descriptor; lists use their first descriptor
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextAlternateUrl {
+    Url(String),
+    Descriptor(AlternateDescriptor),
+    List(Vec<AlternateDescriptor>),
+}
+
+impl NextAlternateUrl {
+    fn into_descriptors(self) -> Vec<AlternateDescriptor> {
+        match self {
+            NextAlternateUrl::Url(url) => vec![AlternateDescriptor { url, title: None }],
+            NextAlternateUrl::Descriptor(descriptor) => vec![descriptor],
+            NextAlternateUrl::List(descriptors) => descriptors,
+        }
+    }
+
+    fn into_url(self) -> Option<String> {
+        self.into_descriptors()
+            .into_iter()
+            .next()
+            .map(|descriptor| descriptor.url)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NextAlternates {
+    canonical: Option<NextAlternateUrl>,
+    #[serde(default)]
+    languages: HashMap<String, NextAlternateUrl>,
+    #[serde(default)]
+    media: HashMap<String, NextAlternateUrl>,
+    #[serde(default)]
+    types: HashMap<String, NextAlternateUrl>,
+}
+
+impl NextAlternates {
+    fn into_alternates(self) -> Alternates {
+        let urls = |map: HashMap<String, NextAlternateUrl>| {
+            map.into_iter()
+                .filter_map(|(key, url)| Some((key, url.into_url()?)))
+                .collect()
+        };
+        Alternates {
+            canonical: self.canonical.and_then(NextAlternateUrl::into_url),
+            languages: urls(self.languages),
+            media: urls(self.media),
+            types: self
+                .types
+                .into_iter()
+                .map(|(mime_type, url)| (mime_type, url.into_descriptors()))
+                .collect(),
+        }
+    }
+
+    /// Alternates of `metadata`, including the canonical URL and alternate links
+    fn from_metadata(metadata: &Metadata) -> Option<Self> {
+        let mut alternates = NextAlternates {
+            canonical: metadata
+                .canonical_url()
+                .map(|url| NextAlternateUrl::Url(url.to_string())),
+            ..Default::default()
+        };
+
+        for link in &metadata.alternate_links {
+            let url = || NextAlternateUrl::Url(link.url.clone());
+            if let Some(ref hreflang) = link.hreflang {
+                alternates.languages.insert(hreflang.clone(), url());
+            } else if let Some(ref media) = link.media {
+                alternates.media.insert(media.clone(), url());
+            } else if let Some(ref mime_type) = link.type_ {
+                alternates.types.insert(mime_type.clone(), url());
+            }
+        }
+        if let Some(ref existing) = metadata.alternates {
+            for (language, url) in &existing.languages {
+                alternates
+                    .languages
+                    .insert(language.clone(), NextAlternateUrl::Url(url.clone()));
+            }
+            for (media, url) in &existing.media {
+                alternates
+                    .media
+                    .insert(media.clone(), NextAlternateUrl::Url(url.clone()));
+            }
+            for (mime_type, descriptors) in &existing.types {
+                let url = match descriptors.as_slice() {
+                    [AlternateDescriptor { url, title: None }] => {
+                        NextAlternateUrl::Url(url.clone())
+                    }
+                    descriptors => NextAlternateUrl::List(descriptors.to_vec()),
+                };
+                alternates.types.insert(mime_type.clone(), url);
+            }
+        }
+
+        let empty = alternates.canonical.is_none()
+            && alternates.languages.is_empty()
+            && alternates.media.is_empty()
+            && alternates.types.is_empty();
+        (!empty).then_some(alternates)
+    }
+}
+
+/// `"/og.png"` or an image descriptor
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextMedia {
+    Url(String),
+    Descriptor(NextMediaDescriptor),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextMediaDescriptor {
+    url: String,
+    secure_url: Option<String>,
+    alt: Option<String>,
+    #[serde(default, rename = "type")]
+    mime_type: Option<String>,
+    width: Option<Text>,
+    height: Option<Text>,
+}
+
+impl NextMedia {
+    fn into_descriptor(self) -> NextMediaDescriptor {
+        match self {
+            NextMedia::Url(url) => NextMediaDescriptor {
+                url,
+                ..Default::default()
+            },
+            NextMedia::Descriptor(descriptor) => descriptor,
+        }
+    }
+
+    /// The URL shorthand when only the URL is set
+    fn new(descriptor: NextMediaDescriptor) -> Self {
+        let url_only = descriptor.secure_url.is_none()
+            && descriptor.alt.is_none()
+            && descriptor.mime_type.is_none()
+            && descriptor.width.is_none()
+            && descriptor.height.is_none();
+        if url_only {
+            NextMedia::Url(descriptor.url)
+        } else {
+            NextMedia::Descriptor(descriptor)
+        }
+    }
+}
+
+fn images(images: Option<OneOrMany<NextMedia>>) -> Result<Vec<OgImage>> {
+    images
+        .map(OneOrMany::into_vec)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|image| {
+            let image = image.into_descriptor();
+            Ok(OgImage {
+                width: size(image.width, "openGraph.images.width")?,
+                height: size(image.height, "openGraph.images.height")?,
+                url: image.url,
+                secure_url: image.secure_url,
+                alt: image.alt,
+                type_: image.mime_type,
+            })
+        })
+        .collect()
+}
+
+/// `"https://…"` or a `{ url, disc, track }` reference
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextTrack {
+    Url(String),
+    Descriptor {
+        url: String,
+        disc: Option<u32>,
+        track: Option<u32>,
+    },
+}
+
+impl From<NextTrack> for MusicTrackRef {
+    fn from(track: NextTrack) -> Self {
+        match track {
+            NextTrack::Url(url) => MusicTrackRef {
+                url,
+                disc: None,
+                track: None,
+            },
+            NextTrack::Descriptor { url, disc, track } => MusicTrackRef { url, disc, track },
+        }
+    }
+}
+
+impl From<&MusicTrackRef> for NextTrack {
+    fn from(track: &MusicTrackRef) -> Self {
+        match track {
+            MusicTrackRef {
+                url,
+                disc: None,
+                track: None,
+            } => NextTrack::Url(url.clone()),
+            MusicTrackRef { url, disc, track } => NextTrack::Descriptor {
+                url: url.clone(),
+                disc: *disc,
+                track: *track,
+            },
+        }
+    }
+}
+
+/// `"https://…"` or a `{ url, role }` actor
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextActor {
+    Url(String),
+    Descriptor { url: String, role: Option<String> },
+}
+
+/// Open Graph fields, with the fields of every `type` at the top level
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextOpenGraph {
+    title: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    site_name: Option<String>,
+    locale: Option<String>,
+    alternate_locale: Option<OneOrMany<String>>,
+    r#type: Option<OgType>,
+    images: Option<OneOrMany<NextMedia>>,
+    videos: Option<OneOrMany<NextMedia>>,
+    audio: Option<OneOrMany<NextMedia>>,
+    published_time: Option<String>,
+    modified_time: Option<String>,
+    expiration_time: Option<String>,
+    authors: Option<OneOrMany<String>>,
+    section: Option<String>,
+    tags: Option<OneOrMany<String>>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    username: Option<String>,
+    gender: Option<String>,
+    isbn: Option<String>,
+    release_date: Option<String>,
+    duration: Option<u32>,
+    albums: Option<OneOrMany<NextTrack>>,
+    songs: Option<OneOrMany<NextTrack>>,
+    musicians: Option<OneOrMany<String>>,
+    creators: Option<OneOrMany<String>>,
+    actors: Option<OneOrMany<NextActor>>,
+    directors: Option<OneOrMany<String>>,
+    writers: Option<OneOrMany<String>>,
+    series: Option<String>,
+}
+
+impl NextOpenGraph {
+    fn into_open_graph(self) -> Result<OpenGraph> {
+        let videos = images(self.videos)?
+            .into_iter()
+            .map(|video| OgVideo {
+                url: video.url,
+                secure_url: video.secure_url,
+                width: video.width,
+                height: video.height,
+                type_: video.type_,
+                alt: video.alt,
+            })
+            .collect();
+        let audio = images(self.audio)?
+            .into_iter()
+            .map(|audio| OgAudio {
+                url: audio.url,
+                secure_url: audio.secure_url,
+                type_: audio.type_,
+            })
+            .collect();
+
+        let mut og = OpenGraph {
+            title: self.title,
+            description: self.description,
+            r#type: self.r#type,
+            images: images(self.images)?,
+            videos,
+            audio,
+            url: self.url,
+            site_name: self.site_name,
+            locale: self.locale,
+            alternate_locale: strings(self.alternate_locale),
+            ..Default::default()
+        };
+
+        let tags = optional_list(strings(self.tags));
+        let author = strings(self.authors).into_iter().next();
+        match og.r#type {
+            Some(OgType::Book) => {
+                og.book = Some(Book {
+                    author,
+                    isbn: self.isbn,
+                    release_date: self.release_date,
+                    tag: tags,
+                });
+            }
+            Some(OgType::Profile) => {
+                og.profile = Some(Profile {
+                    first_name: self.first_name,
+                    last_name: self.last_name,
+                    username: self.username,
+                    gender: self.gender,
+                });
+            }
+            Some(og_type) if og_type.is_music() => {
+                let tracks = |tracks: Option<OneOrMany<NextTrack>>| {
+                    tracks.map(|tracks| {
+                        tracks
+                            .into_vec()
+                            .into_iter()
+                            .map(MusicTrackRef::from)
+                            .collect()
+                    })
+                };
+                og.music = Some(Music {
+                    duration: self.duration,
+                    album: tracks(self.albums),
+                    song: tracks(self.songs),
+                    musician: optional_list(strings(self.musicians)),
+                    release_date: self.release_date,
+                    creator: strings(self.creators).into_iter().next(),
+                });
+            }
+            Some(og_type) if og_type.is_video() => {
+                let actors = self.actors.map(|actors| {
+                    actors
+                        .into_vec()
+                        .into_iter()
+                        .map(|actor| match actor {
+                            NextActor::Url(url) => VideoActor { url, role: None },
+                            NextActor::Descriptor { url, role } => VideoActor { url, role },
+                        })
+                        .collect()
+                });
+                og.video_info = Some(VideoInfo {
+                    actor: actors,
+                    director: optional_list(strings(self.directors)),
+                    writer: optional_list(strings(self.writers)),
+                    duration: self.duration,
+                    release_date: self.release_date,
+                    tag: tags,
+                    series: self.series,
+                });
+            }
+            _ => {
+                let article = Article {
+                    published_time: self.published_time,
+                    modified_time: self.modified_time,
+                    expiration_time: self.expiration_time,
+                    author,
+                    section: self.section,
+                    tag: tags,
+                };
+                if article != Article::default() {
+                    og.article = Some(article);
+                }
+            }
+        }
+
+        Ok(og)
+    }
+}
+
+impl From<&OpenGraph> for NextOpenGraph {
+    fn from(og: &OpenGraph) -> Self {
+        let media = |url: &str,
+                     secure_url: &Option<String>,
+                     alt: &Option<String>,
+                     mime_type: &Option<String>,
+                     width: Option<u32>,
+                     height: Option<u32>| {
+            NextMedia::new(NextMediaDescriptor {
+                url: url.to_string(),
+                secure_url: secure_url.clone(),
+                alt: alt.clone(),
+                mime_type: mime_type.clone(),
+                width: width.map(|width| Text::Number(width.into())),
+                height: height.map(|height| Text::Number(height.into())),
+            })
+        };
+        let list = |values: &Option<Vec<String>>| values.clone().and_then(one_or_many);
+
+        let mut next = NextOpenGraph {
+            title: og.title.clone(),
+            description: og.description.clone(),
+            url: og.url.clone(),
+            site_name: og.site_name.clone(),
+            locale: og.locale.clone(),
+            alternate_locale: one_or_many(og.alternate_locale.clone()),
+            r#type: og.r#type,
+            images: one_or_many(
+                og.images
+                    .iter()
+                    .map(|i| media(&i.url, &i.secure_url, &i.alt, &i.type_, i.width, i.height))
+                    .collect(),
+            ),
+            videos: one_or_many(
+                og.videos
+                    .iter()
+                    .map(|v| media(&v.url, &v.secure_url, &v.alt, &v.type_, v.width, v.height))
+                    .collect(),
+            ),
+            audio: one_or_many(
+                og.audio
+                    .iter()
+                    .map(|a| media(&a.url, &a.secure_url, &None, &a.type_, None, None))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        if let Some(ref article) = og.article {
+            next.published_time = article.published_time.clone();
+            next.modified_time = article.modified_time.clone();
+            next.expiration_time = article.expiration_time.clone();
+            next.authors = article.author.clone().map(OneOrMany::One);
+            next.section = article.section.clone();
+            next.tags = list(&article.tag);
+        }
+        if let Some(ref profile) = og.profile {
+            next.first_name = profile.first_name.clone();
+            next.last_name = profile.last_name.clone();
+            next.username = profile.username.clone();
+            next.gender = profile.gender.clone();
+        }
+        if let Some(ref book) = og.book {
+            next.authors = book.author.clone().map(OneOrMany::One);
+            next.isbn = book.isbn.clone();
+            next.release_date = book.release_date.clone();
+            next.tags = list(&book.tag);
+        }
+        if let Some(ref music) = og.music {
+            let tracks = |tracks: &Option<Vec<MusicTrackRef>>| {
+                tracks
+                    .as_ref()
+                    .and_then(|tracks| one_or_many(tracks.iter().map(NextTrack::from).collect()))
+            };
+            next.duration = music.duration;
+            next.albums = tracks(&music.album);
+            next.songs = tracks(&music.song);
+            next.musicians = list(&music.musician);
+            next.release_date = music.release_date.clone();
+            next.creators = music.creator.clone().map(OneOrMany::One);
+        }
+        if let Some(ref video) = og.video_info {
+            next.actors = video.actor.as_ref().and_then(|actors| {
+                one_or_many(
+                    actors
+                        .iter()
+                        .map(|actor| match actor.role {
+                            Some(ref role) => NextActor::Descriptor {
+                                url: actor.url.clone(),
+                                role: Some(role.clone()),
+                            },
+                            None => NextActor::Url(actor.url.clone()),
+                        })
+                        .collect(),
+                )
+            });
+            next.directors = list(&video.director);
+            next.writers = list(&video.writer);
+            next.duration = video.duration;
+            next.release_date = video.release_date.clone();
+            next.tags = list(&video.tag);
+            next.series = video.series.clone();
+        }
+        next
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum NextTwitterCard {
+    Summary,
+    SummaryLargeImage,
+    App,
+    Player,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextTwitter {
+    card: Option<NextTwitterCard>,
+    site: Option<String>,
+    site_id: Option<String>,
+    creator: Option<String>,
+    creator_id: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    images: Option<OneOrMany<NextMedia>>,
+    players: Option<OneOrMany<NextTwitterPlayer>>,
+    app: Option<NextTwitterApp>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextTwitterPlayer {
+    player_url: String,
+    stream_url: Option<String>,
+    width: Option<Text>,
+    height: Option<Text>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NextTwitterApp {
+    name: Option<String>,
+    #[serde(default)]
+    id: NextTwitterAppValues,
+    #[serde(default)]
+    url: NextTwitterAppValues,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NextTwitterAppValues {
+    iphone: Option<Text>,
+    ipad: Option<Text>,
+    googleplay: Option<Text>,
+}
+
+impl NextTwitter {
+    fn into_twitter(self) -> Result<Twitter> {
+        let image = self
+            .images
+            .and_then(OneOrMany::first)
+            .map(NextMedia::into_descriptor);
+        let player = self.players.and_then(OneOrMany::first);
+        let app = self.app.unwrap_or_default();
+        let name = |id: &Option<Text>| id.as_ref().and(app.name.clone());
+
+        Ok(Twitter {
+            card: self.card.map(|card| match card {
+                NextTwitterCard::Summary => TwitterCard::Summary,
+                NextTwitterCard::SummaryLargeImage => TwitterCard::SummaryLargeImage,
+                NextTwitterCard::App => TwitterCard::App,
+                NextTwitterCard::Player => TwitterCard::Player,
+            }),
+            site: self.site,
+            site_id: self.site_id,
+            creator: self.creator,
+            creator_id: self.creator_id,
+            title: self.title,
+            description: self.description,
+            image_alt: image.as_ref().and_then(|image| image.alt.clone()),
+            image: image.map(|image| image.url),
+            player_width: match player {
+                Some(ref player) => size(player.width.clone(), "twitter.players.width")?,
+                None => None,
+            },
+            player_height: match player {
+                Some(ref player) => size(player.height.clone(), "twitter.players.height")?,
+                None => None,
+            },
+            player_stream: player.as_ref().and_then(|player| player.stream_url.clone()),
+            player: player.map(|player| player.player_url),
+            app_name_iphone: name(&app.id.iphone),
+            app_name_ipad: name(&app.id.ipad),
+            app_name_googleplay: name(&app.id.googleplay),
+            app_id_iphone: app.id.iphone.map(Text::into_string),
+            app_id_ipad: app.id.ipad.map(Text::into_string),
+            app_id_googleplay: app.id.googleplay.map(Text::into_string),
+            app_url_iphone: app.url.iphone.map(Text::into_string),
+            app_url_ipad: app.url.ipad.map(Text::into_string),
+            app_url_googleplay: app.url.googleplay.map(Text::into_string),
+            other: HashMap::new(),
+        })
+    }
+}
+
+impl From<&Twitter> for NextTwitter {
+    fn from(twitter: &Twitter) -> Self {
+        let text = |value: &Option<String>| value.clone().map(Text::String);
+        let app = NextTwitterApp {
+            name: twitter
+                .app_name_iphone
+                .clone()
+                .or_else(|| twitter.app_name_ipad.clone())
+                .or_else(|| twitter.app_name_googleplay.clone()),
+            id: NextTwitterAppValues {
+                iphone: text(&twitter.app_id_iphone),
+                ipad: text(&twitter.app_id_ipad),
+                googleplay: text(&twitter.app_id_googleplay),
+            },
+            url: NextTwitterAppValues {
+                iphone: text(&twitter.app_url_iphone),
+                ipad: text(&twitter.app_url_ipad),
+                googleplay: text(&twitter.app_url_googleplay),
+            },
+        };
+
+        NextTwitter {
+            card: twitter.card.as_ref().map(|card| match card {
+                TwitterCard::Summary => NextTwitterCard::Summary,
+                TwitterCard::SummaryLargeImage => NextTwitterCard::SummaryLargeImage,
+                TwitterCard::App => NextTwitterCard::App,
+                TwitterCard::Player => NextTwitterCard::Player,
+            }),
+            site: twitter.site.clone(),
+            site_id: twitter.site_id.clone(),
+            creator: twitter.creator.clone(),
+            creator_id: twitter.creator_id.clone(),
+            title: twitter.title.clone(),
+            description: twitter.description.clone(),
+            images: twitter.image.as_ref().map(|url| {
+                OneOrMany::One(NextMedia::new(NextMediaDescriptor {
+                    url: url.clone(),
+                    alt: twitter.image_alt.clone(),
+                    ..Default::default()
+                }))
+            }),
+            players: twitter.player.as_ref().map(|url| {
+                OneOrMany::One(NextTwitterPlayer {
+                    player_url: url.clone(),
+                    stream_url: twitter.player_stream.clone(),
+                    width: twitter.player_width.map(|width| Text::Number(width.into())),
+                    height: twitter
+                        .player_height
+                        .map(|height| Text::Number(height.into())),
+                })
+            }),
+            app: Some(app),
+        }
+    }
+}
+
+/// `"width=device-width, initial-scale=1"` or a viewport object
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextViewport {
+    Content(String),
+    Layout(NextViewportLayout),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextViewportLayout {
+    width: Option<Text>,
+    height: Option<Text>,
+    initial_scale: Option<f32>,
+    minimum_scale: Option<f32>,
+    maximum_scale: Option<f32>,
+    user_scalable: Option<bool>,
+    viewport_fit: Option<String>,
+}
+
+impl NextViewport {
+    fn into_viewport(self) -> Result<Viewport> {
+        let layout = match self {
+            NextViewport::Content(content) => {
+                return parse_viewport(&content).ok_or_else(|| invalid("viewport", content))
+            }
+            NextViewport::Layout(layout) => layout,
+        };
+
+        let width = match layout.width {
+            Some(Text::String(ref width)) if width == "device-width" => {
+                Some(ViewportWidth::DeviceWidth)
+            }
+            Some(ref width) => Some(ViewportWidth::Pixels(width.to_u32("viewport.width")?)),
+            None => None,
+        };
+        let height = match layout.height {
+            Some(Text::String(ref height)) if height == "device-height" => {
+                Some(ViewportHeight::DeviceHeight)
+            }
+            Some(ref height) => Some(ViewportHeight::Pixels(height.to_u32("viewport.height")?)),
+            None => None,
+        };
+        let viewport_fit = layout
+            .viewport_fit
+            .map(|fit| match fit.as_str() {
+                "auto" => Ok(ViewportFit::Auto),
+                "contain" => Ok(ViewportFit::Contain),
+                "cover" => Ok(ViewportFit::Cover),
+                _ => Err(invalid("viewport.viewportFit", fit)),
+            })
+            .transpose()?;
+
+        Ok(Viewport {
+            width,
+            height,
+            initial_scale: layout.initial_scale,
+            minimum_scale: layout.minimum_scale,
+            maximum_scale: layout.maximum_scale,
+            user_scalable: layout.user_scalable,
+            viewport_fit,
+        })
+    }
+}
+
+impl From<&Viewport> for NextViewport {
+    fn from(viewport: &Viewport) -> Self {
+        NextViewport::Layout(NextViewportLayout {
+            width: viewport.width.as_ref().map(|width| match width {
+                ViewportWidth::DeviceWidth => Text::String("device-width".into()),
+                ViewportWidth::Pixels(width) => Text::Number((*width).into()),
+            }),
+            height: viewport.height.as_ref().map(|height| match height {
+                ViewportHeight::DeviceHeight => Text::String("device-height".into()),
+                ViewportHeight::Pixels(height) => Text::Number((*height).into()),
+            }),
+            initial_scale: viewport.initial_scale,
+            minimum_scale: viewport.minimum_scale,
+            maximum_scale: viewport.maximum_scale,
+            user_scalable: viewport.user_scalable,
+            viewport_fit: viewport.viewport_fit.as_ref().map(|fit| {
+                match fit {
+                    ViewportFit::Auto => "auto",
+                    ViewportFit::Contain => "contain",
+                    ViewportFit::Cover => "cover",
+                }
+                .to_string()
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum NextColorScheme {
+    Normal,
+    Light,
+    Dark,
+}
+
+impl From<NextColorScheme> for ColorScheme {
+    fn from(color_scheme: NextColorScheme) -> Self {
+        match color_scheme {
+            NextColorScheme::Normal => ColorScheme::Normal,
+            NextColorScheme::Light => ColorScheme::Light,
+            NextColorScheme::Dark => ColorScheme::Dark,
+        }
+    }
+}
+
+impl From<&ColorScheme> for NextColorScheme {
+    fn from(color_scheme: &ColorScheme) -> Self {
+        match color_scheme {
+            ColorScheme::Normal => NextColorScheme::Normal,
+            ColorScheme::Light => NextColorScheme::Light,
+            ColorScheme::Dark => NextColorScheme::Dark,
+        }
+    }
+}
+
+/// `"/icon.png"` or an icon descriptor
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextIcon {
+    Url(String),
+    Descriptor(NextIconDescriptor),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NextIconDescriptor {
+    url: String,
+    #[serde(default, rename = "type")]
+    mime_type: Option<String>,
+    sizes: Option<String>,
+    color: Option<String>,
+    rel: Option<String>,
+    media: Option<String>,
+}
+
+impl From<NextIcon> for IconDescriptor {
+    fn from(icon: NextIcon) -> Self {
+        match icon {
+            NextIcon::Url(url) => IconDescriptor {
+                url,
+                ..Default::default()
+            },
+            NextIcon::Descriptor(icon) => IconDescriptor {
+                url: icon.url,
+                sizes: icon.sizes,
+                type_: icon.mime_type,
+                media: icon.media,
+                rel: icon.rel,
+                color: icon.color,
+            },
+        }
+    }
+}
+
+impl From<&IconDescriptor> for NextIcon {
+    fn from(icon: &IconDescriptor) -> Self {
+        if *icon
+            == (IconDescriptor {
+                url: icon.url.clone(),
+                ..Default::default()
+            })
+        {
+            return NextIcon::Url(icon.url.clone());
+        }
+        NextIcon::Descriptor(NextIconDescriptor {
+            url: icon.url.clone(),
+            mime_type: icon.type_.clone(),
+            sizes: icon.sizes.clone(),
+            color: icon.color.clone(),
+            rel: icon.rel.clone(),
+            media: icon.media.clone(),
+        })
+    }
+}
+
+/// Icons as a URL, a list, or grouped by kind
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextIcons {
+    One(NextIcon),
+    List(Vec<NextIcon>),
+    Grouped(Box<NextIconGroups>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NextIconGroups {
+    icon: Option<OneOrMany<NextIcon>>,
+    shortcut: Option<OneOrMany<NextIcon>>,
+    apple: Option<OneOrMany<NextIcon>>,
+    other: Option<OneOrMany<NextIcon>>,
+}
+
+impl NextIcons {
+    fn into_icons(self) -> Icons {
+        let list = |icons: Option<OneOrMany<NextIcon>>| {
+            icons
+                .map(OneOrMany::into_vec)
+                .unwrap_or_default()
+                .into_iter()
+                .map(IconDescriptor::from)
+                .collect()
+        };
+        match self {
+            NextIcons::One(icon) => Icons {
+                icon: list(Some(OneOrMany::One(icon))),
+                ..Default::default()
+            },
+            NextIcons::List(icons) => Icons {
+                icon: list(Some(OneOrMany::Many(icons))),
+                ..Default::default()
+            },
+            NextIcons::Grouped(groups) => Icons {
+                icon: list(groups.icon),
+                apple: list(groups.apple),
+                shortcut: list(groups.shortcut),
+                other: list(groups.other),
+            },
+        }
+    }
+}
+
+impl From<&Icons> for NextIcons {
+    fn from(icons: &Icons) -> Self {
+        let list =
+            |icons: &[IconDescriptor]| one_or_many(icons.iter().map(NextIcon::from).collect());
+        NextIcons::Grouped(Box::new(NextIconGroups {
+            icon: list(&icons.icon),
+            shortcut: list(&icons.shortcut),
+            apple: list(&icons.apple),
+            other: list(&icons.other),
+        }))
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NextVerification {
+    google: Option<OneOrMany<Text>>,
+    yahoo: Option<OneOrMany<Text>>,
+    yandex: Option<OneOrMany<Text>>,
+    me: Option<OneOrMany<Text>>,
+    #[serde(default)]
+    other: HashMap<String, OneOrMany<Text>>,
+}
+
+impl From<NextVerification> for Verification {
+    fn from(verification: NextVerification) -> Self {
+        let tokens = |tokens: OneOrMany<Text>| -> Vec<String> {
+            tokens
+                .into_vec()
+                .into_iter()
+                .map(Text::into_string)
+                .collect()
+        };
+        Verification {
+            google: verification.google.map(tokens).unwrap_or_default(),
+            yandex: verification.yandex.map(tokens).unwrap_or_default(),
+            yahoo: verification.yahoo.map(tokens).unwrap_or_default(),
+            me: verification.me.map(tokens).unwrap_or_default(),
+            other: verification
+                .other
+                .into_iter()
+                .map(|(name, values)| (name, tokens(values)))
+                .collect(),
+        }
+    }
+}
+
+impl From<&Verification> for NextVerification {
+    fn from(verification: &Verification) -> Self {
+        let tokens =
+            |tokens: &[String]| one_or_many(tokens.iter().cloned().map(Text::String).collect());
+        NextVerification {
+            google: tokens(&verification.google),
+            yahoo: tokens(&verification.yahoo),
+            yandex: tokens(&verification.yandex),
+            me: tokens(&verification.me),
+            other: verification
+                .other
+                .iter()
+                .filter_map(|(name, values)| Some((name.clone(), tokens(values)?)))
+                .collect(),
+        }
+    }
+}
+
+/// `true` or the web app settings
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextAppleWebApp {
+    Capable(bool),
+    Settings(NextAppleWebAppSettings),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextAppleWebAppSettings {
+    capable: Option<bool>,
+    title: Option<String>,
+    status_bar_style: Option<AppleStatusBarStyle>,
+    startup_image: Option<OneOrMany<NextStartupImage>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum NextStartupImage {
+    Url(String),
+    Descriptor(AppleStartupImage),
+}
+
+impl From<NextAppleWebApp> for AppleWebApp {
+    fn from(apple_web_app: NextAppleWebApp) -> Self {
+        match apple_web_app {
+            NextAppleWebApp::Capable(capable) => AppleWebApp {
+                capable: Some(capable),
+                ..Default::default()
+            },
+            NextAppleWebApp::Settings(settings) => AppleWebApp {
+                capable: settings.capable,
+                title: settings.title,
+                status_bar_style: settings.status_bar_style,
+                startup_image: settings
+                    .startup_image
+                    .map(OneOrMany::into_vec)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|image| match image {
+                        NextStartupImage::Url(url) => AppleStartupImage { url, media: None },
+                        NextStartupImage::Descriptor(image) => image,
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<&AppleWebApp> for NextAppleWebApp {
+    fn from(apple_web_app: &AppleWebApp) -> Self {
+        NextAppleWebApp::Settings(NextAppleWebAppSettings {
+            capable: apple_web_app.capable,
+            title: apple_web_app.title.clone(),
+            status_bar_style: apple_web_app.status_bar_style,
+            startup_image: one_or_many(
+                apple_web_app
+                    .startup_image
+                    .iter()
+                    .map(|image| match image.media {
+                        Some(_) => NextStartupImage::Descriptor(image.clone()),
+                        None => NextStartupImage::Url(image.url.clone()),
+                    })
+                    .collect(),
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NextItunes {
+    app_id: Text,
+    app_argument: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_shapes() {
+        let title = |json: &str| {
+            Metadata::from_nextjs_json(&format!(r#"{{"title": {}}}"#, json))
+                .unwrap()
+                .title
+                .unwrap()
+        };
+        assert_eq!(title(r#""Home""#), Title::Static("Home".into()));
+        assert_eq!(
+            title(r#"{"default": "Home"}"#),
+            Title::Static("Home".into())
+        );
+        assert_eq!(
+            title(r#"{"absolute": "Home", "template": "%s"}"#),
+            Title::Absolute("Home".into())
+        );
+        assert_eq!(
+            title(r#"{"template": "%s | Acme", "default": "Acme"}"#),
+            Title::Template {
+                template: "%s | Acme".into(),
+                default: "Acme".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_strip_empty() {
+        let mut value = serde_json::json!({
+            "title": "Home",
+            "description": null,
+            "alternates": { "languages": {}, "canonical": null },
+            "keywords": [],
+            "robots": { "index": false }
+        });
+        strip_empty(&mut value);
+        assert_eq!(
+            value,
+            serde_json::json!({ "title": "Home", "robots": { "index": false } })
+        );
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert!(matches!(
+            Metadata::from_nextjs_json(r#"{"referrer": "sometimes"}"#),
+            Err(Error::InvalidMetadata(_))
+        ));
+        assert!(matches!(
+            Metadata::from_nextjs_json(r#"{"title": 42}"#),
+            Err(Error::SerializationError(_))
+        ));
+    }
+}
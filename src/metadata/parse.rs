@@ -0,0 +1,1291 @@
+//! HTML head parsing for metadata
+//!
+//! This module is the inverse of [`render`](super::render): it reads the
+//! `<title>`, `<meta>`, `<link>` and JSON-LD `<script>` tags of an HTML
+//! document back into a typed [`Metadata`] value. Parsing is lenient like a
+//! browser: malformed tags are skipped, and tags without a typed field are kept
+//! in [`Metadata::additional`].
+
+use super::types::*;
+use chrono::DateTime;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Crawlers whose `<meta name="...">` tags are read as robots overrides
+const CRAWLERS: &[&str] = &[
+    "googlebot-news",
+    "googlebot-image",
+    "googlebot-video",
+    "bingbot",
+    "msnbot",
+    "slurp",
+    "duckduckbot",
+    "baiduspider",
+    "yandex",
+    "applebot",
+];
+
+impl Metadata {
+    /// Parse the metadata tags of an HTML document
+    ///
+    /// Only the `<head>` is read when the document has one; otherwise the whole
+    /// input is treated as a head fragment, so the output of
+    /// [`Metadata::to_head_html`] parses back into equivalent metadata.
+    ///
+    /// `<link rel="alternate">` tags with only an `hreflang`, only a `media` or
+    /// a `type` become entries of [`Metadata::alternates`]; any other
+    /// combination becomes an [`AlternateLink`]. Unknown `<meta>` tags are kept
+    /// in `additional` under their name or property, unknown `<link>` tags under
+    /// `link:{rel}`, and repeated tags are collected into an array.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leptos_next_metadata::metadata::{Metadata, OgType, Title};
+    ///
+    /// let metadata = Metadata::from_html(
+    ///     r#"<!DOCTYPE html>
+    ///     <html><head>
+    ///       <title>Pricing &amp; plans</title>
+    ///       <meta property="og:type" content="website">
+    ///       <meta property="og:image" content="https://example.com/og.png">
+    ///       <meta property="og:image:width" content="1200">
+    ///     </head><body></body></html>"#,
+    /// );
+    ///
+    /// assert_eq!(metadata.title, Some(Title::Static("Pricing & plans".into())));
+    /// let open_graph = metadata.open_graph.unwrap();
+    /// assert_eq!(open_graph.r#type, Some(OgType::Website));
+    /// assert_eq!(open_graph.images[0].width, Some(1200));
+    /// ```
+    pub fn from_html(html: &str) -> Self {
+        let mut parser = Parser::default();
+        for tag in tokenize(head(html)) {
+            parser.tag(tag);
+        }
+        let mut metadata = parser.finish();
+        metadata.lang = html_tag(html)
+            .and_then(|(_, tag)| tag.attr("lang").map(str::to_string))
+            .filter(|lang| !lang.is_empty());
+        metadata
+    }
+}
+
+/// A start tag with its decoded attributes and, for `<title>` and `<script>`,
+/// its text content
+#[derive(Debug)]
+pub(crate) struct Tag {
+    name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    text: Option<String>,
+}
+
+impl Tag {
+    pub(crate) fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The contents of the `<head>` element, or the whole input without one
+fn head(html: &str) -> &str {
+    let lower = html.to_ascii_lowercase();
+    let start = lower
+        .find("<head")
+        .filter(|&i| {
+            // Skip `<header>`
+            matches!(
+                lower.as_bytes().get(i + 5),
+                Some(b'>' | b' ' | b'\t' | b'\n' | b'\r' | b'/')
+            )
+        })
+        .and_then(|i| lower[i..].find('>').map(|end| i + end + 1))
+        .unwrap_or(0);
+    let end = lower[start..]
+        .find("</head")
+        .map_or(html.len(), |i| start + i);
+    &html[start..end]
+}
+
+/// Byte range and contents of the `<html>` start tag, if there is one
+pub(crate) fn html_tag(html: &str) -> Option<(Range<usize>, Tag)> {
+    let lower = html.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(offset) = lower[from..].find("<html") {
+        let start = from + offset;
+        from = start + 5;
+        if matches!(
+            lower.as_bytes().get(from),
+            Some(b'>' | b' ' | b'\t' | b'\n' | b'\r' | b'/')
+        ) {
+            let (attributes, consumed) = parse_attributes(&html[from..]);
+            let tag = Tag {
+                name: "html".to_string(),
+                attributes,
+                text: None,
+            };
+            return Some((start..from + consumed, tag));
+        }
+    }
+    None
+}
+
+/// Split HTML into start tags, skipping comments, end tags and text
+fn tokenize(html: &str) -> Vec<Tag> {
+    let lower = html.to_ascii_lowercase();
+    let bytes = html.as_bytes();
+    let mut tags = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = html[pos..].find('<') {
+        pos += offset;
+        let rest = &html[pos..];
+
+        if rest.starts_with("<!--") {
+            pos = rest.find("-->").map_or(html.len(), |i| pos + i + 3);
+            continue;
+        }
+        if !bytes.get(pos + 1).is_some_and(u8::is_ascii_alphabetic) {
+            // End tag, doctype, processing instruction or a stray `<`
+            pos = rest.find('>').map_or(html.len(), |i| pos + i + 1);
+            continue;
+        }
+
+        let name_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[1..name_end].to_ascii_lowercase();
+        let (attributes, consumed) = parse_attributes(&rest[name_end..]);
+        pos += name_end + consumed;
+
+        // Raw text elements: their content is not markup
+        let mut text = None;
+        if matches!(name.as_str(), "title" | "script" | "style" | "textarea") {
+            let close = format!("</{}", name);
+            let end = lower[pos..].find(&close).map_or(html.len(), |i| pos + i);
+            if name == "title" {
+                text = Some(decode_entities(html[pos..end].trim()));
+            } else if name == "script" {
+                text = Some(html[pos..end].trim().to_string());
+            }
+            pos = end;
+        }
+
+        tags.push(Tag {
+            name,
+            attributes,
+            text,
+        });
+    }
+
+    tags
+}
+
+/// Parse the attributes of a start tag up to and including its closing `>`
+///
+/// Returns the attributes with lowercase names and decoded values, and the
+/// number of bytes consumed.
+fn parse_attributes(input: &str) -> (Vec<(String, String)>, usize) {
+    let bytes = input.as_bytes();
+    let mut attributes = Vec::new();
+    let mut pos = 0;
+
+    loop {
+        while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b'/') {
+            pos += 1;
+        }
+        if pos >= bytes.len() {
+            return (attributes, pos);
+        }
+        if bytes[pos] == b'>' {
+            return (attributes, pos + 1);
+        }
+
+        let name_start = pos;
+        while pos < bytes.len()
+            && !bytes[pos].is_ascii_whitespace()
+            && !b"=>/".contains(&bytes[pos])
+        {
+            pos += 1;
+        }
+        let name = input[name_start..pos].to_ascii_lowercase();
+
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        let mut value = String::new();
+        if bytes.get(pos) == Some(&b'=') {
+            pos += 1;
+            while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+                pos += 1;
+            }
+            let (raw, end) = match bytes.get(pos) {
+                Some(&quote @ (b'"' | b'\'')) => {
+                    let start = pos + 1;
+                    let end = input[start..]
+                        .find(quote as char)
+                        .map_or(input.len(), |i| start + i);
+                    (&input[start..end], (end + 1).min(input.len()))
+                }
+                _ => {
+                    let start = pos;
+                    let end = input[start..]
+                        .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                        .map_or(input.len(), |i| start + i);
+                    (&input[start..end], end)
+                }
+            };
+            value = decode_entities(raw);
+            pos = end;
+        }
+
+        if !name.is_empty() {
+            attributes.push((name, value));
+        }
+    }
+}
+
+/// Decode character references in text or attribute values
+fn decode_entities(value: &str) -> String {
+    if !value.contains('&') {
+        return value.to_string();
+    }
+
+    let mut decoded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(i) = rest.find('&') {
+        decoded.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .map(|end| &rest[1..=end]);
+        let ch = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => {
+                let number = entity.strip_prefix('#')?;
+                let code = match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => number.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+
+        match (ch, entity) {
+            (Some(ch), Some(entity)) => {
+                decoded.push(ch);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Builds metadata from tags in document order
+///
+/// Structured properties such as `og:image:width` describe the most recent
+/// media or reference tag, so the order of tags matters.
+#[derive(Default)]
+struct Parser {
+    metadata: Metadata,
+    json_ld: Vec<JsonLd>,
+    authors: Vec<Author>,
+    price_amount: Option<f64>,
+    price_currency: Option<String>,
+}
+
+impl Parser {
+    fn tag(&mut self, tag: Tag) {
+        match tag.name.as_str() {
+            "title" if self.metadata.title.is_none() => {
+                let title = tag.text.unwrap_or_default();
+                self.metadata.title = Some(Title::Static(title));
+            }
+            "meta" => self.meta(&tag),
+            "link" => self.link(&tag),
+            "script" => self.script(tag),
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Metadata {
+        match self.authors.len() {
+            0 => {}
+            1 => self.metadata.authors = self.authors.pop().map(Authors::Single),
+            _ => self.metadata.authors = Some(Authors::Multiple(std::mem::take(&mut self.authors))),
+        }
+
+        if let (Some(amount), Some(currency)) = (self.price_amount, self.price_currency.take()) {
+            self.product().price = Some(ProductPrice { amount, currency });
+        }
+
+        #[cfg(feature = "json-ld")]
+        {
+            self.metadata.json_ld = match self.json_ld.len() {
+                0 => None,
+                1 => self.json_ld.pop(),
+                _ => Some(serde_json::Value::Array(self.json_ld)),
+            };
+        }
+
+        self.metadata
+    }
+
+    fn meta(&mut self, tag: &Tag) {
+        let content = tag.attr("content").unwrap_or_default().to_string();
+
+        if let Some(property) = tag.attr("property") {
+            if !self.property(property, content.clone()) {
+                add_additional(&mut self.metadata.additional, property, content);
+            }
+        } else if let Some(name) = tag.attr("name") {
+            let name = name.to_ascii_lowercase();
+            if !self.name(&name, content.clone(), tag.attr("media")) {
+                add_additional(&mut self.metadata.additional, &name, content);
+            }
+        } else if let Some(charset) = tag.attr("charset") {
+            add_additional(
+                &mut self.metadata.additional,
+                "charset",
+                charset.to_string(),
+            );
+        } else if let Some(http_equiv) = tag.attr("http-equiv") {
+            let key = format!("http-equiv:{}", http_equiv.to_ascii_lowercase());
+            add_additional(&mut self.metadata.additional, &key, content);
+        }
+    }
+
+    /// Read a `<meta name>` tag, returning whether it has a typed field
+    fn name(&mut self, name: &str, content: String, media: Option<&str>) -> bool {
+        let metadata = &mut self.metadata;
+        match name {
+            "description" => metadata.description = Some(content),
+            "keywords" => {
+                let mut keywords: Vec<String> = content
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|keyword| !keyword.is_empty())
+                    .map(String::from)
+                    .collect();
+                metadata.keywords = match keywords.len() {
+                    0 => return false,
+                    1 => keywords.pop().map(Keywords::Single),
+                    _ => Some(Keywords::Multiple(keywords)),
+                };
+            }
+            "author" => self.authors.push(Author {
+                name: content,
+                url: None,
+                email: None,
+            }),
+            "robots" => metadata.robots = Some(parse_robots(&content, metadata.robots.take())),
+            "googlebot" => {
+                metadata
+                    .robots
+                    .get_or_insert_with(Robots::default)
+                    .google_bot = Some(parse_bot_directives(&content));
+            }
+            name if CRAWLERS.contains(&name) => {
+                metadata
+                    .robots
+                    .get_or_insert_with(Robots::default)
+                    .bots
+                    .insert(name.to_string(), parse_bot_directives(&content));
+            }
+            "viewport" => match parse_viewport(&content) {
+                Some(viewport) => metadata.viewport = Some(viewport),
+                None => return false,
+            },
+            "theme-color" => {
+                if metadata.theme_color.is_some() {
+                    return false;
+                }
+                metadata.theme_color = Some(ThemeColor {
+                    color: content,
+                    media: media.map(String::from),
+                });
+            }
+            "color-scheme" => {
+                metadata.color_scheme = Some(match content.trim() {
+                    "light" => ColorScheme::Light,
+                    "dark" => ColorScheme::Dark,
+                    "normal" => ColorScheme::Normal,
+                    _ => return false,
+                });
+            }
+            "referrer" => match parse_referrer(&content) {
+                Some(referrer) => metadata.referrer = Some(referrer),
+                None => return false,
+            },
+            "format-detection" => match parse_format_detection(&content) {
+                Some(format_detection) => metadata.format_detection = Some(format_detection),
+                None => return false,
+            },
+            "google-site-verification" => self.verification().google.push(content),
+            "yandex-verification" => self.verification().yandex.push(content),
+            "y_key" => self.verification().yahoo.push(content),
+            "me" => self.verification().me.push(content),
+            "apple-itunes-app" => match parse_itunes(&content) {
+                Some(itunes) => metadata.itunes = Some(itunes),
+                None => return false,
+            },
+            "apple-mobile-web-app-capable" => {
+                self.apple_web_app().capable = Some(content.eq_ignore_ascii_case("yes"));
+            }
+            "apple-mobile-web-app-title" => self.apple_web_app().title = Some(content),
+            "apple-mobile-web-app-status-bar-style" => {
+                let style = match content.as_str() {
+                    "default" => AppleStatusBarStyle::Default,
+                    "black" => AppleStatusBarStyle::Black,
+                    "black-translucent" => AppleStatusBarStyle::BlackTranslucent,
+                    _ => return false,
+                };
+                self.apple_web_app().status_bar_style = Some(style);
+            }
+            name if name.starts_with("twitter:") => return self.twitter(name, content),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Read a `<meta property>` tag, returning whether it has a typed field
+    fn property(&mut self, property: &str, content: String) -> bool {
+        match property.split_once(':') {
+            Some(("og", key)) => self.open_graph_property(key, content),
+            Some(("article", key)) => self.article(key, content),
+            Some(("profile", key)) => self.profile(key, content),
+            Some(("book", key)) => self.book(key, content),
+            Some(("music", key)) => self.music(key, content),
+            Some(("video", key)) => self.video(key, content),
+            Some(("product", key)) => self.product_property(key, content),
+            Some(("al", key)) => self.app_link(key, content),
+            // Some sites use `property` for Twitter tags
+            Some(("twitter", _)) => self.twitter(property, content),
+            _ => false,
+        }
+    }
+
+    fn open_graph_property(&mut self, key: &str, content: String) -> bool {
+        let og = self.open_graph();
+        match key {
+            "title" => og.title = Some(content),
+            "description" => og.description = Some(content),
+            "url" => og.url = Some(content),
+            "site_name" => og.site_name = Some(content),
+            "locale" => og.locale = Some(content),
+            "locale:alternate" => og.alternate_locale.push(content),
+            "type" => match content.parse() {
+                Ok(og_type) => og.r#type = Some(og_type),
+                Err(_) => {
+                    og.other.insert("type".to_string(), content);
+                }
+            },
+            "image" | "image:url" => og.images.push(OgImage::from(content)),
+            "video" | "video:url" => og.videos.push(OgVideo {
+                url: content,
+                ..Default::default()
+            }),
+            "audio" | "audio:url" => og.audio.push(OgAudio {
+                url: content,
+                ..Default::default()
+            }),
+            _ => {
+                if !media_property(og, key, &content) {
+                    og.other.insert(key.to_string(), content);
+                }
+            }
+        }
+        true
+    }
+
+    fn article(&mut self, key: &str, content: String) -> bool {
+        let article = self
+            .open_graph()
+            .article
+            .get_or_insert_with(Article::default);
+        match key {
+            "published_time" => article.published_time = Some(content),
+            "modified_time" => article.modified_time = Some(content),
+            "expiration_time" => article.expiration_time = Some(content),
+            "author" => article.author = Some(content),
+            "section" => article.section = Some(content),
+            "tag" => article.tag.get_or_insert_with(Vec::new).push(content),
+            _ => return self.open_graph_other(key, "article", content),
+        }
+        true
+    }
+
+    fn profile(&mut self, key: &str, content: String) -> bool {
+        let profile = self
+            .open_graph()
+            .profile
+            .get_or_insert_with(Profile::default);
+        match key {
+            "first_name" => profile.first_name = Some(content),
+            "last_name" => profile.last_name = Some(content),
+            "username" => profile.username = Some(content),
+            "gender" => profile.gender = Some(content),
+            _ => return self.open_graph_other(key, "profile", content),
+        }
+        true
+    }
+
+    fn book(&mut self, key: &str, content: String) -> bool {
+        let book = self.open_graph().book.get_or_insert_with(Book::default);
+        match key {
+            "author" => book.author = Some(content),
+            "isbn" => book.isbn = Some(content),
+            "release_date" => book.release_date = Some(content),
+            "tag" => book.tag.get_or_insert_with(Vec::new).push(content),
+            _ => return self.open_graph_other(key, "book", content),
+        }
+        true
+    }
+
+    fn music(&mut self, key: &str, content: String) -> bool {
+        let music = self.open_graph().music.get_or_insert_with(Music::default);
+        let handled = match key {
+            "duration" => set_number(&mut music.duration, &content),
+            "release_date" => {
+                music.release_date = Some(content.clone());
+                true
+            }
+            "creator" => {
+                music.creator = Some(content.clone());
+                true
+            }
+            "musician" => {
+                music
+                    .musician
+                    .get_or_insert_with(Vec::new)
+                    .push(content.clone());
+                true
+            }
+            "album" | "song" => {
+                let list = if key == "album" {
+                    &mut music.album
+                } else {
+                    &mut music.song
+                };
+                list.get_or_insert_with(Vec::new).push(MusicTrackRef {
+                    url: content.clone(),
+                    disc: None,
+                    track: None,
+                });
+                true
+            }
+            _ => match key.split_once(':') {
+                Some((list @ ("album" | "song"), field @ ("disc" | "track"))) => {
+                    let list = if list == "album" {
+                        &mut music.album
+                    } else {
+                        &mut music.song
+                    };
+                    match list.as_mut().and_then(|list| list.last_mut()) {
+                        Some(track) if field == "disc" => set_number(&mut track.disc, &content),
+                        Some(track) => set_number(&mut track.track, &content),
+                        None => false,
+                    }
+                }
+                _ => false,
+            },
+        };
+
+        handled || self.open_graph_other(key, "music", content)
+    }
+
+    fn video(&mut self, key: &str, content: String) -> bool {
+        let video = self
+            .open_graph()
+            .video_info
+            .get_or_insert_with(VideoInfo::default);
+        let handled = match key {
+            "actor" => {
+                video.actor.get_or_insert_with(Vec::new).push(VideoActor {
+                    url: content.clone(),
+                    role: None,
+                });
+                true
+            }
+            "actor:role" => match video.actor.as_mut().and_then(|actors| actors.last_mut()) {
+                Some(actor) => {
+                    actor.role = Some(content.clone());
+                    true
+                }
+                None => false,
+            },
+            "director" => {
+                video
+                    .director
+                    .get_or_insert_with(Vec::new)
+                    .push(content.clone());
+                true
+            }
+            "writer" => {
+                video
+                    .writer
+                    .get_or_insert_with(Vec::new)
+                    .push(content.clone());
+                true
+            }
+            "tag" => {
+                video.tag.get_or_insert_with(Vec::new).push(content.clone());
+                true
+            }
+            "duration" => set_number(&mut video.duration, &content),
+            "release_date" => {
+                video.release_date = Some(content.clone());
+                true
+            }
+            "series" => {
+                video.series = Some(content.clone());
+                true
+            }
+            _ => false,
+        };
+
+        handled || self.open_graph_other(key, "video", content)
+    }
+
+    fn product_property(&mut self, key: &str, content: String) -> bool {
+        match key {
+            "price:amount" => match content.trim().parse() {
+                Ok(amount) => self.price_amount = Some(amount),
+                Err(_) => return self.open_graph_other(key, "product", content),
+            },
+            "price:currency" => self.price_currency = Some(content),
+            "availability" => self.product().availability = Some(content),
+            "condition" => self.product().condition = Some(content),
+            "brand" => self.product().brand = Some(content),
+            "retailer_item_id" => self.product().retailer_item_id = Some(content),
+            _ => return self.open_graph_other(key, "product", content),
+        }
+        true
+    }
+
+    /// Keep a namespaced Open Graph property without a typed field
+    fn open_graph_other(&mut self, key: &str, namespace: &str, content: String) -> bool {
+        self.open_graph()
+            .other
+            .insert(format!("{}:{}", namespace, key), content);
+        true
+    }
+
+    fn twitter(&mut self, name: &str, content: String) -> bool {
+        let Some(key) = name.strip_prefix("twitter:") else {
+            return false;
+        };
+        let twitter = self.metadata.twitter.get_or_insert_with(Twitter::default);
+        let field = match key {
+            "card" => {
+                twitter.card = match content.as_str() {
+                    "summary" => Some(TwitterCard::Summary),
+                    "summary_large_image" => Some(TwitterCard::SummaryLargeImage),
+                    "app" => Some(TwitterCard::App),
+                    "player" => Some(TwitterCard::Player),
+                    _ => {
+                        twitter.other.insert(key.to_string(), content);
+                        return true;
+                    }
+                };
+                return true;
+            }
+            "player:width" | "player:height" => {
+                let size = if key == "player:width" {
+                    &mut twitter.player_width
+                } else {
+                    &mut twitter.player_height
+                };
+                if !set_number(size, &content) {
+                    twitter.other.insert(key.to_string(), content);
+                }
+                return true;
+            }
+            "site" => &mut twitter.site,
+            "site:id" => &mut twitter.site_id,
+            "creator" => &mut twitter.creator,
+            "creator:id" => &mut twitter.creator_id,
+            "title" => &mut twitter.title,
+            "description" => &mut twitter.description,
+            "image" | "image:src" => &mut twitter.image,
+            "image:alt" => &mut twitter.image_alt,
+            "player" => &mut twitter.player,
+            "player:stream" => &mut twitter.player_stream,
+            "app:name:iphone" => &mut twitter.app_name_iphone,
+            "app:id:iphone" => &mut twitter.app_id_iphone,
+            "app:url:iphone" => &mut twitter.app_url_iphone,
+            "app:name:ipad" => &mut twitter.app_name_ipad,
+            "app:id:ipad" => &mut twitter.app_id_ipad,
+            "app:url:ipad" => &mut twitter.app_url_ipad,
+            "app:name:googleplay" => &mut twitter.app_name_googleplay,
+            "app:id:googleplay" => &mut twitter.app_id_googleplay,
+            "app:url:googleplay" => &mut twitter.app_url_googleplay,
+            _ => {
+                twitter.other.insert(key.to_string(), content);
+                return true;
+            }
+        };
+        *field = Some(content);
+        true
+    }
+
+    /// Read an `al:{platform}:{property}` tag
+    ///
+    /// The first property of an app (`url`, or `package` on Android) starts a
+    /// new entry; the other properties describe the latest entry.
+    fn app_link(&mut self, key: &str, content: String) -> bool {
+        let Some((platform, property)) = key.split_once(':') else {
+            return false;
+        };
+        let app_links = self
+            .metadata
+            .app_links
+            .get_or_insert_with(AppLinks::default);
+
+        match platform {
+            "ios" | "iphone" | "ipad" => {
+                let apps = match platform {
+                    "ios" => &mut app_links.ios,
+                    "iphone" => &mut app_links.iphone,
+                    _ => &mut app_links.ipad,
+                };
+                if property == "url" || apps.is_empty() {
+                    apps.push(AppLinksApple::default());
+                }
+                let Some(app) = apps.last_mut() else {
+                    return false;
+                };
+                match property {
+                    "url" => app.url = content,
+                    "app_store_id" => app.app_store_id = Some(content),
+                    "app_name" => app.app_name = Some(content),
+                    _ => return false,
+                }
+            }
+            "android" => {
+                let apps = &mut app_links.android;
+                if property == "package" || apps.is_empty() {
+                    apps.push(AppLinksAndroid::default());
+                }
+                let Some(app) = apps.last_mut() else {
+                    return false;
+                };
+                match property {
+                    "package" => app.package = content,
+                    "url" => app.url = Some(content),
+                    "class" => app.class = Some(content),
+                    "app_name" => app.app_name = Some(content),
+                    _ => return false,
+                }
+            }
+            "windows_phone" | "windows" | "windows_universal" => {
+                let apps = match platform {
+                    "windows_phone" => &mut app_links.windows_phone,
+                    "windows" => &mut app_links.windows,
+                    _ => &mut app_links.windows_universal,
+                };
+                if property == "url" || apps.is_empty() {
+                    apps.push(AppLinksWindows::default());
+                }
+                let Some(app) = apps.last_mut() else {
+                    return false;
+                };
+                match property {
+                    "url" => app.url = content,
+                    "app_id" => app.app_id = Some(content),
+                    "app_name" => app.app_name = Some(content),
+                    _ => return false,
+                }
+            }
+            "web" => {
+                let web = &mut app_links.web;
+                if property == "url" || web.is_empty() {
+                    web.push(AppLinksWeb::default());
+                }
+                let Some(entry) = web.last_mut() else {
+                    return false;
+                };
+                match property {
+                    "url" => entry.url = Some(content),
+                    "should_fallback" => entry.should_fallback = Some(content != "false"),
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn link(&mut self, tag: &Tag) {
+        let Some(rel) = tag.attr("rel") else {
+            return;
+        };
+        let rel = rel.to_ascii_lowercase();
+        let href = tag.attr("href").unwrap_or_default().to_string();
+        let attr = |name: &str| tag.attr(name).map(String::from);
+
+        match rel.as_str() {
+            "canonical" => self.metadata.canonical = Some(href),
+            "manifest" => self.metadata.manifest = Some(href),
+            "author" => match self.authors.last_mut() {
+                Some(author) if author.url.is_none() => author.url = Some(href),
+                _ => add_additional(&mut self.metadata.additional, "link:author", href),
+            },
+            "alternate" => self.alternate(tag, href),
+            "apple-touch-startup-image" => {
+                self.apple_web_app().startup_image.push(AppleStartupImage {
+                    url: href,
+                    media: attr("media"),
+                });
+            }
+            "icon"
+            | "shortcut icon"
+            | "apple-touch-icon"
+            | "apple-touch-icon-precomposed"
+            | "mask-icon" => {
+                let icons = self.metadata.icons.get_or_insert_with(Icons::default);
+                let (list, rel) = match rel.as_str() {
+                    "icon" => (&mut icons.icon, None),
+                    "shortcut icon" => (&mut icons.shortcut, None),
+                    "apple-touch-icon" => (&mut icons.apple, None),
+                    _ => (&mut icons.other, Some(rel.clone())),
+                };
+                list.push(IconDescriptor {
+                    url: href,
+                    sizes: attr("sizes"),
+                    type_: attr("type"),
+                    media: attr("media"),
+                    rel,
+                    color: attr("color"),
+                });
+            }
+            _ => {
+                let key = format!("link:{}", rel);
+                add_additional(&mut self.metadata.additional, &key, href);
+            }
+        }
+    }
+
+    fn alternate(&mut self, tag: &Tag, url: String) {
+        let hreflang = tag.attr("hreflang").map(String::from);
+        let media = tag.attr("media").map(String::from);
+        let type_ = tag.attr("type").map(String::from);
+        let title = tag.attr("title").map(String::from);
+
+        match (hreflang, media, type_) {
+            (Some(language), None, None) if title.is_none() => {
+                self.alternates().languages.insert(language, url);
+            }
+            (None, Some(query), None) if title.is_none() => {
+                self.alternates().media.insert(query, url);
+            }
+            (None, None, Some(mime_type)) => {
+                self.alternates()
+                    .types
+                    .entry(mime_type)
+                    .or_default()
+                    .push(AlternateDescriptor { url, title });
+            }
+            (hreflang, media, type_) => self.metadata.alternate_links.push(AlternateLink {
+                url,
+                hreflang,
+                media,
+                type_,
+            }),
+        }
+    }
+
+    fn script(&mut self, tag: Tag) {
+        let is_json_ld = tag
+            .attr("type")
+            .is_some_and(|type_| type_.eq_ignore_ascii_case("application/ld+json"));
+        let Some(text) = tag.text.filter(|_| is_json_ld) else {
+            return;
+        };
+
+        #[cfg(feature = "json-ld")]
+        match serde_json::from_str(&text) {
+            Ok(json_ld) => self.json_ld.push(json_ld),
+            Err(_) => add_additional(&mut self.metadata.additional, "json-ld", text),
+        }
+        #[cfg(not(feature = "json-ld"))]
+        self.json_ld.push(text);
+    }
+
+    fn open_graph(&mut self) -> &mut OpenGraph {
+        self.metadata
+            .open_graph
+            .get_or_insert_with(OpenGraph::default)
+    }
+
+    fn product(&mut self) -> &mut Product {
+        self.open_graph()
+            .product
+            .get_or_insert_with(Product::default)
+    }
+
+    fn verification(&mut self) -> &mut Verification {
+        self.metadata
+            .verification
+            .get_or_insert_with(Verification::default)
+    }
+
+    fn apple_web_app(&mut self) -> &mut AppleWebApp {
+        self.metadata
+            .apple_web_app
+            .get_or_insert_with(AppleWebApp::default)
+    }
+
+    fn alternates(&mut self) -> &mut Alternates {
+        self.metadata
+            .alternates
+            .get_or_insert_with(Alternates::default)
+    }
+}
+
+/// Apply a structured `image:*`, `video:*` or `audio:*` property to the
+/// latest media entry, returning whether it was recognized
+fn media_property(og: &mut OpenGraph, key: &str, content: &str) -> bool {
+    let Some((kind, property)) = key.split_once(':') else {
+        return false;
+    };
+    let content = content.to_string();
+
+    match kind {
+        "image" => {
+            let Some(image) = og.images.last_mut() else {
+                return false;
+            };
+            match property {
+                "secure_url" => image.secure_url = Some(content),
+                "type" => image.type_ = Some(content),
+                "alt" => image.alt = Some(content),
+                "width" => return set_number(&mut image.width, &content),
+                "height" => return set_number(&mut image.height, &content),
+                _ => return false,
+            }
+        }
+        "video" => {
+            let Some(video) = og.videos.last_mut() else {
+                return false;
+            };
+            match property {
+                "secure_url" => video.secure_url = Some(content),
+                "type" => video.type_ = Some(content),
+                "alt" => video.alt = Some(content),
+                "width" => return set_number(&mut video.width, &content),
+                "height" => return set_number(&mut video.height, &content),
+                _ => return false,
+            }
+        }
+        "audio" => {
+            let Some(audio) = og.audio.last_mut() else {
+                return false;
+            };
+            match property {
+                "secure_url" => audio.secure_url = Some(content),
+                "type" => audio.type_ = Some(content),
+                _ => return false,
+            }
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Parse a number into `field`, returning whether it was valid
+fn set_number(field: &mut Option<u32>, content: &str) -> bool {
+    match content.trim().parse() {
+        Ok(number) => {
+            *field = Some(number);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Store a tag without a typed field, collecting repeated tags into an array
+fn add_additional(additional: &mut HashMap<String, AdditionalValue>, key: &str, value: String) {
+    match additional.get_mut(key) {
+        Some(AdditionalValue::String(existing)) => {
+            let first = std::mem::take(existing);
+            additional.insert(key.to_string(), AdditionalValue::Array(vec![first, value]));
+        }
+        Some(AdditionalValue::Array(values)) => values.push(value),
+        Some(AdditionalValue::Object(_)) => {}
+        None => {
+            additional.insert(key.to_string(), AdditionalValue::String(value));
+        }
+    }
+}
+
+/// Apply one robots directive, returning whether it was recognized
+fn apply_directive(
+    directive: &str,
+    index: &mut Option<bool>,
+    follow: &mut Option<bool>,
+    directives: &mut RobotsDirectives,
+) -> bool {
+    let (name, value) = match directive.split_once(':') {
+        Some((name, value)) => (name.trim(), Some(value.trim())),
+        None => (directive, None),
+    };
+
+    match (name, value) {
+        ("index", None) => *index = Some(true),
+        ("noindex", None) => *index = Some(false),
+        ("follow", None) => *follow = Some(true),
+        ("nofollow", None) => *follow = Some(false),
+        ("all", None) => {
+            *index = Some(true);
+            *follow = Some(true);
+        }
+        ("none", None) => {
+            *index = Some(false);
+            *follow = Some(false);
+        }
+        ("noarchive", None) => directives.noarchive = Some(true),
+        ("nosnippet", None) => directives.nosnippet = Some(true),
+        ("notranslate", None) => directives.notranslate = Some(true),
+        ("noimageindex", None) => directives.noimageindex = Some(true),
+        ("nocache", None) => directives.nocache = Some(true),
+        ("unavailable_after", Some(value)) => match DateTime::parse_from_rfc3339(value) {
+            Ok(time) => directives.unavailable_after = Some(time.to_utc()),
+            Err(_) => return false,
+        },
+        ("max-snippet", Some(value)) => match value.parse() {
+            Ok(length) => directives.max_snippet = Some(length),
+            Err(_) => return false,
+        },
+        ("max-video-preview", Some(value)) => match value.parse() {
+            Ok(length) => directives.max_video_preview = Some(length),
+            Err(_) => return false,
+        },
+        ("max-image-preview", Some(value)) => {
+            directives.max_image_preview = Some(match value {
+                "none" => MaxImagePreview::None,
+                "standard" => MaxImagePreview::Standard,
+                "large" => MaxImagePreview::Large,
+                _ => return false,
+            });
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Split a directive list such as `noindex, max-snippet:50`
+fn directives(content: &str) -> impl Iterator<Item = String> + '_ {
+    content
+        .split(',')
+        .map(|directive| directive.trim().to_ascii_lowercase())
+        .filter(|directive| !directive.is_empty())
+}
+
+/// Parse a `robots` tag, keeping crawler overrides read earlier
+pub(crate) fn parse_robots(content: &str, existing: Option<Robots>) -> Robots {
+    let mut robots = existing.unwrap_or_default();
+    for directive in directives(content) {
+        if !apply_directive(
+            &directive,
+            &mut robots.index,
+            &mut robots.follow,
+            &mut robots.directives,
+        ) {
+            let (name, value) = directive.split_once(':').unwrap_or((&directive, ""));
+            robots
+                .other
+                .insert(name.trim().to_string(), value.trim().to_string());
+        }
+    }
+    robots
+}
+
+/// Parse the directives of a crawler override; unknown directives are ignored
+pub(crate) fn parse_bot_directives(content: &str) -> BotDirectives {
+    let mut bot = BotDirectives::default();
+    for directive in directives(content) {
+        apply_directive(
+            &directive,
+            &mut bot.index,
+            &mut bot.follow,
+            &mut bot.directives,
+        );
+    }
+    bot
+}
+
+/// Parse a viewport content string, or `None` if it has unknown entries
+pub(crate) fn parse_viewport(content: &str) -> Option<Viewport> {
+    let mut viewport = Viewport::default();
+
+    for entry in content.split([',', ';']).map(str::trim) {
+        if entry.is_empty() {
+            continue;
+        }
+        let (key, value) = entry.split_once('=')?;
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "width" => {
+                viewport.width = Some(match value {
+                    "device-width" => ViewportWidth::DeviceWidth,
+                    _ => ViewportWidth::Pixels(value.parse().ok()?),
+                });
+            }
+            "height" => {
+                viewport.height = Some(match value {
+                    "device-height" => ViewportHeight::DeviceHeight,
+                    _ => ViewportHeight::Pixels(value.parse().ok()?),
+                });
+            }
+            "initial-scale" => viewport.initial_scale = Some(value.parse().ok()?),
+            "minimum-scale" => viewport.minimum_scale = Some(value.parse().ok()?),
+            "maximum-scale" => viewport.maximum_scale = Some(value.parse().ok()?),
+            "user-scalable" => {
+                viewport.user_scalable = Some(match value {
+                    "yes" | "1" => true,
+                    "no" | "0" => false,
+                    _ => return None,
+                });
+            }
+            "viewport-fit" => {
+                viewport.viewport_fit = Some(match value {
+                    "auto" => ViewportFit::Auto,
+                    "contain" => ViewportFit::Contain,
+                    "cover" => ViewportFit::Cover,
+                    _ => return None,
+                });
+            }
+            _ => return None,
+        }
+    }
+
+    Some(viewport)
+}
+
+pub(crate) fn parse_referrer(content: &str) -> Option<ReferrerPolicy> {
+    Some(match content.trim() {
+        "no-referrer" => ReferrerPolicy::NoReferrer,
+        "no-referrer-when-downgrade" => ReferrerPolicy::NoReferrerWhenDowngrade,
+        "origin" => ReferrerPolicy::Origin,
+        "origin-when-cross-origin" => ReferrerPolicy::OriginWhenCrossOrigin,
+        "same-origin" => ReferrerPolicy::SameOrigin,
+        "strict-origin" => ReferrerPolicy::StrictOrigin,
+        "strict-origin-when-cross-origin" => ReferrerPolicy::StrictOriginWhenCrossOrigin,
+        "unsafe-url" => ReferrerPolicy::UnsafeUrl,
+        _ => return None,
+    })
+}
+
+/// Parse a `format-detection` content string such as `telephone=no`
+fn parse_format_detection(content: &str) -> Option<FormatDetection> {
+    let mut format_detection = FormatDetection::default();
+
+    for entry in content.split(',').map(str::trim) {
+        if entry.is_empty() {
+            continue;
+        }
+        let (key, value) = entry.split_once('=')?;
+        let enabled = match value.trim() {
+            "yes" => true,
+            "no" => false,
+            _ => return None,
+        };
+        match key.trim() {
+            "telephone" => format_detection.telephone = Some(enabled),
+            "email" => format_detection.email = Some(enabled),
+            "address" => format_detection.address = Some(enabled),
+            "date" => format_detection.date = Some(enabled),
+            _ => return None,
+        }
+    }
+
+    Some(format_detection)
+}
+
+/// Parse an `apple-itunes-app` content string
+///
+/// The app argument is a URL that may itself contain commas, so everything
+/// after `app-argument=` is taken as is.
+fn parse_itunes(content: &str) -> Option<Itunes> {
+    let (app_id, rest) = content.split_once(',').unwrap_or((content, ""));
+    let app_id = app_id.trim().strip_prefix("app-id=")?.trim();
+    let rest = rest.trim();
+
+    let app_argument = if rest.is_empty() {
+        None
+    } else {
+        Some(rest.strip_prefix("app-argument=")?.to_string())
+    };
+
+    Some(Itunes {
+        app_id: app_id.to_string(),
+        app_argument,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_attributes() {
+        let tags = tokenize(
+            r#"<!-- <meta name="ignored"> --><meta name=description content='It&#x27;s "ok"' data-x>
+            <META Property="og:title" content="A &amp; B" />"#,
+        );
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].attr("content"), Some(r#"It's "ok""#));
+        assert_eq!(tags[0].attr("data-x"), Some(""));
+        assert_eq!(tags[1].name, "meta");
+        assert_eq!(tags[1].attr("property"), Some("og:title"));
+        assert_eq!(tags[1].attr("content"), Some("A & B"));
+    }
+
+    #[test]
+    fn test_head_skips_body() {
+        let html = "<html><head><title>Head</title></head><body><header><title>Svg</title></header></body></html>";
+        assert_eq!(head(html), "<title>Head</title>");
+        assert_eq!(head("<title>Fragment</title>"), "<title>Fragment</title>");
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a &lt;b&gt; &#169; &copy"), "a <b> © &copy");
+        assert_eq!(decode_entities("&unknown; &"), "&unknown; &");
+    }
+
+    #[test]
+    fn test_parse_content_strings() {
+        assert_eq!(
+            parse_viewport("width=device-width, initial-scale=1, user-scalable=no"),
+            Some(Viewport {
+                user_scalable: Some(false),
+                ..Viewport::device_width()
+            })
+        );
+        assert_eq!(parse_viewport("width=device-width, shrink-to-fit=no"), None);
+
+        let itunes = parse_itunes("app-id=123, app-argument=https://example.com/?a=1,2").unwrap();
+        assert_eq!(itunes.app_id, "123");
+        assert_eq!(
+            itunes.app_argument.as_deref(),
+            Some("https://example.com/?a=1,2")
+        );
+    }
+}
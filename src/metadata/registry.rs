@@ -0,0 +1,351 @@
+//! Route-pattern metadata registry
+//!
+//! A [`MetadataRegistry`] maps route patterns such as `/blog/:slug` or
+//! `/docs/*path` to static [`Metadata`] or to async generator functions that
+//! receive the request and the parent levels' metadata (see
+//! [`MetadataGenerator`]). For a request path it
+//! resolves the chain the way Next.js does for nested layouts: the root
+//! layout, then the layouts of nested segments, then the page.
+//!
+//! On the server, attach the registry to the request's [`MetadataContext`]
+//! with [`MetadataContext::with_registry`] and load the route before rendering:
+//!
+//! ```rust
+//! use leptos_next_metadata::metadata::registry::MetadataRegistry;
+//! use leptos_next_metadata::metadata::{Metadata, MetadataContext, Title};
+//!
+//! let registry = MetadataRegistry::new()
+//!     .layout("/", Metadata::default().title(Title::Template {
+//!         template: "%s | Acme".into(),
+//!         default: "Acme".into(),
+//!     }))
+//!     .layout("/blog", Metadata::default().description("The Acme blog"))
+//!     .page_with("/blog/:slug", |request| async move {
+//!         Metadata::with_title(request.params["slug"].replace('-', " "))
+//!     });
+//!
+//! let context = MetadataContext::new().with_registry(registry);
+//! futures::executor::block_on(context.load_route("/blog/hello-world"));
+//!
+//! let metadata = context.get_merged_metadata();
+//! assert_eq!(metadata.title, Some(Title::Static("hello world | Acme".into())));
+//! assert_eq!(metadata.description.as_deref(), Some("The Acme blog"));
+//! ```
+
+use super::context::MetadataContext;
+use super::generator::{MetadataGenerator, MetadataRequest, ParentMetadata};
+use super::merge::merge_metadata_chain;
+use super::route::RoutePattern;
+use super::streaming::HeadStreaming;
+use super::types::Metadata;
+use futures::future::{join_all, ready, BoxFuture, FutureExt, Shared};
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Metadata registered for a route pattern
+#[derive(Clone)]
+pub enum RouteMetadata {
+    /// Fixed metadata
+    Static(Box<Metadata>),
+    /// Metadata generated per request
+    Generator(Arc<dyn MetadataGenerator>),
+}
+
+impl fmt::Debug for RouteMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteMetadata::Static(metadata) => f.debug_tuple("Static").field(metadata).finish(),
+            RouteMetadata::Generator(_) => f.write_str("Generator(..)"),
+        }
+    }
+}
+
+/// How a registered route applies to request paths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteKind {
+    /// Applies to paths matching the pattern and every path below them
+    Layout,
+    /// Applies only to paths matching the pattern exactly
+    Page,
+}
+
+#[derive(Debug, Clone)]
+struct RouteEntry {
+    pattern: RoutePattern,
+    kind: RouteKind,
+    metadata: RouteMetadata,
+}
+
+/// Maps route patterns to static or generated metadata
+#[derive(Debug, Clone, Default)]
+pub struct MetadataRegistry {
+    entries: Vec<RouteEntry>,
+    pub(crate) streaming: Vec<(RoutePattern, HeadStreaming)>,
+}
+
+impl MetadataRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register static metadata for a layout
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid route pattern.
+    pub fn layout(self, pattern: &str, metadata: Metadata) -> Self {
+        self.with_entry(
+            pattern,
+            RouteKind::Layout,
+            RouteMetadata::Static(Box::new(metadata)),
+        )
+    }
+
+    /// Register static metadata for a page
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid route pattern.
+    pub fn page(self, pattern: &str, metadata: Metadata) -> Self {
+        self.with_entry(
+            pattern,
+            RouteKind::Page,
+            RouteMetadata::Static(Box::new(metadata)),
+        )
+    }
+
+    /// Register an async function of the request for a layout
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid route pattern.
+    pub fn layout_with<F, Fut>(self, pattern: &str, generator: F) -> Self
+    where
+        F: Fn(MetadataRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Metadata> + Send + 'static,
+    {
+        self.layout_generator(pattern, move |request, _| generator(request))
+    }
+
+    /// Register an async function of the request for a page
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid route pattern.
+    pub fn page_with<F, Fut>(self, pattern: &str, generator: F) -> Self
+    where
+        F: Fn(MetadataRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Metadata> + Send + 'static,
+    {
+        self.page_generator(pattern, move |request, _| generator(request))
+    }
+
+    /// Register a [`MetadataGenerator`] for a layout
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid route pattern.
+    pub fn layout_generator(
+        self,
+        pattern: &str,
+        generator: impl MetadataGenerator + 'static,
+    ) -> Self {
+        self.with_entry(
+            pattern,
+            RouteKind::Layout,
+            RouteMetadata::Generator(Arc::new(generator)),
+        )
+    }
+
+    /// Register a [`MetadataGenerator`] for a page
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid route pattern.
+    pub fn page_generator(
+        self,
+        pattern: &str,
+        generator: impl MetadataGenerator + 'static,
+    ) -> Self {
+        self.with_entry(
+            pattern,
+            RouteKind::Page,
+            RouteMetadata::Generator(Arc::new(generator)),
+        )
+    }
+
+    /// Register metadata for a parsed route pattern
+    pub fn insert(&mut self, pattern: RoutePattern, kind: RouteKind, metadata: RouteMetadata) {
+        self.entries.push(RouteEntry {
+            pattern,
+            kind,
+            metadata,
+        });
+    }
+
+    fn with_entry(mut self, pattern: &str, kind: RouteKind, metadata: RouteMetadata) -> Self {
+        let pattern = RoutePattern::new(pattern).unwrap_or_else(|e| panic!("{}", e));
+        self.insert(pattern, kind, metadata);
+        self
+    }
+
+    /// Registered patterns, in registration order
+    pub fn patterns(&self) -> impl Iterator<Item = (&RoutePattern, RouteKind)> {
+        self.entries
+            .iter()
+            .map(|entry| (&entry.pattern, entry.kind))
+    }
+
+    /// Number of registered routes
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no routes are registered
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Routes applying to `url`, from the root layout to the page
+    ///
+    /// Layouts matching a prefix of the path are ordered by depth, and by
+    /// specificity within the same depth. Of the pages matching the whole path,
+    /// only the most specific one is used, the way a router picks one route.
+    pub fn matching(&self, url: &str) -> Vec<(&RoutePattern, RouteKind, MetadataRequest)> {
+        self.matching_entries(MetadataRequest::new(url))
+            .into_iter()
+            .map(|(entry, request)| (&entry.pattern, entry.kind, request))
+            .collect()
+    }
+
+    fn matching_entries(&self, request: MetadataRequest) -> Vec<(&RouteEntry, MetadataRequest)> {
+        let mut layouts: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.kind == RouteKind::Layout)
+            .filter_map(|entry| {
+                let params = entry.pattern.matches_prefix(&request.path)?;
+                Some((entry, params))
+            })
+            .collect();
+        layouts.sort_by(|(a, _), (b, _)| {
+            a.pattern
+                .depth()
+                .cmp(&b.pattern.depth())
+                .then_with(|| a.pattern.cmp_specificity(&b.pattern))
+        });
+
+        let page = self
+            .entries
+            .iter()
+            .filter(|entry| entry.kind == RouteKind::Page)
+            .filter_map(|entry| Some((entry, entry.pattern.matches(&request.path)?)))
+            // `max_by` returns the last of equal elements, so prefer the first
+            .rev()
+            .max_by(|(a, _), (b, _)| a.pattern.cmp_specificity(&b.pattern));
+
+        layouts
+            .into_iter()
+            .chain(page)
+            .map(|(entry, params)| {
+                let request = MetadataRequest {
+                    params,
+                    ..request.clone()
+                };
+                (entry, request)
+            })
+            .collect()
+    }
+
+    /// Resolve the metadata layers for `url`, from the root layout to the page
+    pub async fn resolve_layers(&self, url: &str) -> Vec<Metadata> {
+        self.resolve_request(MetadataRequest::new(url), Vec::new())
+            .await
+    }
+
+    /// Resolve the metadata layers for a request below `base_layers`
+    ///
+    /// `base_layers`, such as site-wide defaults, are not returned but are
+    /// part of the [`ParentMetadata`] that generators receive. Generators run
+    /// concurrently; one that awaits its parent waits only for the levels
+    /// above it.
+    pub async fn resolve_request(
+        &self,
+        request: MetadataRequest,
+        base_layers: Vec<Metadata>,
+    ) -> Vec<Metadata> {
+        let mut layers: Vec<Shared<BoxFuture<'static, Metadata>>> = Vec::new();
+
+        for (entry, request) in self.matching_entries(request) {
+            let layer = match entry.metadata {
+                RouteMetadata::Static(ref metadata) => ready(metadata.as_ref().clone()).boxed(),
+                RouteMetadata::Generator(ref generator) => {
+                    let generator = generator.clone();
+                    let above = layers.clone();
+                    let base_layers = base_layers.clone();
+                    let parent = ParentMetadata::new(async move {
+                        merge_metadata_chain(base_layers.into_iter().chain(join_all(above).await))
+                    });
+                    async move { generator.generate(request, parent).await }.boxed()
+                }
+            };
+            layers.push(layer.shared());
+        }
+
+        join_all(layers).await
+    }
+
+    /// Resolve and merge the metadata for `url`
+    pub async fn resolve(&self, url: &str) -> Metadata {
+        merge_metadata_chain(self.resolve_layers(url).await)
+    }
+}
+
+impl MetadataContext {
+    /// Resolve route metadata from `registry` in this context
+    ///
+    /// The layers loaded with [`load_route`](Self::load_route) come after the
+    /// configured defaults and before metadata registered by components.
+    pub fn with_registry(mut self, registry: MetadataRegistry) -> Self {
+        self.registry = Some(Arc::new(registry));
+        self
+    }
+
+    /// Resolve the registry chain for `url` and use it for this context
+    ///
+    /// Call this on the server before rendering, with the request path and
+    /// query string. Without a registry, no route layers are loaded.
+    pub async fn load_route(&self, url: &str) {
+        self.load_request(MetadataRequest::new(url)).await;
+    }
+
+    /// Resolve the registry chain for a request with headers and locale
+    ///
+    /// The parent metadata passed to generators starts from this context's
+    /// configuration and parent contexts. A streaming mode registered for the
+    /// route replaces the context's.
+    pub async fn load_request(&self, request: MetadataRequest) {
+        let layers = match self.registry {
+            Some(ref registry) => {
+                if let Some(mode) = registry.head_streaming_for(&request.path) {
+                    self.set_head_streaming(mode);
+                }
+                registry.resolve_request(request, self.base_layers()).await
+            }
+            None => Vec::new(),
+        };
+        *self.route_layers.write() = layers;
+    }
+
+    /// Resolve the registry chain for the URL of the current Leptos request
+    ///
+    /// Reads the `RequestUrl` that server integrations such as `leptos_axum`
+    /// provide, falling back to `/` outside of a request.
+    pub async fn load_request_route(&self) {
+        let url = leptos::prelude::use_context::<leptos_router::location::RequestUrl>()
+            .unwrap_or_default();
+        self.load_route(url.as_ref()).await;
+    }
+}
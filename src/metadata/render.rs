@@ -0,0 +1,1273 @@
+//! HTML head rendering for metadata
+//!
+//! This module turns a resolved [`Metadata`] value into the `<title>`, `<meta>`,
+//! `<link>` and JSON-LD `<script>` tags that belong in the document `<head>`.
+//! All attribute values and text content are escaped, so user-provided strings
+//! can be rendered safely.
+
+use super::types::*;
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::io;
+
+impl Metadata {
+    /// Render this metadata into a complete `<head>` HTML fragment
+    ///
+    /// Every tag is emitted on its own line. The fragment does not include the
+    /// surrounding `<head>` element so it can be combined with other head content.
+    ///
+    /// Relative URLs are resolved against `metadata_base`. Without a base they are
+    /// written unchanged; use [`Metadata::resolve_urls`] to treat that as an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leptos_next_metadata::metadata::Metadata;
+    ///
+    /// let html = Metadata::with_title("My Page")
+    ///     .description("A page about \"things\"")
+    ///     .to_head_html();
+    ///
+    /// assert!(html.contains("<title>My Page</title>"));
+    /// assert!(html.contains(r#"<meta name="description" content="A page about &quot;things&quot;">"#));
+    /// ```
+    pub fn to_head_html(&self) -> String {
+        let mut html = String::new();
+        // Writing into a String cannot fail
+        let _ = HeadWriter::new(&mut html).write_metadata(self);
+        html
+    }
+
+    /// Stream the `<head>` HTML fragment for this metadata into a writer
+    ///
+    /// This produces exactly the same output as [`Metadata::to_head_html`] but
+    /// writes tags as they are rendered, which avoids buffering the whole
+    /// fragment when writing directly into a response body.
+    pub fn write_head_html<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_io(writer, |head| head.write_metadata(self))
+    }
+}
+
+impl ViewportConfig {
+    /// Render the `viewport`, `theme-color` and `color-scheme` tags
+    pub fn to_head_html(&self) -> String {
+        let mut html = String::new();
+        // Writing into a String cannot fail
+        let _ = HeadWriter::new(&mut html).write_viewport(self);
+        html
+    }
+
+    /// Stream the tags of [`ViewportConfig::to_head_html`] into a writer
+    pub fn write_head_html<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_io(writer, |head| head.write_viewport(self))
+    }
+}
+
+impl Robots {
+    /// Render the directives as `X-Robots-Tag` header values
+    ///
+    /// The first value holds the directives for all crawlers, if any are set,
+    /// followed by one `user-agent: directives` value per crawler override.
+    /// Send each value as its own `X-Robots-Tag` header.
+    pub fn header_values(&self) -> Vec<String> {
+        let mut values: Vec<String> = Some(self.to_string())
+            .filter(|directives| !directives.is_empty())
+            .into_iter()
+            .collect();
+        for (user_agent, directives) in self.bot_directives() {
+            values.push(format!("{}: {}", user_agent, directives));
+        }
+        values
+    }
+
+    /// Rendered crawler overrides: Googlebot first, then by user agent
+    ///
+    /// Overrides without any directive are skipped.
+    fn bot_directives(&self) -> Vec<(&str, String)> {
+        let mut bots: Vec<_> = self
+            .bots
+            .iter()
+            .map(|(user_agent, directives)| (user_agent.as_str(), directives))
+            .collect();
+        bots.sort_by_key(|(user_agent, _)| *user_agent);
+
+        self.google_bot
+            .iter()
+            .map(|google_bot| ("googlebot", google_bot))
+            .chain(bots)
+            .map(|(user_agent, directives)| (user_agent, directives.to_string()))
+            .filter(|(_, directives)| !directives.is_empty())
+            .collect()
+    }
+}
+
+/// Low-level writer for individual head tags
+///
+/// Values passed to the tag methods are escaped before being written.
+pub(crate) struct HeadWriter<'a, W: Write> {
+    out: &'a mut W,
+}
+
+impl<'a, W: Write> HeadWriter<'a, W> {
+    pub(crate) fn new(out: &'a mut W) -> Self {
+        Self { out }
+    }
+
+    /// Write every tag described by the metadata
+    pub(crate) fn write_metadata(&mut self, metadata: &Metadata) -> fmt::Result {
+        let metadata = match metadata.metadata_base {
+            Some(ref base) => {
+                let mut resolved = metadata.clone();
+                resolved.resolve_relative_urls(Some(base));
+                Cow::Owned(resolved)
+            }
+            None => Cow::Borrowed(metadata),
+        };
+
+        if let Some(ref title) = metadata.title {
+            self.title(&title.resolve(None))?;
+        }
+
+        if let Some(ref description) = metadata.description {
+            self.meta_name("description", description)?;
+        }
+
+        if let Some(ref keywords) = metadata.keywords {
+            self.meta_name("keywords", &keywords.to_string())?;
+        }
+
+        if let Some(ref authors) = metadata.authors {
+            self.authors(authors)?;
+        }
+
+        if let Some(ref robots) = metadata.robots {
+            let directives = robots.to_string();
+            if !directives.is_empty() {
+                self.meta_name("robots", &directives)?;
+            }
+            for (user_agent, directives) in robots.bot_directives() {
+                self.meta_name(user_agent, &directives)?;
+            }
+        }
+
+        if let Some(canonical) = metadata.canonical_url() {
+            self.link("canonical", canonical, &[])?;
+        }
+
+        for alternate in &metadata.alternate_links {
+            self.alternate(alternate)?;
+        }
+
+        if let Some(ref alternates) = metadata.alternates {
+            self.alternates(alternates)?;
+        }
+
+        if let Some(ref viewport) = metadata.viewport {
+            self.meta_name("viewport", &viewport.to_string())?;
+        }
+
+        if let Some(ref theme_color) = metadata.theme_color {
+            self.theme_color(theme_color)?;
+        }
+
+        if let Some(ref color_scheme) = metadata.color_scheme {
+            self.meta_name("color-scheme", &color_scheme.to_string())?;
+        }
+
+        if let Some(ref referrer) = metadata.referrer {
+            self.meta_name("referrer", &referrer.to_string())?;
+        }
+
+        if let Some(ref format_detection) = metadata.format_detection {
+            self.meta_name("format-detection", &format_detection.to_string())?;
+        }
+
+        if let Some(ref verification) = metadata.verification {
+            self.verification(verification)?;
+        }
+
+        if let Some(ref itunes) = metadata.itunes {
+            self.meta_name("apple-itunes-app", &itunes.to_string())?;
+        }
+
+        if let Some(ref apple_web_app) = metadata.apple_web_app {
+            self.apple_web_app(apple_web_app)?;
+        }
+
+        if let Some(ref open_graph) = metadata.open_graph {
+            self.open_graph(open_graph)?;
+        }
+
+        if let Some(ref twitter) = metadata.twitter {
+            self.twitter(twitter)?;
+        }
+
+        if let Some(ref app_links) = metadata.app_links {
+            self.app_links(app_links)?;
+        }
+
+        if let Some(ref icons) = metadata.icons {
+            self.icons(icons)?;
+        }
+
+        if let Some(ref manifest) = metadata.manifest {
+            self.link("manifest", manifest, &[])?;
+        }
+
+        #[cfg(feature = "json-ld")]
+        if let Some(ref json_ld) = metadata.json_ld {
+            self.json_ld(json_ld)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the tags of a viewport config
+    pub(crate) fn write_viewport(&mut self, config: &ViewportConfig) -> fmt::Result {
+        let viewport = config.viewport.to_string();
+        if !viewport.is_empty() {
+            self.meta_name("viewport", &viewport)?;
+        }
+
+        for theme_color in &config.theme_color {
+            self.theme_color(theme_color)?;
+        }
+
+        if let Some(ref color_scheme) = config.color_scheme {
+            self.meta_name("color-scheme", &color_scheme.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a `<title>` tag
+    pub(crate) fn title(&mut self, title: &str) -> fmt::Result {
+        writeln!(self.out, "<title>{}</title>", escape_text(title))
+    }
+
+    /// Write a `<meta name="..." content="...">` tag
+    pub(crate) fn meta_name(&mut self, name: &str, content: &str) -> fmt::Result {
+        writeln!(
+            self.out,
+            r#"<meta name="{}" content="{}">"#,
+            escape_attr(name),
+            escape_attr(content)
+        )
+    }
+
+    /// Write a `<meta property="..." content="...">` tag
+    pub(crate) fn meta_property(&mut self, property: &str, content: &str) -> fmt::Result {
+        writeln!(
+            self.out,
+            r#"<meta property="{}" content="{}">"#,
+            escape_attr(property),
+            escape_attr(content)
+        )
+    }
+
+    /// Write a `<link>` tag with optional extra attributes
+    ///
+    /// Attributes with a `None` value are skipped.
+    pub(crate) fn link(
+        &mut self,
+        rel: &str,
+        href: &str,
+        attributes: &[(&str, Option<&str>)],
+    ) -> fmt::Result {
+        write!(self.out, r#"<link rel="{}""#, escape_attr(rel))?;
+        for (name, value) in attributes {
+            if let Some(value) = value {
+                write!(self.out, r#" {}="{}""#, name, escape_attr(value))?;
+            }
+        }
+        writeln!(self.out, r#" href="{}">"#, escape_attr(href))
+    }
+
+    fn theme_color(&mut self, theme_color: &ThemeColor) -> fmt::Result {
+        write!(
+            self.out,
+            r#"<meta name="theme-color" content="{}""#,
+            escape_attr(&theme_color.color)
+        )?;
+        if let Some(ref media) = theme_color.media {
+            write!(self.out, r#" media="{}""#, escape_attr(media))?;
+        }
+        writeln!(self.out, ">")
+    }
+
+    fn authors(&mut self, authors: &Authors) -> fmt::Result {
+        let authors = match authors {
+            Authors::Single(author) => std::slice::from_ref(author),
+            Authors::Multiple(authors) => authors.as_slice(),
+        };
+
+        for author in authors {
+            self.meta_name("author", &author.name)?;
+            if let Some(ref url) = author.url {
+                self.link("author", url, &[])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn alternate(&mut self, alternate: &AlternateLink) -> fmt::Result {
+        self.link(
+            "alternate",
+            &alternate.url,
+            &[
+                ("hreflang", alternate.hreflang.as_deref()),
+                ("media", alternate.media.as_deref()),
+                ("type", alternate.type_.as_deref()),
+            ],
+        )
+    }
+
+    /// Write the language, media and type alternates, with `x-default` last
+    fn alternates(&mut self, alternates: &Alternates) -> fmt::Result {
+        let mut languages: Vec<_> = alternates.languages.iter().collect();
+        languages.sort_by_key(|(language, _)| (*language == Alternates::X_DEFAULT, *language));
+        for (language, url) in languages {
+            self.link("alternate", url, &[("hreflang", Some(language))])?;
+        }
+
+        let mut media: Vec<_> = alternates.media.iter().collect();
+        media.sort();
+        for (query, url) in media {
+            self.link("alternate", url, &[("media", Some(query))])?;
+        }
+
+        let mut types: Vec<_> = alternates.types.iter().collect();
+        types.sort_by_key(|(mime_type, _)| *mime_type);
+        for (mime_type, descriptors) in types {
+            for descriptor in descriptors {
+                self.link(
+                    "alternate",
+                    &descriptor.url,
+                    &[
+                        ("type", Some(mime_type)),
+                        ("title", descriptor.title.as_deref()),
+                    ],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn verification(&mut self, verification: &Verification) -> fmt::Result {
+        let mut other: Vec<_> = verification.other.iter().collect();
+        other.sort();
+
+        let services = [
+            ("google-site-verification", &verification.google),
+            ("y_key", &verification.yahoo),
+            ("yandex-verification", &verification.yandex),
+            ("me", &verification.me),
+        ];
+        let other = other
+            .into_iter()
+            .map(|(name, tokens)| (name.as_str(), tokens));
+
+        for (name, tokens) in services.into_iter().chain(other) {
+            for token in tokens {
+                self.meta_name(name, token)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apple_web_app(&mut self, apple_web_app: &AppleWebApp) -> fmt::Result {
+        if apple_web_app.capable == Some(true) {
+            self.meta_name("apple-mobile-web-app-capable", "yes")?;
+        }
+        if let Some(ref title) = apple_web_app.title {
+            self.meta_name("apple-mobile-web-app-title", title)?;
+        }
+        for image in &apple_web_app.startup_image {
+            self.link(
+                "apple-touch-startup-image",
+                &image.url,
+                &[("media", image.media.as_deref())],
+            )?;
+        }
+        if let Some(style) = apple_web_app.status_bar_style {
+            self.meta_name("apple-mobile-web-app-status-bar-style", style.as_str())?;
+        }
+
+        Ok(())
+    }
+
+    fn app_links(&mut self, app_links: &AppLinks) -> fmt::Result {
+        let apple = [
+            ("ios", &app_links.ios),
+            ("iphone", &app_links.iphone),
+            ("ipad", &app_links.ipad),
+        ];
+        for (platform, apps) in apple {
+            for app in apps {
+                self.app_link(
+                    platform,
+                    &[
+                        ("url", Some(&app.url)),
+                        ("app_store_id", app.app_store_id.as_ref()),
+                        ("app_name", app.app_name.as_ref()),
+                    ],
+                )?;
+            }
+        }
+
+        for app in &app_links.android {
+            self.app_link(
+                "android",
+                &[
+                    ("package", Some(&app.package)),
+                    ("url", app.url.as_ref()),
+                    ("class", app.class.as_ref()),
+                    ("app_name", app.app_name.as_ref()),
+                ],
+            )?;
+        }
+
+        let windows = [
+            ("windows_phone", &app_links.windows_phone),
+            ("windows", &app_links.windows),
+            ("windows_universal", &app_links.windows_universal),
+        ];
+        for (platform, apps) in windows {
+            for app in apps {
+                self.app_link(
+                    platform,
+                    &[
+                        ("url", Some(&app.url)),
+                        ("app_id", app.app_id.as_ref()),
+                        ("app_name", app.app_name.as_ref()),
+                    ],
+                )?;
+            }
+        }
+
+        for web in &app_links.web {
+            let should_fallback = web.should_fallback.map(|fallback| fallback.to_string());
+            self.app_link(
+                "web",
+                &[
+                    ("url", web.url.as_ref()),
+                    ("should_fallback", should_fallback.as_ref()),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the `al:{platform}:*` properties that have a value
+    fn app_link(&mut self, platform: &str, properties: &[(&str, Option<&String>)]) -> fmt::Result {
+        for (name, value) in properties {
+            if let Some(value) = value {
+                self.meta_property(&format!("al:{}:{}", platform, name), value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn icons(&mut self, icons: &Icons) -> fmt::Result {
+        let lists = [
+            ("shortcut icon", &icons.shortcut),
+            ("icon", &icons.icon),
+            ("apple-touch-icon", &icons.apple),
+            ("icon", &icons.other),
+        ];
+
+        for (default_rel, list) in lists {
+            for icon in list {
+                self.link(
+                    icon.rel.as_deref().unwrap_or(default_rel),
+                    &icon.url,
+                    &[
+                        ("sizes", icon.sizes.as_deref()),
+                        ("type", icon.type_.as_deref()),
+                        ("media", icon.media.as_deref()),
+                        ("color", icon.color.as_deref()),
+                    ],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn open_graph(&mut self, og: &OpenGraph) -> fmt::Result {
+        self.optional_property("og:title", og.title.as_deref())?;
+        self.optional_property("og:description", og.description.as_deref())?;
+        self.optional_property("og:url", og.url.as_deref())?;
+        self.optional_property("og:site_name", og.site_name.as_deref())?;
+        self.optional_property("og:locale", og.locale.as_deref())?;
+        for locale in &og.alternate_locale {
+            self.meta_property("og:locale:alternate", locale)?;
+        }
+
+        // Structured properties follow the media URL they describe
+        for image in &og.images {
+            self.meta_property("og:image", &image.url)?;
+            self.optional_property("og:image:secure_url", image.secure_url.as_deref())?;
+            self.optional_property("og:image:type", image.type_.as_deref())?;
+            self.optional_number("og:image:width", image.width)?;
+            self.optional_number("og:image:height", image.height)?;
+            self.optional_property("og:image:alt", image.alt.as_deref())?;
+        }
+
+        for video in &og.videos {
+            self.meta_property("og:video", &video.url)?;
+            self.optional_property("og:video:secure_url", video.secure_url.as_deref())?;
+            self.optional_property("og:video:type", video.type_.as_deref())?;
+            self.optional_number("og:video:width", video.width)?;
+            self.optional_number("og:video:height", video.height)?;
+            self.optional_property("og:video:alt", video.alt.as_deref())?;
+        }
+
+        for audio in &og.audio {
+            self.meta_property("og:audio", &audio.url)?;
+            self.optional_property("og:audio:secure_url", audio.secure_url.as_deref())?;
+            self.optional_property("og:audio:type", audio.type_.as_deref())?;
+        }
+
+        if let Some(og_type) = og.r#type {
+            self.meta_property("og:type", og_type.as_str())?;
+        }
+
+        if let Some(ref article) = og.article {
+            self.optional_property("article:published_time", article.published_time.as_deref())?;
+            self.optional_property("article:modified_time", article.modified_time.as_deref())?;
+            self.optional_property(
+                "article:expiration_time",
+                article.expiration_time.as_deref(),
+            )?;
+            self.optional_property("article:author", article.author.as_deref())?;
+            self.optional_property("article:section", article.section.as_deref())?;
+            for tag in article.tag.iter().flatten() {
+                self.meta_property("article:tag", tag)?;
+            }
+        }
+
+        if let Some(ref profile) = og.profile {
+            self.optional_property("profile:first_name", profile.first_name.as_deref())?;
+            self.optional_property("profile:last_name", profile.last_name.as_deref())?;
+            self.optional_property("profile:username", profile.username.as_deref())?;
+            self.optional_property("profile:gender", profile.gender.as_deref())?;
+        }
+
+        if let Some(ref book) = og.book {
+            self.optional_property("book:author", book.author.as_deref())?;
+            self.optional_property("book:isbn", book.isbn.as_deref())?;
+            self.optional_property("book:release_date", book.release_date.as_deref())?;
+            for tag in book.tag.iter().flatten() {
+                self.meta_property("book:tag", tag)?;
+            }
+        }
+
+        if let Some(ref music) = og.music {
+            self.music(music)?;
+        }
+
+        if let Some(ref video) = og.video_info {
+            self.video_info(video)?;
+        }
+
+        if let Some(ref product) = og.product {
+            self.product(product)?;
+        }
+
+        let mut other: Vec<_> = og.other.iter().collect();
+        other.sort();
+        for (key, value) in other {
+            if key.contains(':') {
+                self.meta_property(key, value)?;
+            } else {
+                self.meta_property(&format!("og:{}", key), value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn music(&mut self, music: &Music) -> fmt::Result {
+        self.optional_number("music:duration", music.duration)?;
+        for album in music.album.iter().flatten() {
+            self.meta_property("music:album", &album.url)?;
+            self.optional_number("music:album:disc", album.disc)?;
+            self.optional_number("music:album:track", album.track)?;
+        }
+        for song in music.song.iter().flatten() {
+            self.meta_property("music:song", &song.url)?;
+            self.optional_number("music:song:disc", song.disc)?;
+            self.optional_number("music:song:track", song.track)?;
+        }
+        for musician in music.musician.iter().flatten() {
+            self.meta_property("music:musician", musician)?;
+        }
+        self.optional_property("music:release_date", music.release_date.as_deref())?;
+        self.optional_property("music:creator", music.creator.as_deref())
+    }
+
+    fn video_info(&mut self, video: &VideoInfo) -> fmt::Result {
+        for actor in video.actor.iter().flatten() {
+            self.meta_property("video:actor", &actor.url)?;
+            self.optional_property("video:actor:role", actor.role.as_deref())?;
+        }
+        for director in video.director.iter().flatten() {
+            self.meta_property("video:director", director)?;
+        }
+        for writer in video.writer.iter().flatten() {
+            self.meta_property("video:writer", writer)?;
+        }
+        self.optional_number("video:duration", video.duration)?;
+        self.optional_property("video:release_date", video.release_date.as_deref())?;
+        for tag in video.tag.iter().flatten() {
+            self.meta_property("video:tag", tag)?;
+        }
+        self.optional_property("video:series", video.series.as_deref())
+    }
+
+    fn product(&mut self, product: &Product) -> fmt::Result {
+        if let Some(ref price) = product.price {
+            self.meta_property("product:price:amount", &price.amount.to_string())?;
+            self.meta_property("product:price:currency", &price.currency)?;
+        }
+        self.optional_property("product:availability", product.availability.as_deref())?;
+        self.optional_property("product:condition", product.condition.as_deref())?;
+        self.optional_property("product:brand", product.brand.as_deref())?;
+        self.optional_property(
+            "product:retailer_item_id",
+            product.retailer_item_id.as_deref(),
+        )
+    }
+
+    fn twitter(&mut self, twitter: &Twitter) -> fmt::Result {
+        if let Some(ref card) = twitter.card {
+            self.meta_name("twitter:card", &card.to_string())?;
+        }
+
+        let fields = [
+            ("twitter:site", twitter.site.as_deref()),
+            ("twitter:site:id", twitter.site_id.as_deref()),
+            ("twitter:creator", twitter.creator.as_deref()),
+            ("twitter:creator:id", twitter.creator_id.as_deref()),
+            ("twitter:title", twitter.title.as_deref()),
+            ("twitter:description", twitter.description.as_deref()),
+            ("twitter:image", twitter.image.as_deref()),
+            ("twitter:image:alt", twitter.image_alt.as_deref()),
+            ("twitter:player", twitter.player.as_deref()),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                self.meta_name(name, value)?;
+            }
+        }
+
+        if let Some(width) = twitter.player_width {
+            self.meta_name("twitter:player:width", &width.to_string())?;
+        }
+        if let Some(height) = twitter.player_height {
+            self.meta_name("twitter:player:height", &height.to_string())?;
+        }
+
+        let fields = [
+            ("twitter:player:stream", twitter.player_stream.as_deref()),
+            (
+                "twitter:app:name:iphone",
+                twitter.app_name_iphone.as_deref(),
+            ),
+            ("twitter:app:id:iphone", twitter.app_id_iphone.as_deref()),
+            ("twitter:app:url:iphone", twitter.app_url_iphone.as_deref()),
+            ("twitter:app:name:ipad", twitter.app_name_ipad.as_deref()),
+            ("twitter:app:id:ipad", twitter.app_id_ipad.as_deref()),
+            ("twitter:app:url:ipad", twitter.app_url_ipad.as_deref()),
+            (
+                "twitter:app:name:googleplay",
+                twitter.app_name_googleplay.as_deref(),
+            ),
+            (
+                "twitter:app:id:googleplay",
+                twitter.app_id_googleplay.as_deref(),
+            ),
+            (
+                "twitter:app:url:googleplay",
+                twitter.app_url_googleplay.as_deref(),
+            ),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                self.meta_name(name, value)?;
+            }
+        }
+
+        let mut other: Vec<_> = twitter.other.iter().collect();
+        other.sort();
+        for (key, value) in other {
+            self.meta_name(&format!("twitter:{}", key), value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a JSON-LD `<script>` tag
+    ///
+    /// `<`, `>` and `&` are written as JSON unicode escapes so the payload can
+    /// never close the script element early.
+    #[cfg(feature = "json-ld")]
+    pub(crate) fn json_ld(&mut self, json_ld: &JsonLd) -> fmt::Result {
+        let json = serde_json::to_string(json_ld).map_err(|_| fmt::Error)?;
+        let json = json
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .replace('&', "\\u0026");
+        writeln!(
+            self.out,
+            r#"<script type="application/ld+json">{}</script>"#,
+            json
+        )
+    }
+
+    fn optional_property(&mut self, property: &str, content: Option<&str>) -> fmt::Result {
+        match content {
+            Some(content) => self.meta_property(property, content),
+            None => Ok(()),
+        }
+    }
+
+    fn optional_number(&mut self, property: &str, content: Option<u32>) -> fmt::Result {
+        match content {
+            Some(content) => self.meta_property(property, &content.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Escape a value for use inside a double-quoted HTML attribute
+pub(crate) fn escape_attr(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Escape a value for use as HTML text content
+pub(crate) fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Render tags straight into an `io::Write`, surfacing the underlying I/O error
+fn write_io<W, F>(writer: &mut W, render: F) -> io::Result<()>
+where
+    W: io::Write,
+    F: FnOnce(&mut HeadWriter<'_, IoAdapter<'_, W>>) -> fmt::Result,
+{
+    let mut adapter = IoAdapter {
+        inner: writer,
+        error: None,
+    };
+
+    match render(&mut HeadWriter::new(&mut adapter)) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("failed to render metadata"))),
+    }
+}
+
+/// Adapts an `io::Write` to `fmt::Write`, keeping the underlying I/O error
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_render_basic_tags() {
+        let metadata = Metadata::with_title("My Page")
+            .description("Page description")
+            .keywords(vec!["rust".to_string(), "leptos".to_string()])
+            .canonical("https://example.com/page")
+            .alternate("de", "https://example.com/de/page")
+            .theme_color("#ffffff")
+            .color_scheme(ColorScheme::Dark)
+            .referrer(ReferrerPolicy::StrictOriginWhenCrossOrigin);
+
+        let html = metadata.to_head_html();
+
+        assert!(html.contains("<title>My Page</title>\n"));
+        assert!(html.contains(r#"<meta name="description" content="Page description">"#));
+        assert!(html.contains(r#"<meta name="keywords" content="rust, leptos">"#));
+        assert!(html.contains(r#"<link rel="canonical" href="https://example.com/page">"#));
+        assert!(html.contains(
+            r#"<link rel="alternate" hreflang="de" href="https://example.com/de/page">"#
+        ));
+        assert!(html.contains(r##"<meta name="theme-color" content="#ffffff">"##));
+        assert!(html.contains(r#"<meta name="color-scheme" content="dark">"#));
+        assert!(
+            html.contains(r#"<meta name="referrer" content="strict-origin-when-cross-origin">"#)
+        );
+    }
+
+    #[test]
+    fn test_render_escapes_values() {
+        let metadata = Metadata::with_title("Tom & Jerry <3")
+            .description(r#"Say "hi" to <script>alert('x')</script>"#);
+
+        let html = metadata.to_head_html();
+
+        assert!(html.contains("<title>Tom &amp; Jerry &lt;3</title>"));
+        assert!(html.contains(
+            "content=\"Say &quot;hi&quot; to &lt;script&gt;alert(&#x27;x&#x27;)&lt;/script&gt;\""
+        ));
+        assert!(!html.contains("<script>alert"));
+    }
+
+    #[test]
+    fn test_render_open_graph_and_twitter() {
+        let metadata = Metadata::default()
+            .open_graph(OpenGraph {
+                title: Some("OG Title".to_string()),
+                images: vec![OgImage {
+                    alt: Some("Preview".to_string()),
+                    ..OgImage::with_dimensions("https://example.com/og.png", 1200, 630)
+                }],
+                article: Some(Article {
+                    published_time: Some("2024-01-15T10:00:00Z".to_string()),
+                    modified_time: None,
+                    expiration_time: None,
+                    author: Some("Jane".to_string()),
+                    section: None,
+                    tag: Some(vec!["rust".to_string(), "web".to_string()]),
+                }),
+                ..Default::default()
+            })
+            .twitter(Twitter {
+                card: Some(TwitterCard::SummaryLargeImage),
+                site: Some("@example".to_string()),
+                player_width: Some(640),
+                other: HashMap::from([("label1".to_string(), "Reading time".to_string())]),
+                ..Default::default()
+            });
+
+        let html = metadata.to_head_html();
+        let expected = "\
+<meta property=\"og:title\" content=\"OG Title\">
+<meta property=\"og:image\" content=\"https://example.com/og.png\">
+<meta property=\"og:image:width\" content=\"1200\">
+<meta property=\"og:image:height\" content=\"630\">
+<meta property=\"og:image:alt\" content=\"Preview\">
+<meta property=\"article:published_time\" content=\"2024-01-15T10:00:00Z\">
+<meta property=\"article:author\" content=\"Jane\">
+<meta property=\"article:tag\" content=\"rust\">
+<meta property=\"article:tag\" content=\"web\">
+<meta name=\"twitter:card\" content=\"summary_large_image\">
+<meta name=\"twitter:site\" content=\"@example\">
+<meta name=\"twitter:player:width\" content=\"640\">
+<meta name=\"twitter:label1\" content=\"Reading time\">
+";
+        assert_eq!(html, expected);
+    }
+
+    #[test]
+    fn test_render_robots_and_authors() {
+        let metadata = Metadata::default()
+            .authors(vec![
+                Author {
+                    name: "Jane".to_string(),
+                    url: Some("https://example.com/jane".to_string()),
+                    email: None,
+                },
+                Author {
+                    name: "John".to_string(),
+                    url: None,
+                    email: None,
+                },
+            ])
+            .robots(Robots {
+                google_bot: Some(GoogleBot {
+                    index: Some(true),
+                    follow: None,
+                    directives: RobotsDirectives {
+                        max_snippet: Some(-1),
+                        max_image_preview: Some(MaxImagePreview::Large),
+                        ..Default::default()
+                    },
+                }),
+                ..Robots::noindex()
+            });
+
+        let html = metadata.to_head_html();
+
+        assert!(html.contains(r#"<meta name="author" content="Jane">"#));
+        assert!(html.contains(r#"<link rel="author" href="https://example.com/jane">"#));
+        assert!(html.contains(r#"<meta name="author" content="John">"#));
+        assert!(html.contains(r#"<meta name="robots" content="noindex, follow">"#));
+        assert!(html.contains(
+            r#"<meta name="googlebot" content="index, max-snippet:-1, max-image-preview:large">"#
+        ));
+    }
+
+    #[cfg(feature = "json-ld")]
+    #[test]
+    fn test_render_json_ld_cannot_break_out_of_script() {
+        let metadata = Metadata::default().json_ld(serde_json::json!({
+            "@type": "WebPage",
+            "name": "</script><script>alert(1)</script>",
+        }));
+
+        let html = metadata.to_head_html();
+
+        assert!(html.starts_with(r#"<script type="application/ld+json">{"#));
+        assert_eq!(html.matches("</script>").count(), 1);
+        let json = html
+            .trim_start_matches(r#"<script type="application/ld+json">"#)
+            .trim_end()
+            .trim_end_matches("</script>");
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(value["name"], "</script><script>alert(1)</script>");
+    }
+
+    #[test]
+    fn test_write_head_html_matches_to_head_html() {
+        let metadata =
+            Metadata::with_title_and_description("Title", "Description").viewport(Viewport {
+                width: Some(ViewportWidth::DeviceWidth),
+                height: None,
+                initial_scale: Some(1.0),
+                minimum_scale: None,
+                maximum_scale: None,
+                user_scalable: None,
+                viewport_fit: None,
+            });
+
+        let mut buffer = Vec::new();
+        metadata.write_head_html(&mut buffer).unwrap();
+
+        assert_eq!(String::from_utf8(buffer).unwrap(), metadata.to_head_html());
+        assert!(metadata
+            .to_head_html()
+            .contains(r#"<meta name="viewport" content="width=device-width, initial-scale=1">"#));
+    }
+
+    #[test]
+    fn test_render_resolves_relative_urls() {
+        let metadata = Metadata::default()
+            .metadata_base(url::Url::parse("https://acme.com").unwrap())
+            .canonical("/about")
+            .open_graph(OpenGraph::default().image("/og.png"));
+
+        let html = metadata.to_head_html();
+        assert!(html.contains(r#"<link rel="canonical" href="https://acme.com/about">"#));
+        assert!(html.contains(r#"<meta property="og:image" content="https://acme.com/og.png">"#));
+
+        // Without a base, relative URLs are written unchanged
+        let html = Metadata::default().canonical("/about").to_head_html();
+        assert!(html.contains(r#"<link rel="canonical" href="/about">"#));
+    }
+
+    #[test]
+    fn test_render_og_type_and_namespaces() {
+        let html = Metadata::default()
+            .open_graph(OpenGraph {
+                r#type: Some(OgType::VideoEpisode),
+                video_info: Some(VideoInfo {
+                    actor: Some(vec![VideoActor {
+                        url: "https://example.com/actors/1".into(),
+                        role: Some("Detective".into()),
+                    }]),
+                    duration: Some(2700),
+                    series: Some("https://example.com/shows/1".into()),
+                    ..Default::default()
+                }),
+                product: Some(Product {
+                    price: Some(ProductPrice {
+                        amount: 4.5,
+                        currency: "USD".into(),
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .to_head_html();
+
+        assert!(html.contains(
+            "<meta property=\"og:type\" content=\"video.episode\">\n\
+             <meta property=\"video:actor\" content=\"https://example.com/actors/1\">\n\
+             <meta property=\"video:actor:role\" content=\"Detective\">\n\
+             <meta property=\"video:duration\" content=\"2700\">\n\
+             <meta property=\"video:series\" content=\"https://example.com/shows/1\">\n"
+        ));
+        assert!(html.contains(r#"<meta property="product:price:amount" content="4.5">"#));
+        assert!(html.contains(r#"<meta property="product:price:currency" content="USD">"#));
+    }
+
+    #[test]
+    fn test_render_groups_properties_per_image() {
+        let html = Metadata::default()
+            .open_graph(
+                OpenGraph::default()
+                    .image(OgImage {
+                        secure_url: Some("https://example.com/a.png".to_string()),
+                        type_: Some("image/png".to_string()),
+                        alt: Some("First".to_string()),
+                        ..OgImage::with_dimensions("http://example.com/a.png", 1200, 630)
+                    })
+                    .image("https://example.com/b.png"),
+            )
+            .to_head_html();
+
+        assert_eq!(
+            html,
+            "<meta property=\"og:image\" content=\"http://example.com/a.png\">\n\
+             <meta property=\"og:image:secure_url\" content=\"https://example.com/a.png\">\n\
+             <meta property=\"og:image:type\" content=\"image/png\">\n\
+             <meta property=\"og:image:width\" content=\"1200\">\n\
+             <meta property=\"og:image:height\" content=\"630\">\n\
+             <meta property=\"og:image:alt\" content=\"First\">\n\
+             <meta property=\"og:image\" content=\"https://example.com/b.png\">\n"
+        );
+    }
+
+    #[test]
+    fn test_render_icons() {
+        let html = Metadata::default()
+            .icons(
+                Icons::default()
+                    .shortcut("/favicon.ico")
+                    .icon(IconDescriptor {
+                        sizes: Some("32x32".to_string()),
+                        type_: Some("image/png".to_string()),
+                        media: Some("(prefers-color-scheme: dark)".to_string()),
+                        ..IconDescriptor::new("/icon-dark.png")
+                    })
+                    .apple("/apple-touch-icon.png")
+                    .other(IconDescriptor {
+                        rel: Some("mask-icon".to_string()),
+                        color: Some("#5bbad5".to_string()),
+                        ..IconDescriptor::new("/safari-pinned-tab.svg")
+                    }),
+            )
+            .to_head_html();
+
+        assert_eq!(
+            html,
+            "<link rel=\"shortcut icon\" href=\"/favicon.ico\">\n\
+             <link rel=\"icon\" sizes=\"32x32\" type=\"image/png\" \
+             media=\"(prefers-color-scheme: dark)\" href=\"/icon-dark.png\">\n\
+             <link rel=\"apple-touch-icon\" href=\"/apple-touch-icon.png\">\n\
+             <link rel=\"mask-icon\" color=\"#5bbad5\" href=\"/safari-pinned-tab.svg\">\n"
+        );
+    }
+
+    #[test]
+    fn test_render_manifest() {
+        let html = Metadata::default()
+            .metadata_base("https://acme.com".parse().unwrap())
+            .manifest("/manifest.webmanifest")
+            .to_head_html();
+
+        assert_eq!(
+            html,
+            "<link rel=\"manifest\" href=\"https://acme.com/manifest.webmanifest\">\n"
+        );
+    }
+
+    #[test]
+    fn test_render_verification_and_apps() {
+        let html = Metadata::default()
+            .verification(
+                Verification::default()
+                    .google("g-1")
+                    .google("g-2")
+                    .yandex("y-1")
+                    .other("msvalidate.01", "bing-1"),
+            )
+            .itunes(Itunes {
+                app_argument: Some("https://acme.com/app".to_string()),
+                ..Itunes::new("123456789")
+            })
+            .apple_web_app(AppleWebApp {
+                capable: Some(true),
+                title: Some("Acme".to_string()),
+                status_bar_style: Some(AppleStatusBarStyle::BlackTranslucent),
+                startup_image: vec![AppleStartupImage {
+                    url: "/startup.png".to_string(),
+                    media: Some("(device-width: 768px)".to_string()),
+                }],
+            })
+            .app_links(AppLinks {
+                ios: vec![AppLinksApple {
+                    url: "acme://docs".to_string(),
+                    app_store_id: Some("123456789".to_string()),
+                    app_name: None,
+                }],
+                android: vec![AppLinksAndroid {
+                    package: "com.acme.app".to_string(),
+                    ..Default::default()
+                }],
+                web: vec![AppLinksWeb {
+                    url: Some("https://acme.com/docs".to_string()),
+                    should_fallback: Some(false),
+                }],
+                ..Default::default()
+            })
+            .to_head_html();
+
+        assert_eq!(
+            html,
+            "<meta name=\"google-site-verification\" content=\"g-1\">\n\
+             <meta name=\"google-site-verification\" content=\"g-2\">\n\
+             <meta name=\"yandex-verification\" content=\"y-1\">\n\
+             <meta name=\"msvalidate.01\" content=\"bing-1\">\n\
+             <meta name=\"apple-itunes-app\" \
+             content=\"app-id=123456789, app-argument=https://acme.com/app\">\n\
+             <meta name=\"apple-mobile-web-app-capable\" content=\"yes\">\n\
+             <meta name=\"apple-mobile-web-app-title\" content=\"Acme\">\n\
+             <link rel=\"apple-touch-startup-image\" media=\"(device-width: 768px)\" \
+             href=\"/startup.png\">\n\
+             <meta name=\"apple-mobile-web-app-status-bar-style\" \
+             content=\"black-translucent\">\n\
+             <meta property=\"al:ios:url\" content=\"acme://docs\">\n\
+             <meta property=\"al:ios:app_store_id\" content=\"123456789\">\n\
+             <meta property=\"al:android:package\" content=\"com.acme.app\">\n\
+             <meta property=\"al:web:url\" content=\"https://acme.com/docs\">\n\
+             <meta property=\"al:web:should_fallback\" content=\"false\">\n"
+        );
+    }
+
+    #[test]
+    fn test_render_alternates() {
+        let html = Metadata::default()
+            .canonical("https://acme.com/legacy")
+            .alternates(
+                Alternates::locales("https://acme.com/{locale}/docs", ["en-US", "de"])
+                    .canonical("https://acme.com/docs")
+                    .x_default("https://acme.com/docs")
+                    .media(
+                        "only screen and (max-width: 600px)",
+                        "https://m.acme.com/docs",
+                    )
+                    .feed("application/rss+xml", "https://acme.com/rss", Some("Acme")),
+            )
+            .to_head_html();
+
+        assert_eq!(
+            html,
+            "<link rel=\"canonical\" href=\"https://acme.com/docs\">\n\
+             <link rel=\"alternate\" hreflang=\"de\" href=\"https://acme.com/de/docs\">\n\
+             <link rel=\"alternate\" hreflang=\"en-US\" href=\"https://acme.com/en-US/docs\">\n\
+             <link rel=\"alternate\" hreflang=\"x-default\" href=\"https://acme.com/docs\">\n\
+             <link rel=\"alternate\" media=\"only screen and (max-width: 600px)\" \
+             href=\"https://m.acme.com/docs\">\n\
+             <link rel=\"alternate\" type=\"application/rss+xml\" title=\"Acme\" \
+             href=\"https://acme.com/rss\">\n"
+        );
+    }
+
+    #[test]
+    fn test_render_robots_leaves_out_unset_values() {
+        let robots = Robots {
+            directives: RobotsDirectives {
+                noarchive: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let html = Metadata::default().robots(robots).to_head_html();
+        assert_eq!(html, "<meta name=\"robots\" content=\"noarchive\">\n");
+
+        // Only a crawler override: no `robots` tag at all
+        let robots = Robots {
+            google_bot: Some(GoogleBot {
+                index: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(robots.header_values(), vec!["googlebot: index".to_string()]);
+        let html = Metadata::default().robots(robots).to_head_html();
+        assert_eq!(html, "<meta name=\"googlebot\" content=\"index\">\n");
+    }
+
+    #[test]
+    fn test_render_robots_directives() {
+        let unavailable_after = chrono::DateTime::parse_from_rfc3339("2030-01-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let robots = Robots {
+            directives: RobotsDirectives {
+                noarchive: Some(true),
+                nosnippet: Some(false),
+                notranslate: Some(true),
+                noimageindex: Some(true),
+                nocache: Some(true),
+                unavailable_after: Some(unavailable_after),
+                max_video_preview: Some(0),
+                ..Default::default()
+            },
+            google_bot: Some(GoogleBot {
+                follow: Some(false),
+                ..Default::default()
+            }),
+            ..Robots::all()
+        }
+        .bot("bingbot", BotDirectives::noindex())
+        .bot("applebot", BotDirectives::default());
+
+        let html = Metadata::default().robots(robots.clone()).to_head_html();
+        let content = "index, follow, noarchive, notranslate, noimageindex, nocache, \
+                       unavailable_after:2030-01-01T12:00:00Z, max-video-preview:0";
+        assert_eq!(
+            html,
+            format!(
+                "<meta name=\"robots\" content=\"{}\">\n\
+                 <meta name=\"googlebot\" content=\"nofollow\">\n\
+                 <meta name=\"bingbot\" content=\"noindex\">\n",
+                content
+            )
+        );
+
+        assert_eq!(
+            robots.header_values(),
+            vec![
+                content.to_string(),
+                "googlebot: nofollow".to_string(),
+                "bingbot: noindex".to_string(),
+            ]
+        );
+    }
+}
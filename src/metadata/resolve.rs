@@ -0,0 +1,313 @@
+//! Resolution of relative URLs against `metadata_base`
+//!
+//! Like Next.js `metadataBase`, URL-bearing fields may hold paths such as
+//! `/og.png`. These are joined onto the base URL in effect for the metadata
+//! level that defined them, so a layout can set the base once and pages can
+//! use short paths.
+
+use super::*;
+use crate::utils::url::{is_absolute_url, join_url_paths};
+use crate::{Error, Result};
+use url::Url;
+
+/// Resolve a URL against an optional base
+///
+/// Absolute URLs are returned unchanged. Relative URLs are joined onto the base
+/// with [`join_url_paths`], so `/og.png` against `https://acme.com/blog` becomes
+/// `https://acme.com/blog/og.png`. Returns `None` for a relative URL without a base.
+pub fn resolve_url(base: Option<&Url>, url: &str) -> Option<String> {
+    if is_absolute_url(url) {
+        Some(url.to_string())
+    } else {
+        base.map(|base| join_url_paths(base.as_str(), url))
+    }
+}
+
+impl Metadata {
+    /// Resolve all relative URLs against `metadata_base`
+    ///
+    /// Returns [`Error::UnresolvedUrl`] naming the first field that holds a
+    /// relative URL when no `metadata_base` is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leptos_next_metadata::metadata::Metadata;
+    ///
+    /// let metadata = Metadata::default()
+    ///     .metadata_base("https://acme.com".parse().unwrap())
+    ///     .canonical("/about");
+    ///
+    /// let resolved = metadata.resolve_urls().unwrap();
+    /// assert_eq!(resolved.canonical.as_deref(), Some("https://acme.com/about"));
+    ///
+    /// assert!(Metadata::default().canonical("/about").resolve_urls().is_err());
+    /// ```
+    pub fn resolve_urls(&self) -> Result<Metadata> {
+        let mut resolved = self.clone();
+        let base = resolved.metadata_base.clone();
+        let mut unresolved = None;
+
+        resolved.visit_urls_mut(&mut |field, url| match resolve_url(base.as_ref(), url) {
+            Some(absolute) => *url = absolute,
+            None => {
+                unresolved.get_or_insert_with(|| Error::UnresolvedUrl {
+                    field: field.to_string(),
+                    url: url.clone(),
+                });
+            }
+        });
+
+        match unresolved {
+            Some(error) => Err(error),
+            None => Ok(resolved),
+        }
+    }
+
+    /// Resolve relative URLs against `base`, leaving them unchanged when there is none
+    pub(crate) fn resolve_relative_urls(&mut self, base: Option<&Url>) {
+        if base.is_none() {
+            return;
+        }
+
+        self.visit_urls_mut(&mut |_, url| {
+            if let Some(absolute) = resolve_url(base, url) {
+                *url = absolute;
+            }
+        });
+    }
+
+    /// Call `visit` with the field path and value of every URL-bearing field
+    fn visit_urls_mut(&mut self, visit: &mut impl FnMut(&'static str, &mut String)) {
+        if let Some(ref mut canonical) = self.canonical {
+            visit("canonical", canonical);
+        }
+
+        for link in &mut self.alternate_links {
+            visit("alternate_links.url", &mut link.url);
+        }
+
+        if let Some(ref mut alternates) = self.alternates {
+            if let Some(ref mut canonical) = alternates.canonical {
+                visit("alternates.canonical", canonical);
+            }
+            for url in alternates.languages.values_mut() {
+                visit("alternates.languages", url);
+            }
+            for url in alternates.media.values_mut() {
+                visit("alternates.media", url);
+            }
+            for descriptor in alternates.types.values_mut().flatten() {
+                visit("alternates.types.url", &mut descriptor.url);
+            }
+        }
+
+        match self.authors {
+            Some(Authors::Single(ref mut author)) => {
+                if let Some(ref mut url) = author.url {
+                    visit("authors.url", url);
+                }
+            }
+            Some(Authors::Multiple(ref mut authors)) => {
+                for url in authors.iter_mut().filter_map(|a| a.url.as_mut()) {
+                    visit("authors.url", url);
+                }
+            }
+            None => {}
+        }
+
+        if let Some(ref mut icons) = self.icons {
+            let lists = [
+                &mut icons.icon,
+                &mut icons.apple,
+                &mut icons.shortcut,
+                &mut icons.other,
+            ];
+            for icon in lists.into_iter().flatten() {
+                visit("icons.url", &mut icon.url);
+            }
+        }
+
+        if let Some(ref mut manifest) = self.manifest {
+            visit("manifest", manifest);
+        }
+
+        if let Some(ref mut apple_web_app) = self.apple_web_app {
+            for image in &mut apple_web_app.startup_image {
+                visit("apple_web_app.startup_image.url", &mut image.url);
+            }
+        }
+
+        if let Some(ref mut app_links) = self.app_links {
+            for url in app_links.web.iter_mut().filter_map(|web| web.url.as_mut()) {
+                visit("app_links.web.url", url);
+            }
+        }
+
+        if let Some(ref mut og) = self.open_graph {
+            if let Some(ref mut url) = og.url {
+                visit("open_graph.url", url);
+            }
+            for image in &mut og.images {
+                visit("open_graph.images.url", &mut image.url);
+                if let Some(ref mut secure_url) = image.secure_url {
+                    visit("open_graph.images.secure_url", secure_url);
+                }
+            }
+            for video in &mut og.videos {
+                visit("open_graph.videos.url", &mut video.url);
+                if let Some(ref mut secure_url) = video.secure_url {
+                    visit("open_graph.videos.secure_url", secure_url);
+                }
+            }
+            for audio in &mut og.audio {
+                visit("open_graph.audio.url", &mut audio.url);
+                if let Some(ref mut secure_url) = audio.secure_url {
+                    visit("open_graph.audio.secure_url", secure_url);
+                }
+            }
+            if let Some(ref mut music) = og.music {
+                for album in music.album.iter_mut().flatten() {
+                    visit("open_graph.music.album.url", &mut album.url);
+                }
+                for song in music.song.iter_mut().flatten() {
+                    visit("open_graph.music.song.url", &mut song.url);
+                }
+            }
+        }
+
+        if let Some(ref mut twitter) = self.twitter {
+            if let Some(ref mut image) = twitter.image {
+                visit("twitter.image", image);
+            }
+            if let Some(ref mut player) = twitter.player {
+                visit("twitter.player", player);
+            }
+            if let Some(ref mut stream) = twitter.player_stream {
+                visit("twitter.player_stream", stream);
+            }
+        }
+    }
+}
+
+/// Resolve the URLs of a metadata layer before it is merged over `base`
+///
+/// The layer inherits the base URL of the levels above it unless it sets its own.
+pub(crate) fn resolve_layer_urls(base: &Metadata, layer: &mut Metadata) {
+    if layer.metadata_base.is_none() {
+        layer.metadata_base = base.metadata_base.clone();
+    }
+
+    let metadata_base = layer.metadata_base.clone();
+    layer.resolve_relative_urls(metadata_base.as_ref());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_url() {
+        let acme = base("https://acme.com/");
+        let blog = base("https://acme.com/blog");
+
+        assert_eq!(
+            resolve_url(Some(&acme), "/og.png").as_deref(),
+            Some("https://acme.com/og.png")
+        );
+        assert_eq!(
+            resolve_url(Some(&blog), "og.png").as_deref(),
+            Some("https://acme.com/blog/og.png")
+        );
+        assert_eq!(
+            resolve_url(None, "https://cdn.acme.com/og.png").as_deref(),
+            Some("https://cdn.acme.com/og.png")
+        );
+        assert_eq!(resolve_url(None, "/og.png"), None);
+    }
+
+    #[test]
+    fn test_resolve_urls_covers_url_fields() {
+        let metadata = Metadata::default()
+            .metadata_base(base("https://acme.com"))
+            .canonical("/about")
+            .alternate("de", "/de/about")
+            .open_graph(OpenGraph::default().image("/og.png"))
+            .twitter(Twitter {
+                image: Some("https://cdn.acme.com/card.png".into()),
+                ..Default::default()
+            });
+
+        let resolved = metadata.resolve_urls().unwrap();
+        assert_eq!(
+            resolved.canonical.as_deref(),
+            Some("https://acme.com/about")
+        );
+        assert_eq!(resolved.alternate_links[0].url, "https://acme.com/de/about");
+        assert_eq!(
+            resolved.open_graph.unwrap().images[0].url,
+            "https://acme.com/og.png"
+        );
+        assert_eq!(
+            resolved.twitter.unwrap().image.as_deref(),
+            Some("https://cdn.acme.com/card.png")
+        );
+    }
+
+    #[test]
+    fn test_resolve_urls_without_base_is_an_error() {
+        let metadata = Metadata::default().open_graph(OpenGraph::default().image("/og.png"));
+
+        match metadata.resolve_urls() {
+            Err(Error::UnresolvedUrl { field, url }) => {
+                assert_eq!(field, "open_graph.images.url");
+                assert_eq!(url, "/og.png");
+            }
+            other => panic!("expected UnresolvedUrl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_merge_resolves_against_inherited_base() {
+        let layout = Metadata::default().metadata_base(base("https://acme.com"));
+        let page = Metadata::default().canonical("/pricing");
+
+        let merged = merge_metadata(layout.clone(), page.clone());
+        assert_eq!(
+            merged.canonical.as_deref(),
+            Some("https://acme.com/pricing")
+        );
+
+        let merged = layout.merge_with(&page, &MergeConfig::default());
+        assert_eq!(
+            merged.canonical.as_deref(),
+            Some("https://acme.com/pricing")
+        );
+
+        // A level can override the base for itself and the levels below it
+        let docs = Metadata::default()
+            .metadata_base(base("https://docs.acme.com"))
+            .canonical("/intro");
+        let merged = merge_metadata_chain(vec![layout, docs]);
+        assert_eq!(
+            merged.canonical.as_deref(),
+            Some("https://docs.acme.com/intro")
+        );
+    }
+
+    #[test]
+    fn test_validation_requires_base_for_relative_urls() {
+        let metadata = Metadata::default().canonical("/about");
+        let result = metadata.validate();
+        assert!(result.errors.iter().any(
+            |e| e.field.as_deref() == Some("canonical") && e.message.contains("metadata_base")
+        ));
+
+        let result = metadata.metadata_base(base("https://acme.com")).validate();
+        assert!(result.is_valid());
+    }
+}
@@ -0,0 +1,413 @@
+//! Route patterns for matching request paths
+//!
+//! [`RoutePattern`] uses the `leptos_router` path syntax: static segments,
+//! `:name` parameters matching one segment, `:name?` optional parameters and a
+//! trailing `*name` wildcard matching the rest of the path, e.g. `/blog/:slug`
+//! or `/docs/*path`. Patterns can also be built from the segments of a
+//! `leptos_router` route definition with [`RoutePattern::from_segments`].
+
+use crate::{Error, Result};
+use leptos_router::PathSegment;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Parameters captured by a matched route pattern, keyed by name
+pub type RouteParams = HashMap<String, String>;
+
+/// A route pattern such as `/blog/:slug` or `/docs/*path`
+///
+/// A wildcard also matches zero segments, so `/docs/*path` matches `/docs`
+/// with an empty `path`. Captured values are percent-decoded.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::route::RoutePattern;
+///
+/// let pattern: RoutePattern = "/blog/:slug".parse().unwrap();
+/// let params = pattern.matches("/blog/hello%20world?ref=feed").unwrap();
+/// assert_eq!(params["slug"], "hello world");
+/// assert!(pattern.matches("/blog").is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(into = "String")]
+pub struct RoutePattern {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Segment {
+    Static(String),
+    Param(String),
+    OptionalParam(String),
+    Wildcard(String),
+}
+
+impl RoutePattern {
+    /// Parse a route pattern
+    pub fn new(pattern: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            Error::ConfigError(format!("Invalid route pattern \"{}\": {}", pattern, reason))
+        };
+
+        if !pattern.starts_with('/') {
+            return Err(invalid("it must start with `/`"));
+        }
+
+        let mut segments = Vec::new();
+        for part in pattern.split('/').filter(|part| !part.is_empty()) {
+            if matches!(segments.last(), Some(Segment::Wildcard(_))) {
+                return Err(invalid("a wildcard must be the last segment"));
+            }
+
+            let segment =
+                if let Some(name) = part.strip_prefix(':').and_then(|p| p.strip_suffix('?')) {
+                    if !is_name(name) || name.is_empty() {
+                        return Err(invalid(&format!(
+                            "`{}` is not a valid parameter name",
+                            part
+                        )));
+                    }
+                    Segment::OptionalParam(name.to_string())
+                } else if let Some(name) = part.strip_prefix(':') {
+                    if !is_name(name) || name.is_empty() {
+                        return Err(invalid(&format!(
+                            "`{}` is not a valid parameter name",
+                            part
+                        )));
+                    }
+                    Segment::Param(name.to_string())
+                } else if let Some(name) = part.strip_prefix('*') {
+                    if !is_name(name) {
+                        return Err(invalid(&format!("`{}` is not a valid wildcard name", part)));
+                    }
+                    Segment::Wildcard(name.to_string())
+                } else {
+                    Segment::Static(part.to_string())
+                };
+
+            if let Some(name) = segment.name().filter(|name| !name.is_empty()) {
+                if segments.iter().any(|other| other.name() == Some(name)) {
+                    return Err(invalid(&format!("`{}` is captured twice", name)));
+                }
+            }
+            segments.push(segment);
+        }
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            segments,
+        })
+    }
+
+    /// The pattern as written
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Whether the pattern has no parameters or wildcard
+    pub fn is_static(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|segment| matches!(segment, Segment::Static(_)))
+    }
+
+    /// Match a request path, returning the captured parameters
+    ///
+    /// The query string and fragment of `path` are ignored, as are empty
+    /// segments from repeated or trailing slashes.
+    pub fn matches(&self, path: &str) -> Option<RouteParams> {
+        self.match_path(path, false)
+    }
+
+    /// Match the leading segments of a request path
+    ///
+    /// This is how layouts apply to the pages below them: `/blog` matches
+    /// `/blog/launch` as a prefix, and `/` matches every path.
+    pub fn matches_prefix(&self, path: &str) -> Option<RouteParams> {
+        self.match_path(path, true)
+    }
+
+    /// Number of segments, including parameters and a wildcard
+    pub fn depth(&self) -> usize {
+        self.segments.len()
+    }
+
+    fn match_path(&self, path: &str, prefix: bool) -> Option<RouteParams> {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let parts: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+        let mut params = RouteParams::new();
+        match_segments(&self.segments, &parts, prefix, &mut params).then_some(params)
+    }
+
+    /// Order patterns from least to most specific
+    ///
+    /// Segments are compared from the left, with static segments ranking above
+    /// parameters. A trailing wildcard ranks below the same pattern without it,
+    /// so `/blog` is more specific than `/blog/*rest` and less specific than
+    /// `/blog/:slug`.
+    pub fn cmp_specificity(&self, other: &RoutePattern) -> Ordering {
+        self.specificity().cmp(&other.specificity())
+    }
+
+    fn specificity(&self) -> (Vec<u8>, bool) {
+        let ranks = self
+            .segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Static(_) => Some(2),
+                Segment::Param(_) | Segment::OptionalParam(_) => Some(1),
+                Segment::Wildcard(_) => None,
+            })
+            .collect();
+        let has_wildcard = matches!(self.segments.last(), Some(Segment::Wildcard(_)));
+        (ranks, !has_wildcard)
+    }
+}
+
+impl Segment {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Segment::Static(_) => None,
+            Segment::Param(name) | Segment::OptionalParam(name) | Segment::Wildcard(name) => {
+                Some(name)
+            }
+        }
+    }
+}
+
+/// Match `segments` against the path `parts`, collecting captured values
+fn match_segments(
+    segments: &[Segment],
+    parts: &[&str],
+    prefix: bool,
+    params: &mut RouteParams,
+) -> bool {
+    let Some((segment, rest)) = segments.split_first() else {
+        return prefix || parts.is_empty();
+    };
+
+    match segment {
+        Segment::Static(expected) => {
+            parts.first() == Some(&expected.as_str())
+                && match_segments(rest, &parts[1..], prefix, params)
+        }
+        Segment::Param(name) => match parts.split_first() {
+            Some((part, parts)) if match_segments(rest, parts, prefix, params) => {
+                params.insert(name.clone(), decode(part));
+                true
+            }
+            _ => false,
+        },
+        Segment::OptionalParam(name) => match parts.split_first() {
+            Some((part, parts)) if match_segments(rest, parts, prefix, params) => {
+                params.insert(name.clone(), decode(part));
+                true
+            }
+            _ => match_segments(rest, parts, prefix, params),
+        },
+        Segment::Wildcard(name) => {
+            if !name.is_empty() {
+                params.insert(name.clone(), decode(&parts.join("/")));
+            }
+            true
+        }
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn decode(value: &str) -> String {
+    urlencoding::decode(value)
+        .map(|decoded| decoded.into_owned())
+        .unwrap_or_else(|_| value.to_string())
+}
+
+impl RoutePattern {
+    /// Build a pattern from `leptos_router` path segments
+    ///
+    /// Use it to key metadata by the same paths as the router, for example with
+    /// the segments of a `RouteListing` from `generate_route_list`.
+    pub fn from_segments(segments: &[PathSegment]) -> Result<Self> {
+        let mut pattern = String::new();
+        for segment in segments {
+            match segment {
+                PathSegment::Unit => continue,
+                PathSegment::Static(s) if s.trim_matches('/').is_empty() => continue,
+                PathSegment::Static(s) => pattern.push_str(&format!("/{}", s.trim_matches('/'))),
+                PathSegment::Param(name) => pattern.push_str(&format!("/:{}", name)),
+                PathSegment::OptionalParam(name) => pattern.push_str(&format!("/:{}?", name)),
+                PathSegment::Splat(name) => pattern.push_str(&format!("/*{}", name)),
+            }
+        }
+
+        if pattern.is_empty() {
+            pattern.push('/');
+        }
+        Self::new(&pattern)
+    }
+}
+
+impl FromStr for RoutePattern {
+    type Err = Error;
+
+    fn from_str(pattern: &str) -> Result<Self> {
+        Self::new(pattern)
+    }
+}
+
+impl TryFrom<String> for RoutePattern {
+    type Error = Error;
+
+    fn try_from(pattern: String) -> Result<Self> {
+        Self::new(&pattern)
+    }
+}
+
+impl From<RoutePattern> for String {
+    fn from(pattern: RoutePattern) -> Self {
+        pattern.pattern
+    }
+}
+
+impl<'de> Deserialize<'de> for RoutePattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct PatternVisitor;
+
+        impl<'de> Visitor<'de> for PatternVisitor {
+            type Value = RoutePattern;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a route pattern such as `/blog/:slug`")
+            }
+
+            // Parsing in the visitor lets formats such as YAML report the
+            // position of an invalid pattern
+            fn visit_str<E: de::Error>(
+                self,
+                pattern: &str,
+            ) -> std::result::Result<RoutePattern, E> {
+                RoutePattern::new(pattern).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(PatternVisitor)
+    }
+}
+
+impl fmt::Display for RoutePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(pattern: &str) -> RoutePattern {
+        RoutePattern::new(pattern).unwrap()
+    }
+
+    #[test]
+    fn test_match_segments() {
+        assert_eq!(pattern("/").matches("/"), Some(RouteParams::new()));
+        assert!(pattern("/").matches("/about").is_none());
+        assert!(pattern("/about").matches("/about/").is_some());
+        assert!(pattern("/about").matches("/About").is_none());
+
+        let params = pattern("/docs/*path")
+            .matches("/docs/guide/setup#top")
+            .unwrap();
+        assert_eq!(params["path"], "guide/setup");
+        assert_eq!(pattern("/docs/*path").matches("/docs").unwrap()["path"], "");
+        assert!(pattern("/docs/*").matches("/docs/a").unwrap().is_empty());
+        assert!(pattern("/docs/*path").matches("/blog").is_none());
+
+        let params = pattern("/:lang/blog/:slug")
+            .matches("/de/blog/hallo")
+            .unwrap();
+        assert_eq!(params["lang"], "de");
+        assert_eq!(params["slug"], "hallo");
+    }
+
+    #[test]
+    fn test_optional_params_and_prefixes() {
+        let optional = pattern("/shop/:category?/items");
+        assert_eq!(
+            optional.matches("/shop/shoes/items").unwrap()["category"],
+            "shoes"
+        );
+        assert!(optional.matches("/shop/items").unwrap().is_empty());
+        assert!(optional.matches("/shop/a/b/items").is_none());
+
+        assert!(pattern("/").matches_prefix("/any/path").is_some());
+        assert_eq!(
+            pattern("/:lang").matches_prefix("/de/blog").unwrap()["lang"],
+            "de"
+        );
+        assert!(pattern("/blog").matches_prefix("/blogs").is_none());
+    }
+
+    #[test]
+    fn test_from_segments() {
+        let segments = [
+            PathSegment::Static("/docs".into()),
+            PathSegment::Param("version".into()),
+            PathSegment::OptionalParam("lang".into()),
+            PathSegment::Splat("path".into()),
+        ];
+        assert_eq!(
+            RoutePattern::from_segments(&segments).unwrap().as_str(),
+            "/docs/:version/:lang?/*path"
+        );
+        assert_eq!(
+            RoutePattern::from_segments(&[PathSegment::Unit])
+                .unwrap()
+                .as_str(),
+            "/"
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        for invalid in ["blog", "/docs/*path/edit", "/blog/:", "/:id/:id", "/a/:b-c"] {
+            assert!(RoutePattern::new(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_specificity() {
+        let mut patterns: Vec<_> = [
+            "/blog/:slug",
+            "/*",
+            "/blog/new",
+            "/blog",
+            "/blog/*rest",
+            "/:section",
+        ]
+        .into_iter()
+        .map(pattern)
+        .collect();
+        patterns.sort_by(RoutePattern::cmp_specificity);
+        let sorted: Vec<_> = patterns.iter().map(RoutePattern::as_str).collect();
+        assert_eq!(
+            sorted,
+            [
+                "/*",
+                "/:section",
+                "/blog/*rest",
+                "/blog",
+                "/blog/:slug",
+                "/blog/new"
+            ]
+        );
+    }
+}
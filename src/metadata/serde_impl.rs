@@ -4,6 +4,58 @@
 //! for converting between different types in the metadata system.
 
 use super::types::*;
+use serde::{Deserialize, Deserializer};
+use std::str::FromStr;
+
+/// Deserialize either a single value or a list of values into a `Vec`
+pub(crate) fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Color(String),
+            Media {
+                color: String,
+                media: Option<String>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Color(color) => ThemeColor { color, media: None },
+            Repr::Media { color, media } => ThemeColor { color, media },
+        })
+    }
+}
+
+impl From<&str> for ThemeColor {
+    fn from(color: &str) -> Self {
+        ThemeColor::new(color)
+    }
+}
+
+impl From<String> for ThemeColor {
+    fn from(color: String) -> Self {
+        ThemeColor::new(color)
+    }
+}
 
 impl From<String> for Title {
     fn from(s: String) -> Self {
@@ -17,6 +69,59 @@ impl From<&str> for Title {
     }
 }
 
+impl FromStr for OgType {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        OgType::ALL
+            .into_iter()
+            .find(|og_type| og_type.as_str() == s)
+            .ok_or_else(|| crate::Error::InvalidMetadata(format!("Unknown Open Graph type: {}", s)))
+    }
+}
+
+impl From<&str> for IconDescriptor {
+    fn from(url: &str) -> Self {
+        IconDescriptor::new(url)
+    }
+}
+
+impl From<String> for IconDescriptor {
+    fn from(url: String) -> Self {
+        IconDescriptor {
+            url,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for OgImage {
+    fn from(url: &str) -> Self {
+        OgImage::new(url)
+    }
+}
+
+impl From<String> for OgImage {
+    fn from(url: String) -> Self {
+        OgImage {
+            url,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for OgVideo {
+    fn from(url: &str) -> Self {
+        OgVideo::new(url)
+    }
+}
+
+impl From<&str> for OgAudio {
+    fn from(url: &str) -> Self {
+        OgAudio::new(url)
+    }
+}
+
 impl From<String> for Keywords {
     fn from(s: String) -> Self {
         Keywords::Single(s)
@@ -0,0 +1,287 @@
+//! Async metadata with out-of-order streaming SSR
+//!
+//! With out-of-order streaming, Leptos flushes the document shell, including
+//! the `<head>`, before `Suspense` resources resolve. Metadata that depends on
+//! such resources would be missing from the head crawlers see. Register it with
+//! [`register_metadata_async`] instead of [`register_metadata`](super::context::register_metadata)
+//! and choose how [`MetadataContext::inject_head_stream`] handles it:
+//!
+//! - [`HeadStreaming::Block`] holds back the first chunk until the pending
+//!   metadata resolves, for at most [`LimitConfig::max_generation_time`](crate::LimitConfig::max_generation_time).
+//! - [`HeadStreaming::Patch`] flushes the head immediately and streams an inline
+//!   script that replaces the head tags once the metadata resolves.
+//!
+//! The mode is set for a context with [`MetadataContext::with_head_streaming`]
+//! or per route with [`MetadataRegistry::head_streaming`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos::prelude::*;
+//! use leptos_next_metadata::metadata::streaming::register_metadata_async;
+//! use leptos_next_metadata::prelude::*;
+//!
+//! #[component]
+//! fn Product(id: u32) -> impl IntoView {
+//!     let product = Resource::new(move || id, |id| async move {
+//!         Metadata::with_title(format!("Product {}", id))
+//!     });
+//!     register_metadata_async(product);
+//!
+//!     view! { <Suspense>"..."</Suspense> }
+//! }
+//! ```
+
+use super::context::{use_metadata_context, MetadataContext, HEAD_MARKER};
+use super::registry::MetadataRegistry;
+use super::route::RoutePattern;
+use super::types::Metadata;
+use futures::future::{join_all, BoxFuture, FutureExt};
+use std::future::IntoFuture;
+use std::time::Duration;
+
+/// Comment closing the head tags when they may be patched after the flush
+pub const HEAD_END_MARKER: &str = "<!--/leptos-next-metadata-->";
+
+/// How streamed responses handle metadata that is still pending at the flush
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeadStreaming {
+    /// Wait for pending metadata before flushing the head
+    #[default]
+    Block,
+    /// Flush the head immediately and patch it with an inline script
+    Patch,
+}
+
+/// Metadata registered with a context that has not resolved yet
+pub(crate) struct PendingMetadata {
+    /// Slot in the metadata stack reserved at registration
+    index: usize,
+    future: BoxFuture<'static, Metadata>,
+}
+
+impl MetadataContext {
+    /// Use `mode` for metadata pending when the head is streamed
+    pub fn with_head_streaming(self, mode: HeadStreaming) -> Self {
+        self.set_head_streaming(mode);
+        self
+    }
+
+    /// Change the streaming mode, e.g. from a route component
+    pub fn set_head_streaming(&self, mode: HeadStreaming) {
+        *self.head_streaming.write() = mode;
+    }
+
+    /// Current streaming mode
+    pub fn head_streaming(&self) -> HeadStreaming {
+        *self.head_streaming.read()
+    }
+
+    /// Add metadata that resolves later, such as a Leptos `Resource<Metadata>`
+    ///
+    /// The metadata keeps its position in the stack: it overrides metadata
+    /// pushed before it and is overridden by metadata pushed after it.
+    pub fn push_metadata_async<F>(&self, metadata: F)
+    where
+        F: IntoFuture<Output = Metadata>,
+        F::IntoFuture: Send + 'static,
+    {
+        let mut stack = self.metadata_stack.write();
+        stack.push(Metadata::default());
+        self.pending_metadata.lock().push(PendingMetadata {
+            index: stack.len() - 1,
+            future: metadata.into_future().boxed(),
+        });
+    }
+
+    /// Whether async metadata is still pending
+    pub fn has_pending_metadata(&self) -> bool {
+        !self.pending_metadata.lock().is_empty()
+    }
+
+    /// Wait for all pending metadata, including metadata registered meanwhile
+    pub async fn resolve_pending_metadata(&self) {
+        loop {
+            let pending = std::mem::take(&mut *self.pending_metadata.lock());
+            if pending.is_empty() {
+                break;
+            }
+            join_all(pending.into_iter().map(|pending| async move {
+                let metadata = pending.future.await;
+                if let Some(slot) = self.metadata_stack.write().get_mut(pending.index) {
+                    *slot = metadata;
+                }
+            }))
+            .await;
+        }
+    }
+
+    /// Wait for pending metadata for at most `LimitConfig::max_generation_time`
+    ///
+    /// Returns `false` if the time ran out; metadata resolved until then is kept.
+    pub async fn resolve_pending_metadata_timeout(&self) -> bool {
+        if !self.has_pending_metadata() {
+            return true;
+        }
+        let limit = Duration::from_millis(self.config.limits.max_generation_time);
+        let resolved = timeout(limit, self.resolve_pending_metadata()).await;
+        if !resolved {
+            leptos::logging::debug_warn!(
+                "async metadata did not resolve within {}ms",
+                limit.as_millis()
+            );
+        }
+        resolved
+    }
+
+    /// Inline script replacing the streamed head tags with the current ones
+    ///
+    /// The script swaps the nodes between [`HEAD_MARKER`] and
+    /// [`HEAD_END_MARKER`], which [`inject_head_stream`](Self::inject_head_stream)
+    /// writes in [`HeadStreaming::Patch`] mode, and updates `<html lang>` when
+    /// the metadata sets a language.
+    pub fn head_patch_script(&self) -> String {
+        let metadata = self.get_merged_metadata();
+        let lang = metadata
+            .lang
+            .as_deref()
+            .map(|lang| {
+                format!(
+                    "document.documentElement.lang=\"{}\";",
+                    escape_js_string(lang)
+                )
+            })
+            .unwrap_or_default();
+        format!(
+            "<script>(function(){{{lang}var h=document.head,s,e,n;\
+             for(n=h.firstChild;n;n=n.nextSibling){{if(n.nodeType===8){{\
+             if(n.data===\"{start}\")s=n;else if(n.data===\"{end}\")e=n;}}}}\
+             if(!s||!e)return;\
+             while(s.nextSibling&&s.nextSibling!==e)h.removeChild(s.nextSibling);\
+             var t=document.createElement(\"template\");t.innerHTML=\"{html}\";\
+             h.insertBefore(t.content,e);}})()</script>",
+            start = comment_data(HEAD_MARKER),
+            end = comment_data(HEAD_END_MARKER),
+            html = escape_js_string(&metadata.to_head_html()),
+        )
+    }
+}
+
+impl MetadataRegistry {
+    /// Use `mode` for paths matching `pattern` and every path below them
+    ///
+    /// The most specific matching pattern wins; [`MetadataContext::load_route`]
+    /// applies it to the context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid route pattern.
+    pub fn head_streaming(mut self, pattern: &str, mode: HeadStreaming) -> Self {
+        let pattern = RoutePattern::new(pattern).unwrap_or_else(|e| panic!("{}", e));
+        self.streaming.push((pattern, mode));
+        self
+    }
+
+    /// Streaming mode configured for the route of `path`, if any
+    pub fn head_streaming_for(&self, path: &str) -> Option<HeadStreaming> {
+        self.streaming
+            .iter()
+            .filter(|(pattern, _)| pattern.matches_prefix(path).is_some())
+            // `max_by` returns the last of equal elements, so prefer the first
+            .rev()
+            .max_by(|(a, _), (b, _)| {
+                a.depth()
+                    .cmp(&b.depth())
+                    .then_with(|| a.cmp_specificity(b))
+            })
+            .map(|(_, mode)| *mode)
+    }
+}
+
+/// Register async metadata with the nearest metadata context
+///
+/// Accepts any future resolving to [`Metadata`], including a Leptos
+/// `Resource<Metadata>`.
+pub fn register_metadata_async<F>(metadata: F)
+where
+    F: IntoFuture<Output = Metadata>,
+    F::IntoFuture: Send + 'static,
+{
+    match use_metadata_context() {
+        Some(context) => context.push_metadata_async(metadata),
+        None => leptos::logging::debug_warn!(
+            "register_metadata_async called without a metadata context; call \
+             provide_metadata_context() in an ancestor component"
+        ),
+    }
+}
+
+/// Run `future` for at most `limit`, returning whether it completed
+#[cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+async fn timeout(limit: Duration, future: impl std::future::Future<Output = ()>) -> bool {
+    tokio::time::timeout(limit, future).await.is_ok()
+}
+
+/// Without a timer the future runs to completion
+#[cfg(not(all(feature = "ssr", not(target_arch = "wasm32"))))]
+async fn timeout(_limit: Duration, future: impl std::future::Future<Output = ()>) -> bool {
+    future.await;
+    true
+}
+
+/// Text of an HTML comment
+fn comment_data(comment: &str) -> &str {
+    comment.trim_start_matches("<!--").trim_end_matches("-->")
+}
+
+/// Escape a string for a double-quoted JavaScript literal inside `<script>`
+fn escape_js_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            // Keeps `</script>` and `<!--` from ending the script early
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_js_string() {
+        assert_eq!(
+            escape_js_string("<title>\"A\\B\"</title>\n"),
+            "\\u003ctitle\\u003e\\\"A\\\\B\\\"\\u003c/title\\u003e\\n"
+        );
+        assert_eq!(comment_data(HEAD_END_MARKER), "/leptos-next-metadata");
+    }
+
+    #[test]
+    fn test_pending_metadata_keeps_its_position() {
+        let context = MetadataContext::new();
+        context.push_metadata(Metadata::with_title("Layout").description("Layout"));
+        context.push_metadata_async(async { Metadata::with_title("Product") });
+        context.push_metadata(Metadata::default().description("Page"));
+        assert!(context.has_pending_metadata());
+
+        futures::executor::block_on(context.resolve_pending_metadata());
+        assert!(!context.has_pending_metadata());
+        let merged = context.get_merged_metadata();
+        assert_eq!(
+            merged.title,
+            Some(crate::metadata::Title::Static("Product".into()))
+        );
+        assert_eq!(merged.description.as_deref(), Some("Page"));
+    }
+}
@@ -0,0 +1,451 @@
+//! Typestate builders that enforce required fields at compile time
+//!
+//! The setters in [`builder`](super::builder) accept any combination of
+//! fields, and a missing title only shows up when [`Metadata::validate`] runs.
+//! The builders here track the required fields in their type parameters, and
+//! `build()` only exists once every one of them is [`Present`]:
+//!
+//! - [`StrictMetadataBuilder`]: title, description and canonical URL
+//! - [`StrictOpenGraphBuilder`]: title, type, image and URL
+//! - [`StrictTwitterBuilder`]: depends on the card type, see its documentation
+//!
+//! Optional fields without a setter here can be set on the built value.
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::metadata::strict::{StrictOpenGraphBuilder, StrictTwitterBuilder};
+//! use leptos_next_metadata::metadata::{Metadata, OgType};
+//!
+//! let metadata = Metadata::strict()
+//!     .title("Pricing")
+//!     .description("Plans for teams of every size")
+//!     .canonical("https://acme.com/pricing")
+//!     .open_graph(
+//!         StrictOpenGraphBuilder::new()
+//!             .title("Acme pricing")
+//!             .og_type(OgType::Website)
+//!             .image("https://acme.com/og/pricing.png")
+//!             .url("https://acme.com/pricing")
+//!             .build(),
+//!     )
+//!     .twitter(
+//!         StrictTwitterBuilder::summary_large_image()
+//!             .title("Acme pricing")
+//!             .image("https://acme.com/og/pricing.png")
+//!             .build(),
+//!     )
+//!     .build();
+//!
+//! assert_eq!(metadata.canonical.as_deref(), Some("https://acme.com/pricing"));
+//! ```
+//!
+//! Leaving out a required field is a compile error:
+//!
+//! ```rust,compile_fail
+//! use leptos_next_metadata::metadata::Metadata;
+//!
+//! let metadata = Metadata::strict()
+//!     .title("Pricing")
+//!     .description("Plans for teams of every size")
+//!     .build();
+//! ```
+
+use super::types::{
+    Keywords, Metadata, OgImage, OgType, OpenGraph, Robots, Title, Twitter, TwitterCard,
+};
+use std::marker::PhantomData;
+
+/// State of a required field that has not been set
+#[derive(Debug, Clone, Copy)]
+pub enum Missing {}
+
+/// State of a required field that has been set
+#[derive(Debug, Clone, Copy)]
+pub enum Present {}
+
+/// [`Metadata`] builder requiring a title, a description and a canonical URL
+#[derive(Debug, Clone)]
+pub struct StrictMetadataBuilder<
+    HasTitle = Missing,
+    HasDescription = Missing,
+    HasCanonical = Missing,
+> {
+    metadata: Metadata,
+    state: PhantomData<(HasTitle, HasDescription, HasCanonical)>,
+}
+
+impl Metadata {
+    /// Start a [`StrictMetadataBuilder`]
+    pub fn strict() -> StrictMetadataBuilder {
+        StrictMetadataBuilder::new()
+    }
+}
+
+impl StrictMetadataBuilder {
+    /// Create a builder with no fields set
+    pub fn new() -> Self {
+        Self {
+            metadata: Metadata::default(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl Default for StrictMetadataBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, D, C> StrictMetadataBuilder<T, D, C> {
+    fn into_state<T2, D2, C2>(self) -> StrictMetadataBuilder<T2, D2, C2> {
+        StrictMetadataBuilder {
+            metadata: self.metadata,
+            state: PhantomData,
+        }
+    }
+
+    /// Set the title
+    pub fn title(mut self, title: impl Into<Title>) -> StrictMetadataBuilder<Present, D, C> {
+        self.metadata.title = Some(title.into());
+        self.into_state()
+    }
+
+    /// Set the description
+    pub fn description(
+        mut self,
+        description: impl Into<String>,
+    ) -> StrictMetadataBuilder<T, Present, C> {
+        self.metadata.description = Some(description.into());
+        self.into_state()
+    }
+
+    /// Set the canonical URL
+    pub fn canonical(
+        mut self,
+        canonical: impl Into<String>,
+    ) -> StrictMetadataBuilder<T, D, Present> {
+        self.metadata.canonical = Some(canonical.into());
+        self.into_state()
+    }
+
+    /// Set the keywords
+    pub fn keywords(mut self, keywords: impl Into<Keywords>) -> Self {
+        self.metadata.keywords = Some(keywords.into());
+        self
+    }
+
+    /// Set the robots directives
+    pub fn robots(mut self, robots: Robots) -> Self {
+        self.metadata.robots = Some(robots);
+        self
+    }
+
+    /// Set the Open Graph metadata, e.g. from a [`StrictOpenGraphBuilder`]
+    pub fn open_graph(mut self, open_graph: OpenGraph) -> Self {
+        self.metadata.open_graph = Some(open_graph);
+        self
+    }
+
+    /// Set the Twitter card, e.g. from a [`StrictTwitterBuilder`]
+    pub fn twitter(mut self, twitter: Twitter) -> Self {
+        self.metadata.twitter = Some(twitter);
+        self
+    }
+}
+
+impl StrictMetadataBuilder<Present, Present, Present> {
+    /// Build the metadata
+    pub fn build(self) -> Metadata {
+        self.metadata
+    }
+}
+
+/// [`OpenGraph`] builder requiring the four basic properties: title, type,
+/// image and URL
+#[derive(Debug, Clone)]
+pub struct StrictOpenGraphBuilder<
+    HasTitle = Missing,
+    HasType = Missing,
+    HasImage = Missing,
+    HasUrl = Missing,
+> {
+    open_graph: OpenGraph,
+    state: PhantomData<(HasTitle, HasType, HasImage, HasUrl)>,
+}
+
+impl StrictOpenGraphBuilder {
+    /// Create a builder with no properties set
+    pub fn new() -> Self {
+        Self {
+            open_graph: OpenGraph::default(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl Default for StrictOpenGraphBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, K, I, U> StrictOpenGraphBuilder<T, K, I, U> {
+    fn into_state<T2, K2, I2, U2>(self) -> StrictOpenGraphBuilder<T2, K2, I2, U2> {
+        StrictOpenGraphBuilder {
+            open_graph: self.open_graph,
+            state: PhantomData,
+        }
+    }
+
+    /// Set `og:title`
+    pub fn title(mut self, title: impl Into<String>) -> StrictOpenGraphBuilder<Present, K, I, U> {
+        self.open_graph.title = Some(title.into());
+        self.into_state()
+    }
+
+    /// Set `og:type`
+    pub fn og_type(mut self, og_type: OgType) -> StrictOpenGraphBuilder<T, Present, I, U> {
+        self.open_graph.r#type = Some(og_type);
+        self.into_state()
+    }
+
+    /// Add an image; the first one added is preferred
+    pub fn image(mut self, image: impl Into<OgImage>) -> StrictOpenGraphBuilder<T, K, Present, U> {
+        self.open_graph.images.push(image.into());
+        self.into_state()
+    }
+
+    /// Set `og:url`
+    pub fn url(mut self, url: impl Into<String>) -> StrictOpenGraphBuilder<T, K, I, Present> {
+        self.open_graph.url = Some(url.into());
+        self.into_state()
+    }
+
+    /// Set `og:description`
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.open_graph.description = Some(description.into());
+        self
+    }
+
+    /// Set `og:site_name`
+    pub fn site_name(mut self, site_name: impl Into<String>) -> Self {
+        self.open_graph.site_name = Some(site_name.into());
+        self
+    }
+
+    /// Set `og:locale`
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.open_graph.locale = Some(locale.into());
+        self
+    }
+}
+
+impl StrictOpenGraphBuilder<Present, Present, Present, Present> {
+    /// Build the Open Graph metadata
+    pub fn build(self) -> OpenGraph {
+        self.open_graph
+    }
+}
+
+/// Card type of a [`StrictTwitterBuilder`] for `summary` cards
+#[derive(Debug, Clone, Copy)]
+pub enum SummaryCard {}
+
+/// Card type of a [`StrictTwitterBuilder`] for `summary_large_image` cards
+#[derive(Debug, Clone, Copy)]
+pub enum SummaryLargeImageCard {}
+
+/// Card type of a [`StrictTwitterBuilder`] for `player` cards
+#[derive(Debug, Clone, Copy)]
+pub enum PlayerCard {}
+
+/// Card type of a [`StrictTwitterBuilder`] for `app` cards, tracking whether
+/// an app ID has been set
+#[derive(Debug, Clone, Copy)]
+pub struct AppCard<HasAppId = Missing>(PhantomData<HasAppId>);
+
+/// [`Twitter`] card builder whose required fields depend on the card type
+///
+/// | Card | Constructor | Required |
+/// |------|-------------|----------|
+/// | `summary` | [`summary`](Self::summary) | title |
+/// | `summary_large_image` | [`summary_large_image`](Self::summary_large_image) | title, image |
+/// | `player` | [`player`](Self::player) | title, site, image |
+/// | `app` | [`app`](Self::app) | site, at least one app ID |
+///
+/// The player URL and size are required by the `player` constructor.
+///
+/// ```rust,compile_fail
+/// use leptos_next_metadata::metadata::strict::StrictTwitterBuilder;
+///
+/// // A large image card without an image does not build
+/// let twitter = StrictTwitterBuilder::summary_large_image().title("Acme").build();
+/// ```
+///
+/// ```rust,compile_fail
+/// use leptos_next_metadata::metadata::strict::StrictTwitterBuilder;
+///
+/// // An app card without any app ID does not build
+/// let twitter = StrictTwitterBuilder::app().site("@acme").build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct StrictTwitterBuilder<Card, HasTitle = Missing, HasSite = Missing, HasImage = Missing> {
+    twitter: Twitter,
+    state: PhantomData<(Card, HasTitle, HasSite, HasImage)>,
+}
+
+impl<Card> StrictTwitterBuilder<Card> {
+    fn with_card(card: TwitterCard) -> Self {
+        Self {
+            twitter: Twitter {
+                card: Some(card),
+                ..Default::default()
+            },
+            state: PhantomData,
+        }
+    }
+}
+
+impl StrictTwitterBuilder<SummaryCard> {
+    /// Start a `summary` card
+    pub fn summary() -> Self {
+        Self::with_card(TwitterCard::Summary)
+    }
+}
+
+impl StrictTwitterBuilder<SummaryLargeImageCard> {
+    /// Start a `summary_large_image` card
+    pub fn summary_large_image() -> Self {
+        Self::with_card(TwitterCard::SummaryLargeImage)
+    }
+}
+
+impl StrictTwitterBuilder<PlayerCard> {
+    /// Start a `player` card for the HTTPS player iframe at `url`
+    pub fn player(url: impl Into<String>, width: u32, height: u32) -> Self {
+        let mut builder = Self::with_card(TwitterCard::Player);
+        builder.twitter.player = Some(url.into());
+        builder.twitter.player_width = Some(width);
+        builder.twitter.player_height = Some(height);
+        builder
+    }
+}
+
+impl StrictTwitterBuilder<AppCard> {
+    /// Start an `app` card, which needs the ID of at least one store
+    pub fn app() -> Self {
+        Self::with_card(TwitterCard::App)
+    }
+}
+
+impl<A, T, S, I> StrictTwitterBuilder<AppCard<A>, T, S, I> {
+    fn with_app_id(
+        mut self,
+        set: impl FnOnce(&mut Twitter),
+    ) -> StrictTwitterBuilder<AppCard<Present>, T, S, I> {
+        set(&mut self.twitter);
+        StrictTwitterBuilder {
+            twitter: self.twitter,
+            state: PhantomData,
+        }
+    }
+
+    /// Set the App Store ID of the iPhone app
+    pub fn app_id_iphone(
+        self,
+        id: impl Into<String>,
+    ) -> StrictTwitterBuilder<AppCard<Present>, T, S, I> {
+        self.with_app_id(|twitter| twitter.app_id_iphone = Some(id.into()))
+    }
+
+    /// Set the App Store ID of the iPad app
+    pub fn app_id_ipad(
+        self,
+        id: impl Into<String>,
+    ) -> StrictTwitterBuilder<AppCard<Present>, T, S, I> {
+        self.with_app_id(|twitter| twitter.app_id_ipad = Some(id.into()))
+    }
+
+    /// Set the Google Play package of the Android app
+    pub fn app_id_googleplay(
+        self,
+        id: impl Into<String>,
+    ) -> StrictTwitterBuilder<AppCard<Present>, T, S, I> {
+        self.with_app_id(|twitter| twitter.app_id_googleplay = Some(id.into()))
+    }
+}
+
+impl<Card, T, S, I> StrictTwitterBuilder<Card, T, S, I> {
+    fn into_state<T2, S2, I2>(self) -> StrictTwitterBuilder<Card, T2, S2, I2> {
+        StrictTwitterBuilder {
+            twitter: self.twitter,
+            state: PhantomData,
+        }
+    }
+
+    /// Set the title
+    pub fn title(mut self, title: impl Into<String>) -> StrictTwitterBuilder<Card, Present, S, I> {
+        self.twitter.title = Some(title.into());
+        self.into_state()
+    }
+
+    /// Set the `@username` of the website
+    pub fn site(mut self, site: impl Into<String>) -> StrictTwitterBuilder<Card, T, Present, I> {
+        self.twitter.site = Some(site.into());
+        self.into_state()
+    }
+
+    /// Set the image URL
+    pub fn image(mut self, image: impl Into<String>) -> StrictTwitterBuilder<Card, T, S, Present> {
+        self.twitter.image = Some(image.into());
+        self.into_state()
+    }
+
+    /// Set the description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.twitter.description = Some(description.into());
+        self
+    }
+
+    /// Set the `@username` of the content creator
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.twitter.creator = Some(creator.into());
+        self
+    }
+
+    /// Set the alt text of the image
+    pub fn image_alt(mut self, alt: impl Into<String>) -> Self {
+        self.twitter.image_alt = Some(alt.into());
+        self
+    }
+}
+
+impl<S, I> StrictTwitterBuilder<SummaryCard, Present, S, I> {
+    /// Build the card
+    pub fn build(self) -> Twitter {
+        self.twitter
+    }
+}
+
+impl<S> StrictTwitterBuilder<SummaryLargeImageCard, Present, S, Present> {
+    /// Build the card
+    pub fn build(self) -> Twitter {
+        self.twitter
+    }
+}
+
+impl StrictTwitterBuilder<PlayerCard, Present, Present, Present> {
+    /// Build the card
+    pub fn build(self) -> Twitter {
+        self.twitter
+    }
+}
+
+impl<T, I> StrictTwitterBuilder<AppCard<Present>, T, Present, I> {
+    /// Build the card
+    pub fn build(self) -> Twitter {
+        self.twitter
+    }
+}
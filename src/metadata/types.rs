@@ -8,15 +8,21 @@
 //! - `open_graph_types`: Open Graph related types
 //! - `twitter_types`: Twitter Card related types
 //! - `browser_types`: Browser and viewport related types
+//! - `icon_types`: Favicon and icon link types
+//! - `app_types`: Site verification, Apple web app and App Links types
 
 // Re-export all types from submodules
+pub use app_types::*;
 pub use browser_types::*;
 pub use core_types::*;
+pub use icon_types::*;
 pub use open_graph_types::*;
 pub use twitter_types::*;
 
 // Submodules
+mod app_types;
 mod browser_types;
 mod core_types;
+mod icon_types;
 mod open_graph_types;
 mod twitter_types;
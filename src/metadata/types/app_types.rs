@@ -0,0 +1,170 @@
+//! Site verification and native app metadata types
+//!
+//! This module contains the types for search engine verification tokens,
+//! Apple web app settings, the iTunes Smart App Banner and App Links.
+
+use crate::metadata::serde_impl::one_or_many;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Site ownership verification tokens
+///
+/// Each service accepts one or more tokens, rendered as one `<meta>` tag each.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::{Metadata, Verification};
+///
+/// let html = Metadata::default()
+///     .verification(Verification::default().google("google-token"))
+///     .to_head_html();
+///
+/// assert!(html.contains(r#"<meta name="google-site-verification" content="google-token">"#));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Verification {
+    /// Google Search Console tokens (`google-site-verification`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub google: Vec<String>,
+    /// Yandex Webmaster tokens (`yandex-verification`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub yandex: Vec<String>,
+    /// Yahoo tokens (`y_key`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub yahoo: Vec<String>,
+    /// Identity links (`me`), such as an email address or profile URL
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub me: Vec<String>,
+    /// Tokens for other services, keyed by `<meta>` name
+    #[serde(default)]
+    pub other: HashMap<String, Vec<String>>,
+}
+
+/// Settings for pages saved to the iOS home screen
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppleWebApp {
+    /// Whether the page runs full screen (`apple-mobile-web-app-capable`)
+    pub capable: Option<bool>,
+    /// Home screen title (`apple-mobile-web-app-title`)
+    pub title: Option<String>,
+    /// Status bar appearance (`apple-mobile-web-app-status-bar-style`)
+    pub status_bar_style: Option<AppleStatusBarStyle>,
+    /// Launch screen images (`apple-touch-startup-image`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub startup_image: Vec<AppleStartupImage>,
+}
+
+/// Status bar style of an iOS home screen web app
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppleStatusBarStyle {
+    /// White status bar with black text
+    Default,
+    /// Black status bar with white text
+    Black,
+    /// Transparent status bar over the page content
+    BlackTranslucent,
+}
+
+impl AppleStatusBarStyle {
+    /// Content value of the `apple-mobile-web-app-status-bar-style` tag
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AppleStatusBarStyle::Default => "default",
+            AppleStatusBarStyle::Black => "black",
+            AppleStatusBarStyle::BlackTranslucent => "black-translucent",
+        }
+    }
+}
+
+/// Launch screen image of an iOS home screen web app
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppleStartupImage {
+    /// Image URL
+    pub url: String,
+    /// Media query selecting the device, e.g. `(device-width: 768px)`
+    pub media: Option<String>,
+}
+
+/// iTunes Smart App Banner (`apple-itunes-app`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Itunes {
+    /// Numeric App Store identifier of the app
+    pub app_id: String,
+    /// URL passed to the app when it is opened from the banner
+    pub app_argument: Option<String>,
+}
+
+/// App Links (`al:*` properties) pointing to native apps for the page
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppLinks {
+    /// iOS apps (`al:ios`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub ios: Vec<AppLinksApple>,
+    /// iPhone apps (`al:iphone`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub iphone: Vec<AppLinksApple>,
+    /// iPad apps (`al:ipad`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub ipad: Vec<AppLinksApple>,
+    /// Android apps (`al:android`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub android: Vec<AppLinksAndroid>,
+    /// Windows Phone apps (`al:windows_phone`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub windows_phone: Vec<AppLinksWindows>,
+    /// Windows apps (`al:windows`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub windows: Vec<AppLinksWindows>,
+    /// Universal Windows apps (`al:windows_universal`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub windows_universal: Vec<AppLinksWindows>,
+    /// Web fallback (`al:web`)
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub web: Vec<AppLinksWeb>,
+}
+
+/// App Link to an iOS app
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppLinksApple {
+    /// Custom scheme URL opening the app, e.g. `acme://docs`
+    pub url: String,
+    /// Numeric App Store identifier
+    pub app_store_id: Option<String>,
+    /// App name
+    pub app_name: Option<String>,
+}
+
+/// App Link to an Android app
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppLinksAndroid {
+    /// Fully qualified package name, e.g. `com.acme.app`
+    pub package: String,
+    /// Custom scheme URL opening the app
+    pub url: Option<String>,
+    /// Activity class handling the URL
+    pub class: Option<String>,
+    /// App name
+    pub app_name: Option<String>,
+}
+
+/// App Link to a Windows app
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppLinksWindows {
+    /// Custom scheme URL opening the app
+    pub url: String,
+    /// App identifier (GUID)
+    pub app_id: Option<String>,
+    /// App name
+    pub app_name: Option<String>,
+}
+
+/// Web fallback of App Links
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AppLinksWeb {
+    /// Web URL of the content, defaults to the page URL
+    pub url: Option<String>,
+    /// Whether clients should fall back to the web URL when no app is installed
+    pub should_fallback: Option<bool>,
+}
@@ -4,9 +4,10 @@
 //! theme colors, and format detection.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Viewport configuration
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Viewport {
     /// Viewport width
     pub width: Option<ViewportWidth>,
@@ -64,6 +65,51 @@ pub enum ColorScheme {
     Normal,
 }
 
+/// Browser UI color, optionally limited to a media query
+///
+/// Deserializes from either a plain color string or a `{color, media}` object.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ThemeColor {
+    /// CSS color, e.g. `#0f172a`
+    pub color: String,
+    /// Media query selecting when the color applies, e.g.
+    /// `(prefers-color-scheme: dark)`
+    pub media: Option<String>,
+}
+
+/// Viewport, theme color and color scheme of a page
+///
+/// Mirrors the Next.js `generateViewport` export, which keeps these tags
+/// separate from the rest of the metadata. [`ViewportConfig::new`] starts from
+/// the default `width=device-width, initial-scale=1` viewport.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::{ThemeColor, ViewportConfig};
+///
+/// let html = ViewportConfig::new()
+///     .theme_color(ThemeColor::light("#ffffff"))
+///     .theme_color(ThemeColor::dark("#000000"))
+///     .to_head_html();
+///
+/// assert!(html.contains(r#"<meta name="viewport" content="width=device-width, initial-scale=1">"#));
+/// assert!(html.contains(
+///     r##"<meta name="theme-color" content="#000000" media="(prefers-color-scheme: dark)">"##
+/// ));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ViewportConfig {
+    /// `viewport` meta tag settings
+    #[serde(flatten)]
+    pub viewport: Viewport,
+    /// Theme colors, one `theme-color` tag each
+    #[serde(default, deserialize_with = "crate::metadata::serde_impl::one_or_many")]
+    pub theme_color: Vec<ThemeColor>,
+    /// Supported color schemes
+    pub color_scheme: Option<ColorScheme>,
+}
+
 /// Referrer policy
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReferrerPolicy {
@@ -86,7 +132,7 @@ pub enum ReferrerPolicy {
 }
 
 /// Format detection settings
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct FormatDetection {
     /// Detect email addresses
     pub email: Option<bool>,
@@ -111,6 +157,50 @@ pub struct AlternateLink {
     pub type_: Option<String>,
 }
 
+/// Canonical URL and alternate versions of the page
+///
+/// Mirrors the Next.js `alternates` object: `languages` maps BCP 47 language
+/// tags (and `x-default`) to translated pages, `media` maps media queries to
+/// versions such as a mobile site, and `types` maps MIME types to feeds.
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::{Alternates, Metadata};
+///
+/// let alternates = Alternates::locales("/{locale}/pricing", ["en-US", "de-DE"])
+///     .canonical("/en-US/pricing")
+///     .x_default("/pricing")
+///     .feed("application/rss+xml", "/feed.xml", Some("Acme Blog"));
+///
+/// let html = Metadata::default().alternates(alternates).to_head_html();
+/// assert!(html.contains(r#"<link rel="alternate" hreflang="de-DE" href="/de-DE/pricing">"#));
+/// assert!(html.contains(r#"<link rel="alternate" hreflang="x-default" href="/pricing">"#));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Alternates {
+    /// Canonical URL of the page
+    pub canonical: Option<String>,
+    /// Translated pages keyed by language tag, including `x-default`
+    #[serde(default)]
+    pub languages: HashMap<String, String>,
+    /// Alternate versions keyed by media query
+    #[serde(default)]
+    pub media: HashMap<String, String>,
+    /// Feeds and other representations keyed by MIME type
+    #[serde(default)]
+    pub types: HashMap<String, Vec<AlternateDescriptor>>,
+}
+
+/// An alternate representation of the page, such as an RSS feed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AlternateDescriptor {
+    /// Link URL
+    pub url: String,
+    /// Human-readable title, shown by feed readers
+    pub title: Option<String>,
+}
+
 /// JSON-LD structured data
 #[cfg(feature = "json-ld")]
 pub type JsonLd = serde_json::Value;
@@ -2,13 +2,17 @@
 //!
 //! This module contains the primary metadata container and basic types.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 // Import types from other modules
+use super::app_types::{AppLinks, AppleWebApp, Itunes, Verification};
 use super::browser_types::{
-    AlternateLink, ColorScheme, FormatDetection, JsonLd, ReferrerPolicy, Viewport,
+    AlternateLink, Alternates, ColorScheme, FormatDetection, JsonLd, ReferrerPolicy, ThemeColor,
+    Viewport,
 };
+use super::icon_types::Icons;
 use super::open_graph_types::OpenGraph;
 use super::twitter_types::Twitter;
 
@@ -28,8 +32,12 @@ use super::twitter_types::Twitter;
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Metadata {
+    /// Base URL that relative URLs in this metadata are resolved against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_base: Option<url::Url>,
+
     /// The page title
     pub title: Option<Title>,
 
@@ -61,6 +69,10 @@ pub struct Metadata {
     /// Alternate links for different languages/regions
     pub alternate_links: Vec<AlternateLink>,
 
+    /// Canonical URL and alternate versions of the page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alternates: Option<Alternates>,
+
     /// Viewport configuration
     pub viewport: Option<Viewport>,
 
@@ -76,14 +88,44 @@ pub struct Metadata {
     /// Format detection settings
     pub format_detection: Option<FormatDetection>,
 
+    /// Favicons and other icon links
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icons: Option<Icons>,
+
+    /// URL of the web app manifest, rendered as `<link rel="manifest">`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<String>,
+
+    /// Site ownership verification tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+
+    /// Settings for pages saved to the iOS home screen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apple_web_app: Option<AppleWebApp>,
+
+    /// iTunes Smart App Banner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub itunes: Option<Itunes>,
+
+    /// App Links to native apps for the page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_links: Option<AppLinks>,
+
+    /// Language of the document, set as the `lang` attribute of `<html>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
     /// Additional metadata fields
     pub additional: HashMap<String, AdditionalValue>,
 }
 
 /// Title with template support
 ///
-/// Supports both static titles and dynamic templates with default values.
-/// Templates use Liquid syntax for variable substitution.
+/// Titles follow the Next.js resolution rules: a `Template` defined at one level
+/// wraps the titles of the levels below it, with `%s` replaced by the child title.
+/// Its `default` is used when no child level provides a title. An `Absolute`
+/// title ignores any parent template.
 ///
 /// # Examples
 ///
@@ -95,16 +137,21 @@ pub struct Metadata {
 ///
 /// // Template title with default
 /// let template_title = Title::Template {
-///     template: "{{ page.title }} - {{ site.name }}".to_string(),
+///     template: "%s | My Site".to_string(),
 ///     default: "My Site".to_string(),
 /// };
+///
+/// // Title that ignores the parent template
+/// let absolute_title = Title::Absolute("My Site - Home".to_string());
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Title {
-    /// Static title string
+    /// Static title string, wrapped by the parent template
     Static(String),
-    /// Template with default fallback
+    /// Template for child levels with a default fallback
     Template { template: String, default: String },
+    /// Title that is not wrapped by the parent template
+    Absolute(String),
 }
 
 /// Page description type alias
@@ -140,33 +187,91 @@ pub struct Author {
 }
 
 /// Robots directives for search engines
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Rendered as `<meta name="robots">` plus one `<meta>` tag per crawler
+/// override, or as `X-Robots-Tag` header values with
+/// [`Robots::header_values`](Robots::header_values).
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::{BotDirectives, Robots, RobotsDirectives};
+///
+/// let robots = Robots {
+///     directives: RobotsDirectives {
+///         noarchive: Some(true),
+///         max_snippet: Some(50),
+///         ..Default::default()
+///     },
+///     ..Robots::all()
+/// }
+/// .bot("bingbot", BotDirectives::noindex());
+///
+/// assert_eq!(robots.to_string(), "index, follow, noarchive, max-snippet:50");
+/// assert_eq!(
+///     robots.header_values(),
+///     vec!["index, follow, noarchive, max-snippet:50", "bingbot: noindex"]
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Robots {
     /// Allow indexing
     pub index: Option<bool>,
     /// Allow following links
     pub follow: Option<bool>,
-    /// Allow Google to show cached version
+    /// Directives beyond indexing and following
+    #[serde(flatten)]
+    pub directives: RobotsDirectives,
+    /// Directives for Googlebot (`<meta name="googlebot">`)
     pub google_bot: Option<GoogleBot>,
+    /// Directives for other crawlers, keyed by user agent such as `bingbot`
+    #[serde(default)]
+    pub bots: HashMap<String, BotDirectives>,
     /// Additional directives
     pub other: HashMap<String, String>,
 }
 
-/// Google Bot specific directives
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct GoogleBot {
-    /// Allow indexing
-    pub index: Option<bool>,
-    /// Allow following links
-    pub follow: Option<bool>,
-    /// Maximum snippet length
+/// Robots directives beyond `index` and `follow`
+///
+/// Flags only emit their directive when set to `Some(true)`; `Some(false)`
+/// lets a page override a flag inherited from its layout.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RobotsDirectives {
+    /// Do not show a cached copy (`noarchive`)
+    pub noarchive: Option<bool>,
+    /// Do not show a text snippet or video preview (`nosnippet`)
+    pub nosnippet: Option<bool>,
+    /// Do not offer a translation (`notranslate`)
+    pub notranslate: Option<bool>,
+    /// Do not index images on the page (`noimageindex`)
+    pub noimageindex: Option<bool>,
+    /// Do not cache the page (`nocache`)
+    pub nocache: Option<bool>,
+    /// Remove the page from results after this time (`unavailable_after`)
+    pub unavailable_after: Option<DateTime<Utc>>,
+    /// Maximum snippet length in characters, `-1` for no limit
     pub max_snippet: Option<i32>,
     /// Maximum image preview size
     pub max_image_preview: Option<MaxImagePreview>,
-    /// Maximum video preview length
+    /// Maximum video preview length in seconds, `-1` for no limit
     pub max_video_preview: Option<i32>,
 }
 
+/// Directives for a single crawler
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BotDirectives {
+    /// Allow indexing
+    pub index: Option<bool>,
+    /// Allow following links
+    pub follow: Option<bool>,
+    /// Directives beyond indexing and following
+    #[serde(flatten)]
+    pub directives: RobotsDirectives,
+}
+
+/// Google Bot specific directives
+pub type GoogleBot = BotDirectives;
+
 /// Maximum image preview size for Google
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MaxImagePreview {
@@ -181,9 +286,6 @@ pub enum MaxImagePreview {
 /// Canonical URL type alias
 pub type CanonicalUrl = String;
 
-/// Theme color type alias
-pub type ThemeColor = String;
-
 /// Additional metadata value
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AdditionalValue {
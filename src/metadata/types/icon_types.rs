@@ -0,0 +1,60 @@
+//! Icon metadata types
+//!
+//! This module contains the types for favicons, Apple touch icons and other
+//! icon links such as Safari mask icons.
+
+use serde::{Deserialize, Serialize};
+
+/// Icons for the page, rendered as `<link>` tags
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::{IconDescriptor, Icons};
+///
+/// let icons = Icons::default()
+///     .icon("/favicon.ico")
+///     .apple(IconDescriptor {
+///         sizes: Some("180x180".into()),
+///         ..IconDescriptor::new("/apple-touch-icon.png")
+///     })
+///     .other(IconDescriptor {
+///         rel: Some("mask-icon".into()),
+///         color: Some("#5bbad5".into()),
+///         ..IconDescriptor::new("/safari-pinned-tab.svg")
+///     });
+///
+/// assert_eq!(icons.icon.len(), 1);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Icons {
+    /// Standard icons (`rel="icon"`)
+    #[serde(default)]
+    pub icon: Vec<IconDescriptor>,
+    /// Apple touch icons (`rel="apple-touch-icon"`)
+    #[serde(default)]
+    pub apple: Vec<IconDescriptor>,
+    /// Legacy shortcut icons (`rel="shortcut icon"`)
+    #[serde(default)]
+    pub shortcut: Vec<IconDescriptor>,
+    /// Other icon links; each entry should set its own `rel`, e.g. `mask-icon`
+    #[serde(default)]
+    pub other: Vec<IconDescriptor>,
+}
+
+/// A single icon link
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct IconDescriptor {
+    /// Icon URL
+    pub url: String,
+    /// Icon sizes, e.g. `32x32` or `any`
+    pub sizes: Option<String>,
+    /// MIME type, e.g. `image/png`
+    pub type_: Option<String>,
+    /// Media query the icon applies to, e.g. `(prefers-color-scheme: dark)`
+    pub media: Option<String>,
+    /// Link relation, overriding the default of the list the icon is in
+    pub rel: Option<String>,
+    /// Icon color, used by mask icons
+    pub color: Option<String>,
+}
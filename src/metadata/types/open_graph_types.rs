@@ -6,39 +6,64 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// OpenGraph metadata
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct OpenGraph {
     /// OpenGraph title
     pub title: Option<String>,
     /// OpenGraph description
     pub description: Option<String>,
-    /// OpenGraph image
-    pub image: Option<OgImage>,
-    /// OpenGraph video
-    pub video: Option<OgVideo>,
-    /// OpenGraph audio
-    pub audio: Option<OgAudio>,
+    /// OpenGraph object type (`og:type`)
+    pub r#type: Option<OgType>,
+    /// OpenGraph images, in order of preference
+    #[serde(
+        default,
+        alias = "image",
+        deserialize_with = "crate::metadata::serde_impl::one_or_many"
+    )]
+    pub images: Vec<OgImage>,
+    /// OpenGraph videos
+    #[serde(
+        default,
+        alias = "video",
+        deserialize_with = "crate::metadata::serde_impl::one_or_many"
+    )]
+    pub videos: Vec<OgVideo>,
+    /// OpenGraph audio files
+    #[serde(default, deserialize_with = "crate::metadata::serde_impl::one_or_many")]
+    pub audio: Vec<OgAudio>,
     /// OpenGraph URL
     pub url: Option<String>,
     /// Site name
     pub site_name: Option<String>,
     /// Locale
     pub locale: Option<String>,
+    /// Other locales the page is available in (`og:locale:alternate`)
+    #[serde(default, deserialize_with = "crate::metadata::serde_impl::one_or_many")]
+    pub alternate_locale: Vec<String>,
     /// Article metadata
     pub article: Option<Article>,
     /// Profile metadata
     pub profile: Option<Profile>,
     /// Book metadata
     pub book: Option<Book>,
+    /// Music metadata for `music.*` types
+    pub music: Option<Music>,
+    /// Video metadata for `video.*` types
+    pub video_info: Option<VideoInfo>,
+    /// Product metadata
+    pub product: Option<Product>,
     /// Additional OpenGraph properties
+    #[serde(default)]
     pub other: HashMap<String, String>,
 }
 
 /// OpenGraph image
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct OgImage {
     /// Image URL
     pub url: String,
+    /// HTTPS URL of the image, if `url` is not HTTPS
+    pub secure_url: Option<String>,
     /// Image width
     pub width: Option<u32>,
     /// Image height
@@ -50,10 +75,12 @@ pub struct OgImage {
 }
 
 /// OpenGraph video
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct OgVideo {
     /// Video URL
     pub url: String,
+    /// HTTPS URL of the video, if `url` is not HTTPS
+    pub secure_url: Option<String>,
     /// Video width
     pub width: Option<u32>,
     /// Video height
@@ -65,16 +92,18 @@ pub struct OgVideo {
 }
 
 /// OpenGraph audio
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct OgAudio {
     /// Audio URL
     pub url: String,
+    /// HTTPS URL of the audio, if `url` is not HTTPS
+    pub secure_url: Option<String>,
     /// Audio type
     pub type_: Option<String>,
 }
 
 /// Article metadata for OpenGraph
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Article {
     /// Publication time
     pub published_time: Option<String>,
@@ -91,7 +120,7 @@ pub struct Article {
 }
 
 /// Profile metadata for OpenGraph
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Profile {
     /// First name
     pub first_name: Option<String>,
@@ -104,7 +133,7 @@ pub struct Profile {
 }
 
 /// Book metadata for OpenGraph
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct Book {
     /// Author
     pub author: Option<String>,
@@ -115,3 +144,190 @@ pub struct Book {
     /// Tags
     pub tag: Option<Vec<String>>,
 }
+
+/// OpenGraph object type
+///
+/// Serialized as the `og:type` value, e.g. `"music.song"`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum OgType {
+    /// Website (the default when no type is given)
+    #[serde(rename = "website")]
+    Website,
+    /// Article
+    #[serde(rename = "article")]
+    Article,
+    /// Profile
+    #[serde(rename = "profile")]
+    Profile,
+    /// Book
+    #[serde(rename = "book")]
+    Book,
+    /// Song
+    #[serde(rename = "music.song")]
+    MusicSong,
+    /// Album
+    #[serde(rename = "music.album")]
+    MusicAlbum,
+    /// Playlist
+    #[serde(rename = "music.playlist")]
+    MusicPlaylist,
+    /// Radio station
+    #[serde(rename = "music.radio_station")]
+    MusicRadioStation,
+    /// Movie
+    #[serde(rename = "video.movie")]
+    VideoMovie,
+    /// TV show episode
+    #[serde(rename = "video.episode")]
+    VideoEpisode,
+    /// TV show
+    #[serde(rename = "video.tv_show")]
+    VideoTvShow,
+    /// Any other video
+    #[serde(rename = "video.other")]
+    VideoOther,
+    /// Product
+    #[serde(rename = "product")]
+    Product,
+}
+
+impl OgType {
+    /// All OpenGraph object types
+    pub const ALL: [OgType; 13] = [
+        OgType::Website,
+        OgType::Article,
+        OgType::Profile,
+        OgType::Book,
+        OgType::MusicSong,
+        OgType::MusicAlbum,
+        OgType::MusicPlaylist,
+        OgType::MusicRadioStation,
+        OgType::VideoMovie,
+        OgType::VideoEpisode,
+        OgType::VideoTvShow,
+        OgType::VideoOther,
+        OgType::Product,
+    ];
+
+    /// The `og:type` value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OgType::Website => "website",
+            OgType::Article => "article",
+            OgType::Profile => "profile",
+            OgType::Book => "book",
+            OgType::MusicSong => "music.song",
+            OgType::MusicAlbum => "music.album",
+            OgType::MusicPlaylist => "music.playlist",
+            OgType::MusicRadioStation => "music.radio_station",
+            OgType::VideoMovie => "video.movie",
+            OgType::VideoEpisode => "video.episode",
+            OgType::VideoTvShow => "video.tv_show",
+            OgType::VideoOther => "video.other",
+            OgType::Product => "product",
+        }
+    }
+
+    /// Whether this is one of the `music.*` types
+    pub fn is_music(&self) -> bool {
+        matches!(
+            self,
+            OgType::MusicSong
+                | OgType::MusicAlbum
+                | OgType::MusicPlaylist
+                | OgType::MusicRadioStation
+        )
+    }
+
+    /// Whether this is one of the `video.*` types
+    pub fn is_video(&self) -> bool {
+        matches!(
+            self,
+            OgType::VideoMovie | OgType::VideoEpisode | OgType::VideoTvShow | OgType::VideoOther
+        )
+    }
+}
+
+/// Music metadata for OpenGraph (`music:*` properties)
+///
+/// Which properties apply depends on the type: songs have a duration, albums and
+/// musicians; albums and playlists list songs; playlists and radio stations have
+/// a creator.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Music {
+    /// Song duration in seconds (`music:duration`)
+    pub duration: Option<u32>,
+    /// Albums the song appears on (`music:album`)
+    pub album: Option<Vec<MusicTrackRef>>,
+    /// Songs on the album or playlist (`music:song`)
+    pub song: Option<Vec<MusicTrackRef>>,
+    /// Profile URLs of the musicians (`music:musician`)
+    pub musician: Option<Vec<String>>,
+    /// Album release date (`music:release_date`)
+    pub release_date: Option<String>,
+    /// Profile URL of the playlist or station creator (`music:creator`)
+    pub creator: Option<String>,
+}
+
+/// Reference to a song or album, with its position
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MusicTrackRef {
+    /// URL of the referenced song or album
+    pub url: String,
+    /// Disc number
+    pub disc: Option<u32>,
+    /// Track number
+    pub track: Option<u32>,
+}
+
+/// Video metadata for OpenGraph (`video:*` properties)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct VideoInfo {
+    /// Actors (`video:actor`)
+    pub actor: Option<Vec<VideoActor>>,
+    /// Profile URLs of the directors (`video:director`)
+    pub director: Option<Vec<String>>,
+    /// Profile URLs of the writers (`video:writer`)
+    pub writer: Option<Vec<String>>,
+    /// Duration in seconds (`video:duration`)
+    pub duration: Option<u32>,
+    /// Release date (`video:release_date`)
+    pub release_date: Option<String>,
+    /// Tags (`video:tag`)
+    pub tag: Option<Vec<String>>,
+    /// URL of the TV show an episode belongs to (`video:series`)
+    pub series: Option<String>,
+}
+
+/// Actor in a video
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VideoActor {
+    /// Profile URL of the actor
+    pub url: String,
+    /// Role played (`video:actor:role`)
+    pub role: Option<String>,
+}
+
+/// Product metadata for OpenGraph (`product:*` properties)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Product {
+    /// Price (`product:price:amount` and `product:price:currency`)
+    pub price: Option<ProductPrice>,
+    /// Availability, e.g. `in stock` (`product:availability`)
+    pub availability: Option<String>,
+    /// Condition, e.g. `new` (`product:condition`)
+    pub condition: Option<String>,
+    /// Brand (`product:brand`)
+    pub brand: Option<String>,
+    /// Retailer item ID (`product:retailer_item_id`)
+    pub retailer_item_id: Option<String>,
+}
+
+/// Product price
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProductPrice {
+    /// Amount
+    pub amount: f64,
+    /// ISO 4217 currency code, e.g. `USD`
+    pub currency: String,
+}
@@ -5,6 +5,7 @@
 
 use super::types::*;
 use super::utils::ValidationUtils;
+use crate::metadata::resolve::resolve_url;
 use crate::metadata::*;
 
 impl Metadata {
@@ -12,6 +13,10 @@ impl Metadata {
     ///
     /// Returns a `ValidationResult` with any issues found and an overall score.
     ///
+    /// Unset robots `index` and `follow` values count as `index, follow`. A
+    /// nofollow page with a canonical URL pointing elsewhere is only reported
+    /// when `og:url` gives the page's own URL.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -58,6 +63,22 @@ impl Metadata {
             self.validate_robots(robots, &mut result);
         }
 
+        // Validate verification tokens
+        if let Some(ref verification) = self.verification {
+            self.validate_verification(verification, &mut result);
+        }
+
+        // Validate native app metadata
+        self.validate_apps(&mut result);
+
+        // Validate viewport and theme color
+        if let Some(ref viewport) = self.viewport {
+            validate_viewport(viewport, "viewport", &mut result);
+        }
+        if let Some(ref theme_color) = self.theme_color {
+            validate_theme_colors(std::slice::from_ref(theme_color), &mut result);
+        }
+
         // Check for missing recommended fields
         self.check_missing_recommended(&mut result);
 
@@ -68,7 +89,7 @@ impl Metadata {
     fn validate_title(&self, result: &mut ValidationResult) {
         if let Some(ref title) = self.title {
             match title {
-                Title::Static(s) => {
+                Title::Static(s) | Title::Absolute(s) => {
                     if s.is_empty() {
                         result.add_error(ValidationError {
                             code: ValidationErrorCode::MissingRequired,
@@ -191,35 +212,118 @@ impl Metadata {
             });
         }
 
-        // Validate image
-        if og.image.is_none() {
+        // Validate images
+        if og.images.is_empty() {
             result.add_warning(ValidationWarning {
                 code: ValidationWarningCode::MissingRecommended,
                 message: "Open Graph image is missing".to_string(),
-                field: Some("openGraph.image".to_string()),
+                field: Some("openGraph.images".to_string()),
                 suggestion: Some("Add an Open Graph image for better social sharing".to_string()),
             });
-        } else if let Some(image) = &og.image {
-            if !ValidationUtils::is_valid_url(&image.url) {
-                result.add_error(ValidationError {
-                    code: ValidationErrorCode::InvalidUrl,
-                    message: format!("Invalid Open Graph image URL: {}", image.url),
-                    field: Some("openGraph.image.url".to_string()),
-                    suggestion: Some("Provide a valid absolute URL".to_string()),
-                });
-            }
+        }
+
+        for (index, image) in og.images.iter().enumerate() {
+            let field = format!("openGraph.images[{}]", index);
+            self.validate_url(
+                &image.url,
+                "Open Graph image",
+                &format!("{}.url", field),
+                result,
+            );
 
             if image.width.is_none() || image.height.is_none() {
                 result.add_warning(ValidationWarning {
                     code: ValidationWarningCode::CouldImprove,
-                    message: "Open Graph image dimensions are missing".to_string(),
-                    field: Some("openGraph.image".to_string()),
+                    message: format!("Open Graph image dimensions are missing: {}", image.url),
+                    field: Some(field),
                     suggestion: Some("Add width and height for better performance".to_string()),
                 });
             }
         }
 
-        // Note: OpenGraph type validation removed as the current struct doesn't have a type field
+        for (index, video) in og.videos.iter().enumerate() {
+            let field = format!("openGraph.videos[{}].url", index);
+            self.validate_url(&video.url, "Open Graph video", &field, result);
+        }
+
+        for (index, audio) in og.audio.iter().enumerate() {
+            let field = format!("openGraph.audio[{}].url", index);
+            self.validate_url(&audio.url, "Open Graph audio", &field, result);
+        }
+
+        self.validate_og_type(og, result);
+    }
+
+    /// Validate that type-specific Open Graph properties match `og:type`
+    fn validate_og_type(&self, og: &OpenGraph, result: &mut ValidationResult) {
+        type MatchesType = fn(&OgType) -> bool;
+        let sections: [(&str, bool, MatchesType); 6] = [
+            ("article", og.article.is_some(), |t| *t == OgType::Article),
+            ("profile", og.profile.is_some(), |t| *t == OgType::Profile),
+            ("book", og.book.is_some(), |t| *t == OgType::Book),
+            ("music", og.music.is_some(), OgType::is_music),
+            ("videoInfo", og.video_info.is_some(), OgType::is_video),
+            ("product", og.product.is_some(), |t| *t == OgType::Product),
+        ];
+
+        for (section, present, matches_type) in sections {
+            if !present {
+                continue;
+            }
+
+            match og.r#type {
+                Some(og_type) if !matches_type(&og_type) => {
+                    result.add_error(ValidationError {
+                        code: ValidationErrorCode::InvalidFormat,
+                        message: format!(
+                            "Open Graph {} properties do not apply to type '{}'",
+                            section, og_type
+                        ),
+                        field: Some(format!("openGraph.{}", section)),
+                        suggestion: Some(format!(
+                            "Change openGraph.type or remove the {} properties",
+                            section
+                        )),
+                    });
+                }
+                None => {
+                    result.add_warning(ValidationWarning {
+                        code: ValidationWarningCode::MissingRecommended,
+                        message: format!(
+                            "Open Graph {} properties are set but the type is missing",
+                            section
+                        ),
+                        field: Some("openGraph.type".to_string()),
+                        suggestion: Some(
+                            "Set openGraph.type so crawlers read the type-specific properties"
+                                .to_string(),
+                        ),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        if let Some(price) = og.product.as_ref().and_then(|p| p.price.as_ref()) {
+            if !price.amount.is_finite() || price.amount < 0.0 {
+                result.add_error(ValidationError {
+                    code: ValidationErrorCode::InvalidFormat,
+                    message: format!("Invalid product price amount: {}", price.amount),
+                    field: Some("openGraph.product.price.amount".to_string()),
+                    suggestion: Some("Use a non-negative amount".to_string()),
+                });
+            }
+
+            if price.currency.len() != 3 || !price.currency.bytes().all(|b| b.is_ascii_uppercase())
+            {
+                result.add_error(ValidationError {
+                    code: ValidationErrorCode::InvalidFormat,
+                    message: format!("Invalid product price currency: {}", price.currency),
+                    field: Some("openGraph.product.price.currency".to_string()),
+                    suggestion: Some("Use an ISO 4217 currency code such as USD".to_string()),
+                });
+            }
+        }
     }
 
     /// Validate Twitter metadata
@@ -234,14 +338,7 @@ impl Metadata {
         }
 
         if let Some(ref image) = twitter.image {
-            if !ValidationUtils::is_valid_url(image) {
-                result.add_error(ValidationError {
-                    code: ValidationErrorCode::InvalidUrl,
-                    message: format!("Invalid Twitter image URL: {}", image),
-                    field: Some("twitter.image".to_string()),
-                    suggestion: Some("Provide a valid absolute URL".to_string()),
-                });
-            }
+            self.validate_url(image, "Twitter image", "twitter.image", result);
         }
     }
 
@@ -290,34 +387,393 @@ impl Metadata {
     /// Validate URLs in the metadata
     fn validate_urls(&self, result: &mut ValidationResult) {
         if let Some(ref canonical) = self.canonical {
-            if !ValidationUtils::is_valid_url(canonical) {
-                result.add_error(ValidationError {
-                    code: ValidationErrorCode::InvalidUrl,
-                    message: format!("Invalid canonical URL: {}", canonical),
+            self.validate_url(canonical, "canonical", "canonical", result);
+        }
+
+        for link in &self.alternate_links {
+            self.validate_url(&link.url, "alternate", "alternate_links.url", result);
+            if let Some(ref hreflang) = link.hreflang {
+                validate_hreflang(hreflang, "alternate_links.hreflang", result);
+            }
+        }
+
+        if let Some(ref alternates) = self.alternates {
+            self.validate_alternates(alternates, result);
+        }
+
+        if let Some(ref manifest) = self.manifest {
+            self.validate_url(manifest, "manifest", "manifest", result);
+        }
+    }
+
+    /// Validate the canonical URL, language tags and alternate URLs
+    fn validate_alternates(&self, alternates: &Alternates, result: &mut ValidationResult) {
+        if let Some(ref canonical) = alternates.canonical {
+            self.validate_url(canonical, "canonical", "alternates.canonical", result);
+
+            if self
+                .canonical
+                .as_ref()
+                .is_some_and(|legacy| legacy != canonical)
+            {
+                result.add_warning(ValidationWarning {
+                    code: ValidationWarningCode::CouldImprove,
+                    message: "canonical is ignored because alternates.canonical is set".to_string(),
                     field: Some("canonical".to_string()),
-                    suggestion: Some("Provide a valid absolute URL".to_string()),
+                    suggestion: Some("Set the canonical URL in one place".to_string()),
+                });
+            }
+        }
+
+        let mut languages: Vec<_> = alternates.languages.iter().collect();
+        languages.sort();
+        for (language, url) in languages {
+            let field = format!("alternates.languages.{}", language);
+            validate_hreflang(language, &field, result);
+            self.validate_url(url, "alternate language", &field, result);
+        }
+
+        if !alternates.languages.is_empty()
+            && !alternates.languages.contains_key(Alternates::X_DEFAULT)
+        {
+            result.add_warning(ValidationWarning {
+                code: ValidationWarningCode::MissingRecommended,
+                message: "Language alternates have no x-default".to_string(),
+                field: Some("alternates.languages".to_string()),
+                suggestion: Some(
+                    "Add an x-default page for visitors whose language is not listed".to_string(),
+                ),
+            });
+        }
+
+        for url in alternates.media.values() {
+            self.validate_url(url, "alternate media", "alternates.media", result);
+        }
+
+        for (mime_type, descriptors) in &alternates.types {
+            let field = format!("alternates.types.{}", mime_type);
+            let is_mime_type = mime_type
+                .split_once('/')
+                .is_some_and(|(kind, subtype)| !kind.is_empty() && !subtype.is_empty());
+            if !is_mime_type {
+                result.add_error(ValidationError {
+                    code: ValidationErrorCode::InvalidFormat,
+                    message: format!("Invalid MIME type: {}", mime_type),
+                    field: Some(field.clone()),
+                    suggestion: Some("Use a MIME type such as application/rss+xml".to_string()),
+                });
+            }
+            for descriptor in descriptors {
+                self.validate_url(&descriptor.url, "alternate type", &field, result);
+            }
+        }
+    }
+
+    /// Validate a single URL, resolving relative URLs against `metadata_base`
+    fn validate_url(&self, url: &str, label: &str, field: &str, result: &mut ValidationResult) {
+        let Some(resolved) = resolve_url(self.metadata_base.as_ref(), url) else {
+            result.add_error(ValidationError {
+                code: ValidationErrorCode::InvalidUrl,
+                message: format!(
+                    "Relative {} URL {} cannot be resolved without metadata_base",
+                    label, url
+                ),
+                field: Some(field.to_string()),
+                suggestion: Some("Set metadata_base or provide an absolute URL".to_string()),
+            });
+            return;
+        };
+
+        if !ValidationUtils::is_valid_url(&resolved) {
+            result.add_error(ValidationError {
+                code: ValidationErrorCode::InvalidUrl,
+                message: format!("Invalid {} URL: {}", label, url),
+                field: Some(field.to_string()),
+                suggestion: Some("Provide a valid absolute URL".to_string()),
+            });
+        }
+    }
+
+    /// Validate metadata and check its robots directives against robots.txt
+    ///
+    /// `path` is the URL path of the page, such as `/blog/post`. Crawling
+    /// rules for `*`, Googlebot and every crawler with its own directives are
+    /// checked: a disallowed page never shows its robots meta tags to that
+    /// crawler, so both `noindex` and `index` lose their effect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leptos_next_metadata::metadata::{Metadata, Robots, RobotsTxtRules};
+    ///
+    /// let rules = RobotsTxtRules::parse("User-agent: *\nDisallow: /drafts\n");
+    /// let metadata = Metadata::default().robots(Robots::noindex());
+    ///
+    /// let result = metadata.validate_with_robots_txt(&rules, "/drafts/post");
+    /// assert!(result.warnings.iter().any(|w| w.field.as_deref() == Some("robots.index")));
+    /// ```
+    pub fn validate_with_robots_txt(&self, rules: &RobotsTxtRules, path: &str) -> ValidationResult {
+        let mut result = self.validate();
+
+        let Some(ref robots) = self.robots else {
+            return result;
+        };
+
+        // Crawlers index pages that do not say otherwise
+        let mut crawlers = vec![(
+            "*",
+            robots.index.unwrap_or(true),
+            "robots.index".to_string(),
+        )];
+        for (user_agent, field, directives) in bot_overrides(robots) {
+            let index = directives.index.unwrap_or(robots.index.unwrap_or(true));
+            crawlers.push((user_agent, index, format!("{}.index", field)));
+        }
+
+        for (user_agent, index, field) in crawlers {
+            if rules.is_allowed(user_agent, path) {
+                continue;
+            }
+
+            let message = if index {
+                format!(
+                    "robots.txt disallows {} for {}, so the page cannot be indexed",
+                    path, user_agent
+                )
+            } else {
+                format!(
+                    "robots.txt disallows {} for {}, so noindex is never seen",
+                    path, user_agent
+                )
+            };
+            result.add_warning(ValidationWarning {
+                code: ValidationWarningCode::ConflictingDirectives,
+                message,
+                field: Some(field),
+                suggestion: Some(
+                    "Allow crawling in robots.txt so the robots directives are read".to_string(),
+                ),
+            });
+        }
+
+        result
+    }
+
+    /// Validate robots directives and report contradictory signals
+    ///
+    /// The metadata does not know the URL of its page, so a nofollow page
+    /// whose canonical URL points elsewhere is only reported when `og:url` is
+    /// set, and `og:url` stands in for the page URL.
+    fn validate_robots(&self, robots: &Robots, result: &mut ValidationResult) {
+        // Crawlers default to index, follow when a value is unset
+        let index = robots.index.unwrap_or(true);
+        let follow = robots.follow.unwrap_or(true);
+
+        validate_robots_directives(&robots.directives, "robots", result);
+
+        for (user_agent, field, directives) in bot_overrides(robots) {
+            validate_robots_directives(&directives.directives, &field, result);
+
+            let overrides = [
+                ("index", "noindex", index, directives.index),
+                ("follow", "nofollow", follow, directives.follow),
+            ];
+            for (allow, deny, general, bot) in overrides {
+                let Some(bot) = bot.filter(|bot| *bot != general) else {
+                    continue;
+                };
+                let (general, bot) = if bot { (deny, allow) } else { (allow, deny) };
+                result.add_warning(ValidationWarning {
+                    code: ValidationWarningCode::ConflictingDirectives,
+                    message: format!("robots is {} but {} is {}", general, user_agent, bot),
+                    field: Some(format!("{}.{}", field, allow)),
+                    suggestion: Some(format!(
+                        "Remove the {} override unless it is intentional",
+                        user_agent
+                    )),
+                });
+            }
+        }
+
+        let canonical = self.canonical_url();
+
+        if !index {
+            let has_alternates = !self.alternate_links.is_empty()
+                || self
+                    .alternates
+                    .as_ref()
+                    .is_some_and(|a| !a.languages.is_empty());
+            let signals = [
+                ("canonical", canonical.is_some(), "a canonical URL"),
+                ("alternates", has_alternates, "hreflang alternates"),
+            ];
+            for (field, present, signal) in signals {
+                if present {
+                    result.add_warning(ValidationWarning {
+                        code: ValidationWarningCode::ConflictingDirectives,
+                        message: format!("Page is noindex but declares {}", signal),
+                        field: Some(field.to_string()),
+                        suggestion: Some(format!(
+                            "Remove {} or allow indexing of the page",
+                            signal
+                        )),
+                    });
+                }
+            }
+        }
+
+        // Without follow, crawlers may not reach the canonical page
+        let page_url = self.open_graph.as_ref().and_then(|og| og.url.as_deref());
+        if let (false, Some(canonical), Some(page_url)) = (follow, canonical, page_url) {
+            let resolve = |url| resolve_url(self.metadata_base.as_ref(), url);
+            if resolve(canonical) != resolve(page_url) {
+                result.add_warning(ValidationWarning {
+                    code: ValidationWarningCode::ConflictingDirectives,
+                    message: format!(
+                        "Page is nofollow but its canonical URL points to {}",
+                        canonical
+                    ),
+                    field: Some("robots.follow".to_string()),
+                    suggestion: Some(
+                        "Allow following links so crawlers reach the canonical page".to_string(),
+                    ),
                 });
             }
         }
+    }
 
-        if !self.alternate_links.is_empty() {
-            for link in &self.alternate_links {
-                if !ValidationUtils::is_valid_url(&link.url) {
+    /// Validate site verification tokens
+    fn validate_verification(&self, verification: &Verification, result: &mut ValidationResult) {
+        let services = [
+            ("google", &verification.google),
+            ("yandex", &verification.yandex),
+            ("yahoo", &verification.yahoo),
+            ("me", &verification.me),
+        ];
+        let mut other: Vec<_> = verification.other.iter().collect();
+        other.sort();
+        let other = other
+            .into_iter()
+            .map(|(name, tokens)| (name.as_str(), tokens));
+
+        for (service, tokens) in services.into_iter().chain(other) {
+            if service.trim().is_empty() {
+                result.add_error(ValidationError {
+                    code: ValidationErrorCode::MissingRequired,
+                    message: "Verification service name is empty".to_string(),
+                    field: Some("verification.other".to_string()),
+                    suggestion: Some("Use the meta name expected by the service".to_string()),
+                });
+            }
+
+            for (i, token) in tokens.iter().enumerate() {
+                if token.trim().is_empty() {
                     result.add_error(ValidationError {
-                        code: ValidationErrorCode::InvalidUrl,
-                        message: format!("Invalid alternate URL: {}", link.url),
-                        field: Some("alternate_links.url".to_string()),
-                        suggestion: Some("Provide a valid absolute URL".to_string()),
+                        code: ValidationErrorCode::MissingRequired,
+                        message: format!("Empty {} verification token", service),
+                        field: Some(format!("verification.{}[{}]", service, i)),
+                        suggestion: Some("Copy the token from the service dashboard".to_string()),
                     });
                 }
             }
         }
     }
 
-    /// Validate robots directives
-    fn validate_robots(&self, _robots: &Robots, _result: &mut ValidationResult) {
-        // Note: robots validation simplified as the current struct has limited fields
-        // Could add validation for index/follow values if needed
+    /// Validate Apple web app, iTunes and App Links metadata
+    fn validate_apps(&self, result: &mut ValidationResult) {
+        if let Some(ref apple_web_app) = self.apple_web_app {
+            for (i, image) in apple_web_app.startup_image.iter().enumerate() {
+                let field = format!("appleWebApp.startupImage[{}].url", i);
+                self.validate_url(&image.url, "Apple startup image", &field, result);
+            }
+
+            if apple_web_app.status_bar_style.is_some() && apple_web_app.capable != Some(true) {
+                result.add_warning(ValidationWarning {
+                    code: ValidationWarningCode::CouldImprove,
+                    message: "Status bar style only applies to capable Apple web apps".to_string(),
+                    field: Some("appleWebApp.statusBarStyle".to_string()),
+                    suggestion: Some("Set appleWebApp.capable to true".to_string()),
+                });
+            }
+        }
+
+        if let Some(ref itunes) = self.itunes {
+            validate_store_id(&itunes.app_id, "itunes.appId", result);
+            if let Some(ref argument) = itunes.app_argument {
+                self.validate_url(
+                    argument,
+                    "iTunes app argument",
+                    "itunes.appArgument",
+                    result,
+                );
+            }
+        }
+
+        let Some(ref app_links) = self.app_links else {
+            return;
+        };
+
+        let apple = [
+            ("ios", &app_links.ios),
+            ("iphone", &app_links.iphone),
+            ("ipad", &app_links.ipad),
+        ];
+        for (platform, apps) in apple {
+            for (i, app) in apps.iter().enumerate() {
+                validate_app_url(
+                    &app.url,
+                    &format!("appLinks.{}[{}].url", platform, i),
+                    result,
+                );
+                if let Some(ref id) = app.app_store_id {
+                    let field = format!("appLinks.{}[{}].appStoreId", platform, i);
+                    validate_store_id(id, &field, result);
+                }
+            }
+        }
+
+        for (i, app) in app_links.android.iter().enumerate() {
+            let is_package = app.package.split('.').count() > 1
+                && app.package.split('.').all(|segment| {
+                    !segment.is_empty()
+                        && segment
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                });
+            if !is_package {
+                result.add_error(ValidationError {
+                    code: ValidationErrorCode::InvalidFormat,
+                    message: format!("Invalid Android package name: {}", app.package),
+                    field: Some(format!("appLinks.android[{}].package", i)),
+                    suggestion: Some("Use the application ID, e.g. com.example.app".to_string()),
+                });
+            }
+            if let Some(ref url) = app.url {
+                validate_app_url(url, &format!("appLinks.android[{}].url", i), result);
+            }
+        }
+
+        let windows = [
+            ("windowsPhone", &app_links.windows_phone),
+            ("windows", &app_links.windows),
+            ("windowsUniversal", &app_links.windows_universal),
+        ];
+        for (platform, apps) in windows {
+            for (i, app) in apps.iter().enumerate() {
+                validate_app_url(
+                    &app.url,
+                    &format!("appLinks.{}[{}].url", platform, i),
+                    result,
+                );
+            }
+        }
+
+        for (i, web) in app_links.web.iter().enumerate() {
+            if let Some(ref url) = web.url {
+                let field = format!("appLinks.web[{}].url", i);
+                self.validate_url(url, "App Links web", &field, result);
+            }
+        }
     }
 
     /// Check for missing recommended fields
@@ -350,3 +806,233 @@ impl Metadata {
         }
     }
 }
+
+impl ViewportConfig {
+    /// Validate scale ranges, zoom accessibility and theme colors
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leptos_next_metadata::metadata::{Viewport, ViewportConfig};
+    ///
+    /// let config = ViewportConfig::new().viewport(Viewport {
+    ///     user_scalable: Some(false),
+    ///     ..Viewport::device_width()
+    /// });
+    ///
+    /// let result = config.validate();
+    /// assert!(result.is_valid());
+    /// assert!(result.has_warnings());
+    /// ```
+    pub fn validate(&self) -> ValidationResult {
+        let mut result = ValidationResult::new();
+        validate_viewport(&self.viewport, "viewport", &mut result);
+        validate_theme_colors(&self.theme_color, &mut result);
+        result
+    }
+}
+
+/// Smallest and largest zoom factors browsers accept
+const SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.1..=10.0;
+
+/// Zoom factor below which users cannot enlarge text enough (WCAG 1.4.4)
+const MIN_ACCESSIBLE_MAXIMUM_SCALE: f32 = 5.0;
+
+/// Validate viewport scales and settings that prevent zooming
+fn validate_viewport(viewport: &Viewport, field: &str, result: &mut ValidationResult) {
+    let scales = [
+        ("initialScale", viewport.initial_scale),
+        ("minimumScale", viewport.minimum_scale),
+        ("maximumScale", viewport.maximum_scale),
+    ];
+    for (name, scale) in scales {
+        if scale.is_some_and(|scale| !SCALE_RANGE.contains(&scale)) {
+            result.add_error(ValidationError {
+                code: ValidationErrorCode::InvalidFormat,
+                message: format!(
+                    "{} must be between {} and {}",
+                    name,
+                    SCALE_RANGE.start(),
+                    SCALE_RANGE.end()
+                ),
+                field: Some(format!("{}.{}", field, name)),
+                suggestion: None,
+            });
+        }
+    }
+
+    let minimum = viewport.minimum_scale.unwrap_or(*SCALE_RANGE.start());
+    let maximum = viewport.maximum_scale.unwrap_or(*SCALE_RANGE.end());
+    if minimum > maximum {
+        result.add_error(ValidationError {
+            code: ValidationErrorCode::InvalidFormat,
+            message: "minimumScale is larger than maximumScale".to_string(),
+            field: Some(format!("{}.minimumScale", field)),
+            suggestion: None,
+        });
+    } else if let Some(initial) = viewport.initial_scale {
+        if initial < minimum || initial > maximum {
+            result.add_warning(ValidationWarning {
+                code: ValidationWarningCode::CouldImprove,
+                message:
+                    "initialScale is outside minimumScale and maximumScale, so browsers clamp it"
+                        .to_string(),
+                field: Some(format!("{}.initialScale", field)),
+                suggestion: None,
+            });
+        }
+    }
+
+    if viewport.user_scalable == Some(false) {
+        result.add_warning(ValidationWarning {
+            code: ValidationWarningCode::AccessibilityConsideration,
+            message: "user-scalable=no prevents users with low vision from zooming".to_string(),
+            field: Some(format!("{}.userScalable", field)),
+            suggestion: Some("Remove userScalable to allow pinch zoom".to_string()),
+        });
+    }
+
+    if viewport
+        .maximum_scale
+        .is_some_and(|scale| scale < MIN_ACCESSIBLE_MAXIMUM_SCALE)
+    {
+        result.add_warning(ValidationWarning {
+            code: ValidationWarningCode::AccessibilityConsideration,
+            message: format!(
+                "maximumScale below {} limits zooming for users with low vision",
+                MIN_ACCESSIBLE_MAXIMUM_SCALE
+            ),
+            field: Some(format!("{}.maximumScale", field)),
+            suggestion: Some("Remove maximumScale or set it to at least 5".to_string()),
+        });
+    }
+}
+
+/// Validate theme colors and their media queries
+fn validate_theme_colors(theme_colors: &[ThemeColor], result: &mut ValidationResult) {
+    let mut seen_media = std::collections::HashSet::new();
+
+    for (i, theme_color) in theme_colors.iter().enumerate() {
+        if theme_color.color.trim().is_empty() {
+            result.add_error(ValidationError {
+                code: ValidationErrorCode::MissingRequired,
+                message: "Theme color is empty".to_string(),
+                field: Some(format!("themeColor[{}].color", i)),
+                suggestion: None,
+            });
+        }
+
+        // Browsers use the first color whose media query matches
+        if !seen_media.insert(theme_color.media.as_deref()) {
+            result.add_error(ValidationError {
+                code: ValidationErrorCode::DuplicateValue,
+                message: match theme_color.media {
+                    Some(ref media) => format!("Another theme color already targets {}", media),
+                    None => "Another theme color already applies to all media".to_string(),
+                },
+                field: Some(format!("themeColor[{}].media", i)),
+                suggestion: Some("Give each theme color a distinct media query".to_string()),
+            });
+        }
+    }
+}
+
+/// Crawler overrides of robots directives with their user agent and field path
+fn bot_overrides(robots: &Robots) -> Vec<(&str, String, &BotDirectives)> {
+    let mut bots: Vec<_> = robots
+        .bots
+        .iter()
+        .map(|(user_agent, directives)| {
+            let field = format!("robots.bots.{}", user_agent);
+            (user_agent.as_str(), field, directives)
+        })
+        .collect();
+    bots.sort_by(|a, b| a.0.cmp(b.0));
+
+    let google_bot = robots
+        .google_bot
+        .iter()
+        .map(|directives| ("googlebot", "robots.googleBot".to_string(), directives));
+    google_bot.chain(bots).collect()
+}
+
+/// Validate directive values and directives that cancel each other out
+fn validate_robots_directives(
+    directives: &RobotsDirectives,
+    field: &str,
+    result: &mut ValidationResult,
+) {
+    if let Some(unavailable_after) = directives.unavailable_after {
+        if unavailable_after <= chrono::Utc::now() {
+            result.add_error(ValidationError {
+                code: ValidationErrorCode::InvalidDate,
+                message: format!(
+                    "unavailable_after {} is in the past, so the page is dropped from results",
+                    unavailable_after.to_rfc3339()
+                ),
+                field: Some(format!("{}.unavailableAfter", field)),
+                suggestion: Some("Remove unavailable_after or set a future date".to_string()),
+            });
+        }
+    }
+
+    let limits = [
+        ("maxSnippet", directives.max_snippet),
+        ("maxVideoPreview", directives.max_video_preview),
+    ];
+    for (name, limit) in limits {
+        if limit.is_some_and(|limit| limit < -1) {
+            result.add_error(ValidationError {
+                code: ValidationErrorCode::InvalidFormat,
+                message: format!("{} must be -1 (no limit) or greater", name),
+                field: Some(format!("{}.{}", field, name)),
+                suggestion: None,
+            });
+        }
+    }
+
+    if directives.nosnippet == Some(true) && directives.max_snippet.is_some() {
+        result.add_warning(ValidationWarning {
+            code: ValidationWarningCode::ConflictingDirectives,
+            message: "nosnippet overrides max-snippet".to_string(),
+            field: Some(format!("{}.maxSnippet", field)),
+            suggestion: Some("Use either nosnippet or max-snippet".to_string()),
+        });
+    }
+}
+
+/// Validate an `hreflang` value: a BCP 47 language tag or `x-default`
+fn validate_hreflang(hreflang: &str, field: &str, result: &mut ValidationResult) {
+    if hreflang != Alternates::X_DEFAULT && !ValidationUtils::is_valid_language_tag(hreflang) {
+        result.add_error(ValidationError {
+            code: ValidationErrorCode::InvalidFormat,
+            message: format!("Invalid language tag: {}", hreflang),
+            field: Some(field.to_string()),
+            suggestion: Some("Use a BCP 47 language tag such as en or en-US".to_string()),
+        });
+    }
+}
+
+/// Validate a numeric App Store identifier
+fn validate_store_id(id: &str, field: &str, result: &mut ValidationResult) {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        result.add_error(ValidationError {
+            code: ValidationErrorCode::InvalidFormat,
+            message: format!("Invalid App Store ID: {}", id),
+            field: Some(field.to_string()),
+            suggestion: Some("Use the numeric ID from the App Store URL".to_string()),
+        });
+    }
+}
+
+/// Validate a URL opening a native app, which may use a custom scheme
+fn validate_app_url(url: &str, field: &str, result: &mut ValidationResult) {
+    if url::Url::parse(url).is_err() {
+        result.add_error(ValidationError {
+            code: ValidationErrorCode::InvalidUrl,
+            message: format!("Invalid app URL: {}", url),
+            field: Some(field.to_string()),
+            suggestion: Some("Provide an absolute URL such as example://path".to_string()),
+        });
+    }
+}
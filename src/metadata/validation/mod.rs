@@ -4,10 +4,12 @@
 //! including validation rules, error handling, and utility functions.
 
 pub mod core;
+pub mod robots_txt;
 pub mod rules;
 pub mod types;
 pub mod utils;
 
+pub use robots_txt::RobotsTxtRules;
 pub use rules::*;
 pub use types::*;
 pub use utils::*;
@@ -0,0 +1,183 @@
+//! robots.txt rules
+//!
+//! This module parses the `Allow` and `Disallow` rules of a robots.txt file
+//! following RFC 9309, so robots directives can be checked against them.
+
+/// Parsed `Allow` and `Disallow` rules of a robots.txt file
+///
+/// # Example
+///
+/// ```rust
+/// use leptos_next_metadata::metadata::RobotsTxtRules;
+///
+/// let rules = RobotsTxtRules::parse(
+///     "User-agent: *\nDisallow: /admin\nAllow: /admin/login$\n",
+/// );
+///
+/// assert!(!rules.is_allowed("googlebot", "/admin/users"));
+/// assert!(rules.is_allowed("googlebot", "/admin/login"));
+/// assert!(rules.is_allowed("googlebot", "/blog"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RobotsTxtRules {
+    groups: Vec<Group>,
+}
+
+/// Rules shared by one or more user agents
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Group {
+    user_agents: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+impl Group {
+    fn names(&self, user_agent: &str) -> bool {
+        self.user_agents.iter().any(|ua| ua == user_agent)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+impl RobotsTxtRules {
+    /// Parse the contents of a robots.txt file
+    ///
+    /// Lines other than `User-agent`, `Allow` and `Disallow` are ignored.
+    pub fn parse(content: &str) -> Self {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut in_user_agents = false;
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key.trim().to_ascii_lowercase().as_str() {
+                "user-agent" => {
+                    // Consecutive user agent lines share the rules that follow
+                    if !in_user_agents {
+                        groups.push(Group::default());
+                        in_user_agents = true;
+                    }
+                    if let Some(group) = groups.last_mut() {
+                        group.user_agents.push(value.to_ascii_lowercase());
+                    }
+                }
+                directive @ ("allow" | "disallow") => {
+                    in_user_agents = false;
+                    // An empty `Disallow` allows everything
+                    if value.is_empty() {
+                        continue;
+                    }
+                    if let Some(group) = groups.last_mut() {
+                        group.rules.push(Rule {
+                            allow: directive == "allow",
+                            pattern: value.to_string(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self { groups }
+    }
+
+    /// Whether `user_agent` may crawl `path`
+    ///
+    /// Uses the groups naming the user agent, or the `*` group when none does.
+    /// The longest matching rule wins, and `Allow` wins a tie.
+    pub fn is_allowed(&self, user_agent: &str, path: &str) -> bool {
+        let user_agent = user_agent.to_ascii_lowercase();
+        let names = |agent: &str| self.groups.iter().any(|group| group.names(agent));
+        let agent = if names(&user_agent) { &user_agent } else { "*" };
+
+        let rules = self
+            .groups
+            .iter()
+            .filter(|group| group.names(agent))
+            .flat_map(|group| &group.rules);
+
+        rules
+            .filter(|rule| matches_pattern(&rule.pattern, path))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .map_or(true, |rule| rule.allow)
+    }
+}
+
+/// Match a path against a rule pattern with `*` wildcards and a `$` end anchor
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = path.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        if anchored && i == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+
+    !anchored || rest.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("/", "/anything"));
+        assert!(matches_pattern("/fish", "/fish.html"));
+        assert!(!matches_pattern("/fish", "/Fish"));
+        assert!(matches_pattern("/*.php", "/index.php?page=1"));
+        assert!(matches_pattern("/*.php$", "/folder/index.php"));
+        assert!(!matches_pattern("/*.php$", "/index.php?page=1"));
+        assert!(matches_pattern("/fish*", "/fish"));
+        assert!(matches_pattern("/about$", "/about"));
+        assert!(!matches_pattern("/about$", "/about/team"));
+    }
+
+    #[test]
+    fn test_group_selection_and_precedence() {
+        let rules = RobotsTxtRules::parse(
+            "# Example\n\
+             User-agent: *\n\
+             Disallow: /private\n\
+             \n\
+             User-agent: Googlebot\n\
+             User-agent: bingbot\n\
+             Disallow: /\n\
+             Allow: /public\n\
+             Disallow: /public/drafts\n\
+             \n\
+             User-agent: duckduckbot\n\
+             Disallow:\n",
+        );
+
+        assert!(!rules.is_allowed("otherbot", "/private/page"));
+        assert!(rules.is_allowed("otherbot", "/blog"));
+
+        assert!(!rules.is_allowed("googlebot", "/blog"));
+        assert!(rules.is_allowed("GoogleBot", "/public/page"));
+        assert!(!rules.is_allowed("bingbot", "/public/drafts/1"));
+
+        assert!(rules.is_allowed("duckduckbot", "/private/page"));
+        assert!(RobotsTxtRules::default().is_allowed("*", "/"));
+    }
+}
@@ -96,6 +96,9 @@ pub enum ValidationWarningCode {
 
     /// Accessibility consideration
     AccessibilityConsideration,
+
+    /// Directives that send contradictory signals
+    ConflictingDirectives,
 }
 
 /// Overall validation status
@@ -20,6 +20,82 @@ impl ValidationUtils {
             false
         }
     }
+
+    /// Check if a string is a well-formed BCP 47 language tag
+    ///
+    /// Accepts tags such as `en`, `en-US`, `es-419`, `sr-Latn-RS`, `de-CH-1901`
+    /// and private use tags like `x-klingon`. Subtags are only checked for their
+    /// shape, not against the IANA registry.
+    pub fn is_valid_language_tag(tag: &str) -> bool {
+        let subtags: Vec<&str> = tag.split('-').collect();
+        let well_formed = |s: &&str| {
+            !s.is_empty() && s.len() <= 8 && s.chars().all(|c| c.is_ascii_alphanumeric())
+        };
+        if !subtags.iter().all(well_formed) {
+            return false;
+        }
+
+        let alpha = |s: &str| s.chars().all(|c| c.is_ascii_alphabetic());
+        let digit = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        let private_use = |s: &[&str]| s.len() > 1 && s[0].eq_ignore_ascii_case("x");
+
+        if private_use(&subtags) {
+            return true;
+        }
+
+        // Primary language: 2-3 letters, or 5-8 letters for registered languages
+        let language = subtags[0];
+        if !alpha(language) || !matches!(language.len(), 2 | 3 | 5..=8) {
+            return false;
+        }
+        let mut rest = &subtags[1..];
+
+        // Up to three extended language subtags, such as `zh-yue`
+        let mut extlangs = 0;
+        while language.len() <= 3 && extlangs < 3 {
+            match rest.first() {
+                Some(s) if s.len() == 3 && alpha(s) => {
+                    rest = &rest[1..];
+                    extlangs += 1;
+                }
+                _ => break,
+            }
+        }
+
+        // Script, such as `Latn`
+        if rest.first().is_some_and(|s| s.len() == 4 && alpha(s)) {
+            rest = &rest[1..];
+        }
+
+        // Region, such as `US` or `419`
+        if rest
+            .first()
+            .is_some_and(|s| (s.len() == 2 && alpha(s)) || (s.len() == 3 && digit(s)))
+        {
+            rest = &rest[1..];
+        }
+
+        // Variants, such as `1901` or `valencia`
+        while rest.first().is_some_and(|s| {
+            s.len() >= 5 || (s.len() == 4 && s.starts_with(|c: char| c.is_ascii_digit()))
+        }) {
+            rest = &rest[1..];
+        }
+
+        // Extensions: a singleton followed by subtags of 2-8 characters
+        while rest
+            .first()
+            .is_some_and(|s| s.len() == 1 && !s.eq_ignore_ascii_case("x"))
+        {
+            let length = rest[1..].iter().take_while(|s| s.len() >= 2).count();
+            if length == 0 {
+                return false;
+            }
+            rest = &rest[1 + length..];
+        }
+
+        rest.is_empty() || private_use(rest)
+    }
 }
 
 /// Validator for specific metadata types
@@ -84,26 +160,43 @@ impl MetadataValidator {
         warnings
     }
 
-    /// Validate an Open Graph image
-    pub fn validate_og_image(image: &OgImage) -> Vec<ValidationError> {
+    /// Validate each Open Graph image
+    ///
+    /// Error fields are prefixed with the index of the image, e.g. `images[1].url`.
+    pub fn validate_og_image(images: &[OgImage]) -> Vec<ValidationError> {
         let mut errors = Vec::new();
 
-        if url::Url::parse(&image.url).is_err() {
-            errors.push(ValidationError {
-                code: ValidationErrorCode::InvalidUrl,
-                message: format!("Invalid Open Graph image URL: {}", image.url),
-                field: Some("url".to_string()),
-                suggestion: Some("Provide a valid absolute URL".to_string()),
-            });
-        }
-
-        if image.width.is_none() || image.height.is_none() {
-            errors.push(ValidationError {
-                code: ValidationErrorCode::MissingRequired,
-                message: "Open Graph image dimensions are missing".to_string(),
-                field: Some("dimensions".to_string()),
-                suggestion: Some("Add width and height for better performance".to_string()),
-            });
+        for (index, image) in images.iter().enumerate() {
+            let field = |name: &str| Some(format!("images[{}].{}", index, name));
+
+            if url::Url::parse(&image.url).is_err() {
+                errors.push(ValidationError {
+                    code: ValidationErrorCode::InvalidUrl,
+                    message: format!("Invalid Open Graph image URL: {}", image.url),
+                    field: field("url"),
+                    suggestion: Some("Provide a valid absolute URL".to_string()),
+                });
+            }
+
+            if let Some(ref secure_url) = image.secure_url {
+                if !secure_url.starts_with("https://") || url::Url::parse(secure_url).is_err() {
+                    errors.push(ValidationError {
+                        code: ValidationErrorCode::InvalidUrl,
+                        message: format!("Invalid Open Graph image secure URL: {}", secure_url),
+                        field: field("secure_url"),
+                        suggestion: Some("Provide an absolute https:// URL".to_string()),
+                    });
+                }
+            }
+
+            if image.width.is_none() || image.height.is_none() {
+                errors.push(ValidationError {
+                    code: ValidationErrorCode::MissingRequired,
+                    message: "Open Graph image dimensions are missing".to_string(),
+                    field: field("dimensions"),
+                    suggestion: Some("Add width and height for better performance".to_string()),
+                });
+            }
         }
 
         errors
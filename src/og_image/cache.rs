@@ -60,8 +60,8 @@ impl CacheEntry {
 pub struct CacheKey {
     /// Template name
     pub template: String,
-    /// Serialized template data
-    pub data_hash: u64,
+    /// Fingerprint of all parameters, see [`OgImageParams::fingerprint`]
+    pub fingerprint: String,
     /// Image dimensions
     pub size: (u32, u32),
     /// Image format
@@ -69,30 +69,21 @@ pub struct CacheKey {
 }
 
 impl CacheKey {
-    pub fn new(params: &OgImageParams) -> Self {
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
-
-        // Hash the template data by serializing to string
-        for (key, value) in &params.data {
-            key.hash(&mut hasher);
-            // Serialize the value to string for hashing since liquid::Value doesn't implement Hash
-            let value_str = serde_json::to_string(value).unwrap_or_default();
-            value_str.hash(&mut hasher);
-        }
-
-        Self {
+    /// Key for `params`, failing if their template data cannot be fingerprinted
+    pub fn new(params: &OgImageParams) -> Result<Self> {
+        Ok(Self {
             template: params.template.clone(),
-            data_hash: hasher.finish(),
+            fingerprint: params.fingerprint()?,
             size: params.size.unwrap_or((1200, 630)),
             format: format!("{:?}", params.format),
-        }
+        })
     }
 }
 
 impl Hash for CacheKey {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.template.hash(state);
-        self.data_hash.hash(state);
+        self.fingerprint.hash(state);
         self.size.hash(state);
         self.format.hash(state);
     }
@@ -109,7 +109,7 @@ impl OgImageGenerator {
         let _timing_guard = TimingGuard::new(self.metrics.clone());
 
         // Check cache first
-        let cache_key = CacheKey::new(&params);
+        let cache_key = CacheKey::new(&params)?;
         if let Some(cached_data) = self.cache.get(&cache_key).await? {
             self.metrics.record_cache_hit();
             let content_type = match self.config.format {
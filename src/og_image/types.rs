@@ -191,3 +191,44 @@ impl OgImageParams {
         self
     }
 }
+
+impl OgImageParams {
+    /// Stable fingerprint of every parameter, for cache keys
+    ///
+    /// See [`crate::metadata::fingerprint`]. Fails if a template value cannot
+    /// be serialized, such as a date liquid cannot format.
+    pub fn fingerprint(&self) -> crate::Result<String> {
+        #[derive(serde::Serialize)]
+        struct Canonical<'a, D> {
+            template: &'a str,
+            data: &'a D,
+            size: Option<(u32, u32)>,
+            background_color: Option<[u8; 4]>,
+            text_color: Option<[u8; 4]>,
+            format: String,
+        }
+
+        #[cfg(feature = "og-images")]
+        let (background_color, text_color) = (
+            self.background_color.map(|color| color.0),
+            self.text_color.map(|color| color.0),
+        );
+        #[cfg(not(feature = "og-images"))]
+        let (background_color, text_color) = (None, None);
+
+        crate::metadata::fingerprint::fingerprint(&Canonical {
+            template: &self.template,
+            data: &self.data,
+            size: self.size,
+            background_color,
+            text_color,
+            format: format!("{:?}", self.format),
+        })
+    }
+
+    /// Strong ETag for the image generated from these parameters
+    pub fn etag(&self) -> crate::Result<String> {
+        self.fingerprint()
+            .map(|fingerprint| crate::metadata::fingerprint::etag(&fingerprint))
+    }
+}
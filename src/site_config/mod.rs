@@ -0,0 +1,632 @@
+//! Site-wide metadata defaults from a config file
+//!
+//! A `metadata.toml` or `metadata.yaml` file declares the defaults shared by
+//! every page: site name, base URL, title template, Twitter site, locales,
+//! default Open Graph image, organization JSON-LD and per-route overrides.
+//! [`SiteConfig`] loads and validates it, then provides a [`MetadataConfig`]
+//! and the base [`Metadata`] layer for the root of the layout chain.
+//!
+//! ```toml
+//! site_name = "Acme"
+//! base_url = "https://acme.com"
+//! title_template = "%s | Acme"
+//! description = "Tools for teams"
+//! twitter_site = "@acme"
+//! locales = ["en-US", "de-DE"]
+//! default_og_image = { url = "/og.png", width = 1200, height = 630 }
+//!
+//! [organization]
+//! logo = "/logo.png"
+//!
+//! [[routes]]
+//! pattern = "/blog/*rest"
+//! title = "Blog"
+//! title_template = "%s | Acme Blog"
+//! og_image = "/og/blog.png"
+//! ```
+//!
+//! Errors name the file, line and column of the offending value.
+//!
+//! # Example
+//!
+//! ```rust
+//! use leptos_next_metadata::site_config::SiteConfig;
+//! use leptos_next_metadata::metadata::Title;
+//!
+//! let config = SiteConfig::from_yaml_str(
+//!     "site_name: Acme\n\
+//!      base_url: https://acme.com\n\
+//!      title_template: \"%s | Acme\"\n\
+//!      routes: [{ pattern: /admin/*rest, noindex: true }]\n",
+//! )
+//! .unwrap();
+//!
+//! let page = config.metadata_for("/admin/users");
+//! assert_eq!(page.title, Some(Title::Static("Acme".into())));
+//! assert_eq!(page.robots.map(|robots| robots.index), Some(Some(false)));
+//! ```
+
+use crate::metadata::resolve::resolve_url;
+use crate::metadata::route::RoutePattern;
+use crate::metadata::{
+    merge_metadata_chain, Keywords, Metadata, OgImage, OgType, OpenGraph, Robots, Title, Twitter,
+    TwitterCard, TITLE_PLACEHOLDER,
+};
+use crate::{Error, MetadataConfig, Result};
+use serde::de::{self, DeserializeSeed, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+/// File names searched by [`SiteConfig::discover`], in order
+pub const CONFIG_FILE_NAMES: [&str; 3] = ["metadata.toml", "metadata.yaml", "metadata.yml"];
+
+/// Site-wide metadata defaults
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteConfig {
+    /// Site name, used for `og:site_name` and as the default title
+    pub site_name: Option<String>,
+    /// Absolute base URL that relative URLs are resolved against
+    pub base_url: Option<Url>,
+    /// Title template wrapping page titles, containing `%s`
+    ///
+    /// Requires `default_title` or `site_name`.
+    #[serde(default, deserialize_with = "title_template")]
+    pub title_template: Option<String>,
+    /// Title of pages that set none, defaulting to the site name
+    pub default_title: Option<String>,
+    /// Default page description
+    pub description: Option<String>,
+    /// Twitter handle of the site, e.g. `@acme`
+    #[serde(default, deserialize_with = "twitter_handle")]
+    pub twitter_site: Option<String>,
+    /// Default locale, defaulting to the first of `locales`
+    #[serde(default, deserialize_with = "locale")]
+    pub default_locale: Option<String>,
+    /// Locales the site is served in
+    #[serde(default, deserialize_with = "locales")]
+    pub locales: Vec<String>,
+    /// Default Open Graph and Twitter image
+    pub default_og_image: Option<SiteImage>,
+    /// Schema.org `Organization` properties for the site's JSON-LD
+    ///
+    /// `@context`, `@type`, `name` and `url` default to schema.org,
+    /// `Organization`, the site name and the base URL.
+    pub organization: Option<Map<String, Value>>,
+    /// Overrides for pages matching a route pattern
+    #[serde(default)]
+    pub routes: Vec<RouteOverride>,
+}
+
+/// Image given as a URL or with its dimensions
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum SiteImage {
+    /// Image URL
+    Url(String),
+    /// Image with dimensions and alt text
+    Image {
+        url: String,
+        width: Option<u32>,
+        height: Option<u32>,
+        alt: Option<String>,
+    },
+}
+
+/// Metadata overrides for pages matching a route pattern
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RouteOverride {
+    /// Route pattern, e.g. `/blog/:slug` or `/docs/*path`
+    pub pattern: RoutePattern,
+    /// Title of the matched pages
+    pub title: Option<String>,
+    /// Title template for pages below the matched ones, containing `%s`
+    ///
+    /// Requires `title`, which the parent template wraps as usual.
+    #[serde(default, deserialize_with = "title_template")]
+    pub title_template: Option<String>,
+    /// Description of the matched pages
+    pub description: Option<String>,
+    /// Keywords of the matched pages
+    pub keywords: Option<Vec<String>>,
+    /// Open Graph and Twitter image of the matched pages
+    pub og_image: Option<SiteImage>,
+    /// Keep the matched pages out of search results
+    #[serde(default)]
+    pub noindex: bool,
+}
+
+/// Format of a config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// TOML
+    Toml,
+    /// YAML
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Format of a file by its extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "toml" => Some(ConfigFormat::Toml),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            _ => None,
+        }
+    }
+}
+
+impl SiteConfig {
+    /// Parse a TOML config
+    pub fn from_toml_str(source: &str) -> Result<Self> {
+        Self::parse(source, ConfigFormat::Toml)
+    }
+
+    /// Parse a YAML config
+    pub fn from_yaml_str(source: &str) -> Result<Self> {
+        Self::parse(source, ConfigFormat::Yaml)
+    }
+
+    /// Parse a config in the given format
+    pub fn parse(source: &str, format: ConfigFormat) -> Result<Self> {
+        let config: SiteConfig = match format {
+            ConfigFormat::Toml => toml::from_str(source).map_err(|e| {
+                let (line, column) = e
+                    .span()
+                    .map(|span| line_column(source, span.start))
+                    .unwrap_or((1, 1));
+                config_error(line, column, e.message())
+            })?,
+            ConfigFormat::Yaml => serde_yaml::from_str::<Option<SiteConfig>>(source)
+                .map(Option::unwrap_or_default)
+                .map_err(|e| {
+                    let message = e.to_string();
+                    match e.location() {
+                        Some(location) => {
+                            let suffix = format!(
+                                " at line {} column {}",
+                                location.line(),
+                                location.column()
+                            );
+                            let message = message.strip_suffix(&suffix).unwrap_or(&message);
+                            config_error(location.line(), location.column(), message)
+                        }
+                        None => Error::ConfigError(message),
+                    }
+                })?,
+        };
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Read a `.toml`, `.yaml` or `.yml` config file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path).ok_or_else(|| {
+            Error::ConfigError(format!(
+                "{}: expected a .toml, .yaml or .yml file",
+                path.display()
+            ))
+        })?;
+        let source = std::fs::read_to_string(path)?;
+
+        Self::parse(&source, format).map_err(|e| match e {
+            Error::ConfigError(message) => {
+                Error::ConfigError(format!("{}: {}", path.display(), message))
+            }
+            e => e,
+        })
+    }
+
+    /// Load the first of [`CONFIG_FILE_NAMES`] found in `dir`
+    ///
+    /// Returns `Ok(None)` if there is no config file.
+    pub fn discover(dir: impl AsRef<Path>) -> Result<Option<Self>> {
+        Self::find(dir).map(Self::from_file).transpose()
+    }
+
+    /// Path of the first of [`CONFIG_FILE_NAMES`] found in `dir`
+    pub fn find(dir: impl AsRef<Path>) -> Option<PathBuf> {
+        CONFIG_FILE_NAMES
+            .iter()
+            .map(|name| dir.as_ref().join(name))
+            .find(|path| path.is_file())
+    }
+
+    /// Checks that need more than one field
+    fn validate(&self) -> Result<()> {
+        if let Some(ref locale) = self.default_locale {
+            if !self.locales.is_empty() && !self.locales.contains(locale) {
+                return Err(Error::ConfigError(format!(
+                    "`default_locale` \"{}\" is not listed in `locales`",
+                    locale
+                )));
+            }
+        }
+
+        if self.title_template.is_some() && self.default_title.is_none() && self.site_name.is_none()
+        {
+            return Err(Error::ConfigError(
+                "`title_template` needs a `default_title` or `site_name`".to_string(),
+            ));
+        }
+
+        for route in &self.routes {
+            if route.title_template.is_some() && route.title.is_none() {
+                return Err(Error::ConfigError(format!(
+                    "route \"{}\": `title_template` needs a `title`",
+                    route.pattern
+                )));
+            }
+        }
+
+        if self.base_url.is_none() {
+            let images = self.default_og_image.iter().chain(
+                self.routes
+                    .iter()
+                    .filter_map(|route| route.og_image.as_ref()),
+            );
+            for image in images {
+                if Url::parse(image.url()).is_err() {
+                    return Err(Error::ConfigError(format!(
+                        "image \"{}\" is relative, which needs a `base_url`",
+                        image.url()
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The default locale: `default_locale`, or else the first of `locales`
+    pub fn locale(&self) -> Option<&str> {
+        self.default_locale
+            .as_deref()
+            .or_else(|| self.locales.first().map(String::as_str))
+    }
+
+    /// Metadata system configuration with the site's base URL
+    pub fn metadata_config(&self) -> MetadataConfig {
+        MetadataConfig {
+            metadata_base: self.base_url.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Base metadata layer for the root layout
+    pub fn base_metadata(&self) -> Metadata {
+        let default_title = self
+            .default_title
+            .clone()
+            .or_else(|| self.site_name.clone());
+        let title = match (self.title_template.clone(), default_title) {
+            (Some(template), Some(default)) => Some(Title::Template { template, default }),
+            (_, default) => default.map(Title::Static),
+        };
+
+        let images: Vec<OgImage> = self
+            .default_og_image
+            .iter()
+            .map(SiteImage::to_og_image)
+            .collect();
+        let mut metadata = Metadata {
+            metadata_base: self.base_url.clone(),
+            title,
+            description: self.description.clone(),
+            open_graph: Some(OpenGraph {
+                r#type: Some(OgType::Website),
+                site_name: self.site_name.clone(),
+                description: self.description.clone(),
+                locale: self.locale().map(|locale| locale.replace('-', "_")),
+                images: images.clone(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        if self.twitter_site.is_some() || !images.is_empty() {
+            metadata.twitter = Some(Twitter {
+                card: Some(if images.is_empty() {
+                    TwitterCard::Summary
+                } else {
+                    TwitterCard::SummaryLargeImage
+                }),
+                site: self.twitter_site.clone(),
+                image: images.first().map(|image| image.url.clone()),
+                ..Default::default()
+            });
+        }
+
+        if let Some(ref organization) = self.organization {
+            metadata.json_ld = Some(self.organization_json_ld(organization));
+        }
+
+        metadata.resolve_relative_urls(self.base_url.as_ref());
+        metadata
+    }
+
+    /// Route overrides matching `path`, from least to most specific
+    pub fn matching_routes<'a>(&'a self, path: &'a str) -> Vec<&'a RouteOverride> {
+        let mut routes: Vec<_> = self
+            .routes
+            .iter()
+            .filter(|route| route.pattern.matches(path).is_some())
+            .collect();
+        routes.sort_by(|a, b| a.pattern.cmp_specificity(&b.pattern));
+        routes
+    }
+
+    /// Metadata layers for `path`: the base layer, then each matching override
+    ///
+    /// Pass them to [`merge_metadata_chain`] ahead of the page's own layers.
+    pub fn layers(&self, path: &str) -> Vec<Metadata> {
+        let base = self.base_metadata();
+        let mut open_graph = base.open_graph.clone();
+        let mut twitter = base.twitter.clone();
+        let mut layers = vec![base];
+
+        for route in self.matching_routes(path) {
+            let mut layer = Metadata {
+                title: match (route.title_template.clone(), route.title.clone()) {
+                    (Some(template), Some(default)) => Some(Title::Template { template, default }),
+                    (_, title) => title.map(Title::Static),
+                },
+                description: route.description.clone(),
+                keywords: route.keywords.clone().map(Keywords::Multiple),
+                robots: route.noindex.then(Robots::noindex),
+                ..Default::default()
+            };
+
+            // Open Graph and Twitter sections replace the inherited ones when
+            // merged, so carry the site-wide values into the override
+            if route.description.is_some() || route.og_image.is_some() {
+                let og = open_graph.get_or_insert_with(OpenGraph::default);
+                if let Some(ref description) = route.description {
+                    og.description = Some(description.clone());
+                }
+                if let Some(ref image) = route.og_image {
+                    og.images = vec![image.to_og_image()];
+                    let twitter = twitter.get_or_insert_with(Twitter::default);
+                    twitter.card = Some(TwitterCard::SummaryLargeImage);
+                    twitter.image = Some(image.url().to_string());
+                    layer.twitter = Some(twitter.clone());
+                }
+                layer.open_graph = Some(og.clone());
+            }
+
+            layer.resolve_relative_urls(self.base_url.as_ref());
+            layers.push(layer);
+        }
+
+        layers
+    }
+
+    /// Site metadata for `path`, with all matching overrides applied
+    pub fn metadata_for(&self, path: &str) -> Metadata {
+        merge_metadata_chain(self.layers(path))
+    }
+
+    fn organization_json_ld(&self, organization: &Map<String, Value>) -> Value {
+        let mut json_ld = Map::new();
+        json_ld.insert("@context".to_string(), Value::from("https://schema.org"));
+        json_ld.insert("@type".to_string(), Value::from("Organization"));
+        if let Some(ref name) = self.site_name {
+            json_ld.insert("name".to_string(), Value::from(name.as_str()));
+        }
+        if let Some(ref base_url) = self.base_url {
+            json_ld.insert("url".to_string(), Value::from(base_url.as_str()));
+        }
+        json_ld.extend(organization.clone());
+
+        // JSON-LD is not resolved when rendering, so resolve the common URL
+        // properties here, the same way as the metadata layers
+        if let Some(ref base_url) = self.base_url {
+            for key in ["url", "logo", "image"] {
+                if let Some(Value::String(url)) = json_ld.get_mut(key) {
+                    if let Some(absolute) = resolve_url(Some(base_url), url) {
+                        *url = absolute;
+                    }
+                }
+            }
+        }
+
+        Value::Object(json_ld)
+    }
+}
+
+impl SiteImage {
+    /// Image URL
+    pub fn url(&self) -> &str {
+        match self {
+            SiteImage::Url(url) | SiteImage::Image { url, .. } => url,
+        }
+    }
+
+    /// Open Graph image
+    pub fn to_og_image(&self) -> OgImage {
+        match self {
+            SiteImage::Url(url) => OgImage::new(url),
+            SiteImage::Image {
+                url,
+                width,
+                height,
+                alt,
+            } => OgImage {
+                url: url.clone(),
+                width: *width,
+                height: *height,
+                alt: alt.clone(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+fn config_error(line: usize, column: usize, message: &str) -> Error {
+    Error::ConfigError(format!("line {}, column {}: {}", line, column, message))
+}
+
+/// One-based line and column of a byte offset
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+    (line, column)
+}
+
+// Values are checked inside a visitor, where serde_yaml still knows the
+// position of the value and adds it to the error
+
+/// Deserializes a string that passes `check`
+#[derive(Clone, Copy)]
+struct Checked(fn(&str) -> std::result::Result<(), String>);
+
+impl<'de> Visitor<'de> for Checked {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<String, E> {
+        (self.0)(value).map_err(E::custom)?;
+        Ok(value.to_string())
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Checked {
+    type Value = String;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<String, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+/// Deserializes a list of strings that pass `check`
+struct CheckedList(Checked);
+
+impl<'de> Visitor<'de> for CheckedList {
+    type Value = Vec<String>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list of strings")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> std::result::Result<Vec<String>, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+fn title_template<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    deserializer
+        .deserialize_str(Checked(check_title_template))
+        .map(Some)
+}
+
+fn twitter_handle<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    deserializer
+        .deserialize_str(Checked(check_twitter_handle))
+        .map(Some)
+}
+
+fn locale<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    deserializer
+        .deserialize_str(Checked(check_locale))
+        .map(Some)
+}
+
+fn locales<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<String>, D::Error> {
+    deserializer.deserialize_seq(CheckedList(Checked(check_locale)))
+}
+
+fn check_title_template(template: &str) -> std::result::Result<(), String> {
+    if template.contains(TITLE_PLACEHOLDER) {
+        Ok(())
+    } else {
+        Err(format!(
+            "title template \"{}\" has no `{}` placeholder",
+            template, TITLE_PLACEHOLDER
+        ))
+    }
+}
+
+fn check_twitter_handle(handle: &str) -> std::result::Result<(), String> {
+    let valid = handle.strip_prefix('@').is_some_and(|name| {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "\"{}\" is not a Twitter handle such as `@acme`",
+            handle
+        ))
+    }
+}
+
+/// Check the shape of a BCP 47 tag such as `en` or `pt-BR`
+fn check_locale(locale: &str) -> std::result::Result<(), String> {
+    let mut subtags = locale.split('-');
+    let language = subtags.next().unwrap_or_default();
+    let valid = (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_alphabetic())
+        && subtags.all(|subtag| {
+            (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "\"{}\" is not a locale such as `en` or `pt-BR`",
+            locale
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_column() {
+        let source = "a = 1\nbé = 2\n";
+        assert_eq!(line_column(source, 0), (1, 1));
+        assert_eq!(line_column(source, 6), (2, 1));
+        assert_eq!(line_column(source, source.find('=').unwrap()), (1, 3));
+        assert_eq!(line_column(source, source.rfind('=').unwrap()), (2, 4));
+    }
+
+    #[test]
+    fn test_check_locale() {
+        for valid in ["en", "pt-BR", "zh-Hant-TW", "es-419"] {
+            assert!(check_locale(valid).is_ok(), "{}", valid);
+        }
+        for invalid in ["", "english", "en_US", "de-", "e"] {
+            assert!(check_locale(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 //! Utility functions and helpers for leptos-next-metadata
 
+pub mod url;
+
 /// Common utility functions for metadata operations
 pub mod common {
 
@@ -84,20 +86,11 @@ pub mod seo {
 pub mod cache {
 
     /// Generate a cache key from metadata
+    ///
+    /// The key is the [`Metadata::fingerprint`](crate::metadata::Metadata::fingerprint),
+    /// so it covers every field and is stable across processes.
     pub fn generate_cache_key(metadata: &crate::metadata::Metadata) -> String {
-        // For now, use a simple string-based cache key
-        // In a full implementation, this could use a more sophisticated approach
-        let title = metadata
-            .title
-            .as_ref()
-            .map(|t| match t {
-                crate::metadata::Title::Static(s) => s.as_str(),
-                crate::metadata::Title::Template { default, .. } => default.as_str(),
-            })
-            .unwrap_or("no-title");
-
-        let description = metadata.description.as_deref().unwrap_or("no-description");
-        format!("{}-{}", title, description)
+        metadata.fingerprint()
     }
 }
 
@@ -213,30 +206,42 @@ mod tests {
     }
 
     mod cache {
-        use crate::metadata::{Metadata, Title};
+        use crate::metadata::{Metadata, OgImage, OpenGraph, Title};
 
         #[test]
         fn test_generate_cache_key() {
             let metadata = Metadata::with_title("Test Title").description("Test Description");
 
             let cache_key = crate::utils::cache::generate_cache_key(&metadata);
-            assert_eq!(cache_key, "Test Title-Test Description");
+            assert_eq!(cache_key, metadata.fingerprint());
+            assert_eq!(
+                cache_key,
+                crate::utils::cache::generate_cache_key(&metadata.clone())
+            );
         }
 
         #[test]
-        fn test_generate_cache_key_no_title() {
-            let metadata = Metadata::default().description("Test Description");
+        fn test_generate_cache_key_og_image() {
+            let page = |image: &str| {
+                Metadata::with_title("Test Title")
+                    .description("Test Description")
+                    .open_graph(OpenGraph::default().image(OgImage::new(image)))
+            };
 
-            let cache_key = crate::utils::cache::generate_cache_key(&metadata);
-            assert_eq!(cache_key, "no-title-Test Description");
+            assert_ne!(
+                crate::utils::cache::generate_cache_key(&page("/a.png")),
+                crate::utils::cache::generate_cache_key(&page("/b.png"))
+            );
         }
 
         #[test]
         fn test_generate_cache_key_no_description() {
             let metadata = Metadata::with_title("Test Title");
 
-            let cache_key = crate::utils::cache::generate_cache_key(&metadata);
-            assert_eq!(cache_key, "Test Title-no-description");
+            assert_ne!(
+                crate::utils::cache::generate_cache_key(&metadata),
+                crate::utils::cache::generate_cache_key(&metadata.clone().description(""))
+            );
         }
 
         #[test]
@@ -251,7 +256,12 @@ mod tests {
             };
 
             let cache_key = crate::utils::cache::generate_cache_key(&metadata);
-            assert_eq!(cache_key, "Default Title-Test Description");
+            assert_ne!(
+                cache_key,
+                crate::utils::cache::generate_cache_key(
+                    &Metadata::with_title("Default Title").description("Test Description")
+                )
+            );
         }
     }
 }
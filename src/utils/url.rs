@@ -34,9 +34,7 @@ pub fn is_absolute_url(url: &str) -> bool {
 
 /// Extract domain from URL
 pub fn extract_domain(url: &str) -> Result<String> {
-    let url = url::Url::parse(url).map_err(|e| {
-        crate::Error::UrlError(url::ParseError::from(e))
-    })?;
+    let url = url::Url::parse(url)?;
 
     url.host_str()
         .map(|host| host.to_string())
@@ -64,19 +62,17 @@ pub fn build_url_with_params(base: &str, params: &[(&str, &str)]) -> String {
 
 /// Normalize URL by removing trailing slashes and fragments
 pub fn normalize_url(url: &str) -> Result<String> {
-    let mut parsed = url::Url::parse(url).map_err(|e| {
-        crate::Error::UrlError(url::ParseError::from(e))
-    })?;
+    let mut parsed = url::Url::parse(url)?;
 
     // Remove fragment
     parsed.set_fragment(None);
 
     // Normalize path
-    let path = parsed.path().trim_end_matches('/');
+    let path = parsed.path().trim_end_matches('/').to_string();
     if path.is_empty() {
         parsed.set_path("/");
     } else {
-        parsed.set_path(path);
+        parsed.set_path(&path);
     }
 
     Ok(parsed.to_string())
@@ -0,0 +1,83 @@
+//! Tests for canonical and alternate links
+
+use leptos_next_metadata::metadata::{Alternates, Metadata, ValidationUtils};
+
+#[test]
+fn test_language_tags() {
+    let valid = [
+        "en",
+        "en-US",
+        "es-419",
+        "zh-Hant-TW",
+        "sr-Latn-RS",
+        "de-CH-1901",
+        "zh-yue-HK",
+        "en-US-u-ca-gregory",
+        "x-klingon",
+        "en-x-private",
+    ];
+    for tag in valid {
+        assert!(ValidationUtils::is_valid_language_tag(tag), "{}", tag);
+    }
+
+    let invalid = [
+        "",
+        "e",
+        "english-",
+        "en_US",
+        "en-USA-",
+        "en-u",
+        "123",
+        "en-US-x",
+    ];
+    for tag in invalid {
+        assert!(!ValidationUtils::is_valid_language_tag(tag), "{}", tag);
+    }
+}
+
+#[test]
+fn test_locales_from_pattern() {
+    let alternates = Alternates::locales("/{locale}/blog/{locale}-feed", ["en", "fr"]);
+
+    assert_eq!(alternates.languages["en"], "/en/blog/en-feed");
+    assert_eq!(alternates.languages["fr"], "/fr/blog/fr-feed");
+    assert!(!alternates.is_empty());
+    assert!(Alternates::default().is_empty());
+}
+
+#[test]
+fn test_validate_alternates() {
+    let metadata = Metadata::default()
+        .metadata_base("https://acme.com".parse().unwrap())
+        .canonical("/legacy")
+        .alternates(
+            Alternates::locales("/{locale}", ["en-US", "en_GB"])
+                .canonical("/")
+                .feed("rss", "/feed.xml", None),
+        );
+
+    let result = metadata.validate();
+    let errors: Vec<_> = result
+        .errors
+        .iter()
+        .filter_map(|e| e.field.as_deref())
+        .collect();
+    assert_eq!(
+        errors,
+        vec!["alternates.languages.en_GB", "alternates.types.rss"]
+    );
+
+    let warnings: Vec<_> = result
+        .warnings
+        .iter()
+        .filter_map(|w| w.field.as_deref())
+        .collect();
+    assert!(warnings.contains(&"canonical"));
+    assert!(warnings.contains(&"alternates.languages"));
+
+    let metadata = Metadata::default().alternates(
+        Alternates::locales("https://acme.com/{locale}", ["en", "de"])
+            .x_default("https://acme.com/"),
+    );
+    assert!(metadata.validate().is_valid());
+}
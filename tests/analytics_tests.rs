@@ -14,6 +14,18 @@ use std::collections::HashMap;
 #[cfg(not(target_arch = "wasm32"))]
 mod native_tests {
     use super::*;
+    use std::sync::Once;
+
+    /// Run from a temp dir, so the integration's file handler does not append
+    /// to `analytics_events.jsonl` in the repository
+    fn in_temp_dir() {
+        static CHDIR: Once = Once::new();
+        CHDIR.call_once(|| {
+            let dir = std::env::temp_dir().join("leptos_next_metadata_analytics_tests");
+            std::fs::create_dir_all(&dir).unwrap();
+            std::env::set_current_dir(dir).unwrap();
+        });
+    }
 
     #[test]
     fn test_analytics_manager_creation() {
@@ -149,6 +161,7 @@ mod native_tests {
 
     #[test]
     fn test_analytics_integration() {
+        in_temp_dir();
         let analytics_config = AnalyticsConfig::default();
         let integration_config = IntegrationConfig::default();
         let mut integration =
@@ -255,6 +268,7 @@ mod native_tests {
 
     #[test]
     fn test_analytics_wrapper() {
+        in_temp_dir();
         let analytics = MetadataAnalyticsIntegration::default();
         let test_value = "test_data".to_string();
         let wrapper = test_value.with_analytics(analytics);
@@ -269,6 +283,7 @@ mod native_tests {
 
     #[test]
     fn test_analytics_context() {
+        in_temp_dir();
         let analytics = MetadataAnalyticsIntegration::default();
         let context = AnalyticsContext::new("test_operation".to_string(), analytics);
 
@@ -286,6 +301,7 @@ mod native_tests {
 
     #[test]
     fn test_analytics_error_tracking() {
+        in_temp_dir();
         let analytics = MetadataAnalyticsIntegration::default();
         let context = AnalyticsContext::new("test_operation".to_string(), analytics);
 
@@ -0,0 +1,112 @@
+//! Tests for verification, Apple web app, iTunes and App Links metadata
+
+use leptos_next_metadata::metadata::{
+    AppLinks, AppLinksAndroid, AppLinksApple, AppleStatusBarStyle, AppleWebApp, Itunes, Metadata,
+    Verification,
+};
+
+#[test]
+fn test_deserialize_next_style_config() {
+    let metadata: Metadata = serde_json::from_value(serde_json::json!({
+        "verification": {
+            "google": "google-token",
+            "yandex": ["yandex-1", "yandex-2"],
+            "other": { "me": ["my-email", "my-link"] }
+        },
+        "apple_web_app": {
+            "capable": true,
+            "title": "Acme",
+            "status_bar_style": "black-translucent",
+            "startup_image": { "url": "/startup.png" }
+        },
+        "itunes": { "app_id": "123456789" },
+        "app_links": {
+            "ios": { "url": "acme://ios", "app_store_id": "123456789" },
+            "web": [{ "url": "https://acme.com", "should_fallback": true }]
+        },
+        "alternate_links": [],
+        "additional": {}
+    }))
+    .unwrap();
+
+    let verification = metadata.verification.unwrap();
+    assert_eq!(verification.google, vec!["google-token"]);
+    assert_eq!(verification.yandex.len(), 2);
+    assert_eq!(verification.other["me"].len(), 2);
+
+    let apple_web_app = metadata.apple_web_app.unwrap();
+    assert_eq!(
+        apple_web_app.status_bar_style,
+        Some(AppleStatusBarStyle::BlackTranslucent)
+    );
+    assert_eq!(apple_web_app.startup_image[0].url, "/startup.png");
+
+    assert_eq!(metadata.itunes.unwrap().app_id, "123456789");
+
+    let app_links = metadata.app_links.unwrap();
+    assert_eq!(app_links.ios[0].url, "acme://ios");
+    assert_eq!(app_links.web[0].should_fallback, Some(true));
+}
+
+#[test]
+fn test_validate_app_metadata() {
+    let metadata = Metadata::default()
+        .verification(Verification::default().google(" "))
+        .itunes(Itunes::new("id123"))
+        .apple_web_app(AppleWebApp {
+            status_bar_style: Some(AppleStatusBarStyle::Black),
+            ..Default::default()
+        })
+        .app_links(AppLinks {
+            ios: vec![AppLinksApple {
+                url: "not a url".into(),
+                ..Default::default()
+            }],
+            android: vec![AppLinksAndroid {
+                package: "acme".into(),
+                url: Some("acme://android".into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+    let result = metadata.validate();
+    let fields: Vec<_> = result
+        .errors
+        .iter()
+        .filter_map(|e| e.field.as_deref())
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "verification.google[0]",
+            "itunes.appId",
+            "appLinks.ios[0].url",
+            "appLinks.android[0].package",
+        ]
+    );
+    assert!(result
+        .warnings
+        .iter()
+        .any(|w| w.field.as_deref() == Some("appleWebApp.statusBarStyle")));
+}
+
+#[test]
+fn test_valid_app_metadata() {
+    let metadata = Metadata::default()
+        .metadata_base("https://acme.com".parse().unwrap())
+        .verification(Verification::default().google("google-token"))
+        .itunes(Itunes {
+            app_argument: Some("/app".into()),
+            ..Itunes::new("123456789")
+        })
+        .app_links(AppLinks {
+            android: vec![AppLinksAndroid {
+                package: "com.acme.app".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+    assert!(metadata.validate().is_valid());
+}
@@ -0,0 +1,166 @@
+//! Tests for structural diffs between metadata values
+
+use leptos_next_metadata::metadata::diff::{Change, ChangeKind, MetadataDiff};
+use leptos_next_metadata::metadata::{
+    Alternates, Keywords, Metadata, OgImage, OpenGraph, Robots, Title, Twitter, TwitterCard,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn page() -> Metadata {
+    Metadata::with_title("Pricing")
+        .keywords(vec!["pricing".to_string(), "plans".to_string()])
+        .alternates(Alternates::default().language("de-DE", "https://acme.com/de/pricing"))
+        .open_graph(OpenGraph::default().image(OgImage::with_dimensions(
+            "https://acme.com/og.png",
+            1200,
+            630,
+        )))
+        .json_ld(json!({ "@type": "Product", "offers": { "price": "19" } }))
+}
+
+#[test]
+fn test_identical_metadata_has_no_diff() {
+    assert_eq!(page(), page());
+    assert!(page().diff(&page()).is_empty());
+    assert_eq!(page().diff(&page()).to_string(), "");
+}
+
+#[test]
+fn test_nested_changes_use_dotted_paths() {
+    let mut after = page();
+    after.title = Some(Title::Static("Plans".into()));
+    after.keywords = Some(Keywords::Multiple(vec!["plans".into()]));
+    after.alternates = Some(
+        Alternates::default()
+            .language("de-DE", "https://acme.com/de/preise")
+            .language("fr", "https://acme.com/fr/tarifs"),
+    );
+    if let Some(ref mut og) = after.open_graph {
+        og.images[0].width = Some(1080);
+        og.images
+            .push(OgImage::new("https://acme.com/og-square.png"));
+    }
+    after.json_ld = Some(json!({ "@type": "Product", "offers": { "price": "29" } }));
+
+    let diff = MetadataDiff::between(&page(), &after);
+    let paths: Vec<_> = diff.changes.iter().map(|c| c.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "alternates.languages.de-DE",
+            "alternates.languages.fr",
+            "json_ld.offers.price",
+            "keywords[0]",
+            "keywords[1]",
+            "open_graph.images[0].width",
+            "open_graph.images[1]",
+            "title",
+        ]
+    );
+
+    assert_eq!(
+        diff.changes[6].kind,
+        ChangeKind::Added {
+            value: serde_json::to_value(OgImage::new("https://acme.com/og-square.png")).unwrap()
+        }
+    );
+    assert_eq!(
+        diff.changes[4],
+        Change {
+            path: "keywords[1]".into(),
+            kind: ChangeKind::Removed {
+                value: json!("plans")
+            },
+        }
+    );
+    assert_eq!(diff.added().count(), 2);
+    assert_eq!(diff.removed().count(), 1);
+    assert_eq!(diff.changed().count(), 5);
+    assert_eq!(diff.at("open_graph").count(), 2);
+    assert_eq!(diff.at("open_graph.images[0]").count(), 1);
+    assert_eq!(diff.at("alternates.languages").count(), 2);
+}
+
+#[test]
+fn test_variant_and_option_changes() {
+    let before = Metadata::default()
+        .title(Title::Static("Home".into()))
+        .twitter(Twitter {
+            card: Some(TwitterCard::Summary),
+            ..Default::default()
+        })
+        .additional("msvalidate.01", "token");
+    let after = Metadata::default()
+        .title(Title::Absolute("Home".into()))
+        .twitter(Twitter {
+            card: Some(TwitterCard::SummaryLargeImage),
+            ..Default::default()
+        })
+        .description("Welcome");
+
+    let diff = before.diff(&after);
+    let report = diff.to_string();
+    assert!(report.contains(r#"- additional["msvalidate.01"]: "token""#));
+    assert!(report.contains(r#"+ description: "Welcome""#));
+    assert!(report.contains(r#"~ title: {"Static":"Home"} -> {"Absolute":"Home"}"#));
+    assert!(report.contains(r#"~ twitter.card: "Summary" -> "SummaryLargeImage""#));
+    assert_eq!(report.lines().count(), diff.len());
+
+    // A section missing on one side is reported once as a whole
+    let diff = Metadata::default().diff(&page());
+    assert_eq!(diff.at("open_graph").count(), 1);
+    assert_eq!(diff.at("open_graph").next().unwrap().path, "open_graph");
+}
+
+#[test]
+fn test_user_keyed_maps_are_not_variants() {
+    let before = Metadata::default()
+        .additional("X-UA-Compatible", "IE=edge")
+        .robots(Robots {
+            other: HashMap::from([("Indexifembedded".to_string(), String::new())]),
+            ..Default::default()
+        });
+
+    // A second key is reported on its own
+    let after = before.clone().additional("Referrer", "origin");
+    let report = before.diff(&after).to_string();
+    assert_eq!(report, "+ additional.Referrer: \"origin\"\n");
+
+    // A changed value keeps its key in the path
+    let after = before
+        .clone()
+        .additional("X-UA-Compatible", "IE=11")
+        .robots(Robots {
+            other: HashMap::from([("Indexifembedded".to_string(), "yes".to_string())]),
+            ..Default::default()
+        });
+    let report = before.diff(&after).to_string();
+    assert_eq!(
+        report,
+        "~ additional.X-UA-Compatible: \"IE=edge\" -> \"IE=11\"\n\
+         ~ robots.other.Indexifembedded: \"\" -> \"yes\"\n"
+    );
+}
+
+#[test]
+fn test_json_report() {
+    let before = Metadata::default().canonical("https://acme.com/a");
+    let after = Metadata::default().canonical("https://acme.com/b");
+
+    let report = before.diff(&after).to_json();
+    assert_eq!(
+        report,
+        json!({
+            "changes": [{
+                "path": "canonical",
+                "kind": "changed",
+                "from": "https://acme.com/a",
+                "to": "https://acme.com/b"
+            }]
+        })
+    );
+
+    let parsed: MetadataDiff = serde_json::from_value(report).unwrap();
+    assert_eq!(parsed, before.diff(&after));
+}
@@ -0,0 +1,77 @@
+//! Tests for stable metadata and OG image fingerprints
+
+use leptos_next_metadata::metadata::fingerprint::etag_matches;
+use leptos_next_metadata::metadata::{Alternates, Metadata, MetadataContext, OgImage, OpenGraph};
+use leptos_next_metadata::og_image::cache::CacheKey;
+use leptos_next_metadata::og_image::OgImageParams;
+
+#[test]
+fn test_metadata_fingerprint_is_stable() {
+    // Languages are a `HashMap`, so insertion order must not matter
+    let languages: Vec<(String, String)> = (0..20)
+        .map(|i| (format!("l{}", i), format!("/l{}/pricing", i)))
+        .collect();
+    let page = |languages: Vec<(String, String)>| {
+        let alternates = languages
+            .into_iter()
+            .fold(Alternates::default(), |alternates, (language, url)| {
+                alternates.language(language, url)
+            });
+        Metadata::with_title("Pricing").alternates(alternates)
+    };
+    let metadata = page(languages.clone());
+    let reversed = page(languages.into_iter().rev().collect());
+    assert_eq!(metadata.fingerprint(), reversed.fingerprint());
+    assert_ne!(
+        metadata.fingerprint(),
+        Metadata::with_title("Pricing").fingerprint()
+    );
+
+    // Pinned, so that changes to the encoding are noticed
+    assert_eq!(
+        Metadata::with_title("Pricing").fingerprint(),
+        "f4d6c1ac4696d2f1819158b58ae203e6dbb203c6c8f61a9af0fdea7fec13a878"
+    );
+}
+
+#[test]
+fn test_metadata_etag() {
+    let context = MetadataContext::new();
+    context.push_metadata(
+        Metadata::with_title("Pricing")
+            .open_graph(OpenGraph::default().image(OgImage::new("/og.png"))),
+    );
+    let etag = context.etag();
+    assert_eq!(etag, context.get_merged_metadata().etag());
+    assert!(etag.starts_with('"') && etag.ends_with('"'));
+    assert!(etag_matches(&etag, &etag));
+
+    context.push_metadata(
+        Metadata::default().open_graph(OpenGraph::default().image(OgImage::new("/og-2.png"))),
+    );
+    assert!(!etag_matches(&etag, &context.etag()));
+}
+
+#[test]
+fn test_og_image_cache_key() {
+    let mut data = liquid::Object::new();
+    data.insert("title".into(), liquid::model::Value::scalar("Pricing"));
+    data.insert("subtitle".into(), liquid::model::Value::scalar("Plans"));
+    let params = OgImageParams::new("default").data(data.clone());
+
+    assert_eq!(
+        CacheKey::new(&params).unwrap(),
+        CacheKey::new(&params.clone()).unwrap()
+    );
+    let fingerprint = params.fingerprint().unwrap();
+    assert_eq!(params.etag().unwrap(), format!("\"{}\"", fingerprint));
+
+    // Colors were not part of the key before
+    let dark = params.clone().background_color(image::Rgba([0, 0, 0, 255]));
+    assert_ne!(
+        CacheKey::new(&params).unwrap(),
+        CacheKey::new(&dark).unwrap()
+    );
+    let large = params.clone().size(1600, 900);
+    assert_ne!(fingerprint, large.fingerprint().unwrap());
+}
@@ -0,0 +1,229 @@
+//! Tests for loading metadata from Markdown front matter
+
+use leptos_next_metadata::front_matter::{
+    FrontMatterConfig, FrontMatterFormat, FrontMatterKeys, FrontMatterLoader,
+};
+use leptos_next_metadata::metadata::{Authors, Keywords, OgType, Robots, Title};
+use leptos_next_metadata::Error;
+use serde_json::json;
+
+const YAML_POST: &str = r#"---
+title: "Metadata diffs in CI"
+description: Catch SEO regressions before they ship.
+date: 2025-09-01
+updated: 2025-09-14T10:00:00Z
+tags: [seo, ci]
+authors:
+  - name: Ana Lima
+    url: https://acme.com/team/ana
+  - Ben Ortiz
+image: /og/diffs.png
+canonical: /blog/metadata-diffs
+series: tooling
+---
+
+# Metadata diffs in CI
+
+Every deploy now prints a diff.
+"#;
+
+#[test]
+fn test_yaml_front_matter() {
+    let loader = FrontMatterLoader::with_config(FrontMatterConfig {
+        metadata_base: Some("https://acme.com".parse().unwrap()),
+        ..Default::default()
+    });
+    let post = loader.parse(YAML_POST).unwrap();
+    let metadata = &post.metadata;
+
+    assert_eq!(post.format, Some(FrontMatterFormat::Yaml));
+    assert_eq!(post.front_matter["series"], "tooling");
+    assert!(post.body.starts_with("# Metadata diffs in CI"));
+    assert!(!post.draft);
+
+    assert_eq!(
+        metadata.title,
+        Some(Title::Static("Metadata diffs in CI".into()))
+    );
+    assert_eq!(
+        metadata.description.as_deref(),
+        Some("Catch SEO regressions before they ship.")
+    );
+    assert_eq!(
+        metadata.keywords,
+        Some(Keywords::Multiple(vec!["seo".into(), "ci".into()]))
+    );
+    assert_eq!(
+        metadata.canonical.as_deref(),
+        Some("https://acme.com/blog/metadata-diffs")
+    );
+    match metadata.authors {
+        Some(Authors::Multiple(ref authors)) => {
+            assert_eq!(authors[0].url.as_deref(), Some("https://acme.com/team/ana"));
+            assert_eq!(authors[1].name, "Ben Ortiz");
+        }
+        ref other => panic!("expected two authors, got {:?}", other),
+    }
+    assert!(metadata.robots.is_none());
+
+    let open_graph = metadata.open_graph.as_ref().unwrap();
+    assert_eq!(open_graph.r#type, Some(OgType::Article));
+    assert_eq!(open_graph.images[0].url, "https://acme.com/og/diffs.png");
+    let article = open_graph.article.as_ref().unwrap();
+    assert_eq!(article.published_time.as_deref(), Some("2025-09-01"));
+    assert_eq!(
+        article.modified_time.as_deref(),
+        Some("2025-09-14T10:00:00Z")
+    );
+    assert_eq!(article.author.as_deref(), Some("https://acme.com/team/ana"));
+    assert_eq!(article.tag, Some(vec!["seo".into(), "ci".into()]));
+
+    assert_eq!(
+        metadata.json_ld,
+        Some(json!({
+            "@context": "https://schema.org",
+            "@type": "BlogPosting",
+            "headline": "Metadata diffs in CI",
+            "description": "Catch SEO regressions before they ship.",
+            "author": [
+                { "@type": "Person", "name": "Ana Lima", "url": "https://acme.com/team/ana" },
+                { "@type": "Person", "name": "Ben Ortiz" }
+            ],
+            "datePublished": "2025-09-01",
+            "dateModified": "2025-09-14T10:00:00Z",
+            "image": "https://acme.com/og/diffs.png",
+            "url": "https://acme.com/blog/metadata-diffs",
+            "wordCount": 10,
+            "keywords": "seo, ci"
+        }))
+    );
+}
+
+#[test]
+fn test_json_ld_urls_match_open_graph_under_base_path() {
+    let loader = FrontMatterLoader::with_config(FrontMatterConfig {
+        metadata_base: Some("https://acme.com/blog".parse().unwrap()),
+        ..Default::default()
+    });
+    let post = loader
+        .parse("---\ntitle: Cover\nimage: /cover.png\ncanonical: /cover\n---\nBody")
+        .unwrap();
+    let metadata = &post.metadata;
+
+    let og_image = &metadata.open_graph.as_ref().unwrap().images[0].url;
+    assert_eq!(og_image, "https://acme.com/blog/cover.png");
+    assert_eq!(
+        metadata.canonical.as_deref(),
+        Some("https://acme.com/blog/cover")
+    );
+
+    let json_ld = metadata.json_ld.as_ref().unwrap();
+    assert_eq!(json_ld["image"], og_image.as_str());
+    assert_eq!(json_ld["url"], "https://acme.com/blog/cover");
+    assert_eq!(
+        post.blog_posting.url.as_deref(),
+        metadata.canonical.as_deref()
+    );
+}
+
+#[test]
+fn test_toml_front_matter_with_custom_keys() {
+    let loader = FrontMatterLoader::with_config(FrontMatterConfig {
+        keys: FrontMatterKeys {
+            description: "summary".into(),
+            date: "published".into(),
+            tags: "categories".into(),
+            authors: "author".into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let post = loader
+        .parse(
+            "+++\n\
+             title = \"Release 2.0\"\n\
+             summary = \"What is new\"\n\
+             published = 2025-10-01T08:30:00Z\n\
+             categories = \"release, changelog\"\n\
+             author = \"Ana Lima\"\n\
+             draft = true\n\
+             +++\n\
+             Body text.\n",
+        )
+        .unwrap();
+
+    assert_eq!(post.format, Some(FrontMatterFormat::Toml));
+    assert!(post.draft);
+    assert_eq!(post.metadata.robots, Some(Robots::none()));
+    assert_eq!(post.metadata.description.as_deref(), Some("What is new"));
+    assert_eq!(
+        post.metadata.keywords,
+        Some(Keywords::Multiple(vec![
+            "release".into(),
+            "changelog".into()
+        ]))
+    );
+    assert!(matches!(
+        post.metadata.authors,
+        Some(Authors::Single(ref author)) if author.name == "Ana Lima"
+    ));
+    assert_eq!(
+        post.blog_posting.date_published.as_deref(),
+        Some("2025-10-01T08:30:00Z")
+    );
+    assert_eq!(
+        post.blog_posting.author,
+        Some(json!({ "@type": "Person", "name": "Ana Lima" }))
+    );
+}
+
+#[test]
+fn test_body_fallbacks() {
+    let post = FrontMatterLoader::new()
+        .parse(
+            "---\ntitle: Notes\n---\n\
+             ## Intro\n\n\
+             > Short notes on [Leptos](https://leptos.dev) and *streaming* SSR.\n\n\
+             ```rust\nfn not_counted() {}\n```\n",
+        )
+        .unwrap();
+    assert_eq!(
+        post.metadata.description.as_deref(),
+        Some("Short notes on Leptos and streaming SSR.")
+    );
+    assert_eq!(post.word_count, 8);
+
+    let loader = FrontMatterLoader::with_config(FrontMatterConfig {
+        description_from_body: false,
+        ..Default::default()
+    });
+    let post = loader
+        .parse("Plain Markdown without front matter.")
+        .unwrap();
+    assert_eq!(post.format, None);
+    assert!(post.front_matter.is_empty());
+    assert_eq!(post.metadata.description, None);
+    assert_eq!(post.word_count, 5);
+}
+
+#[test]
+fn test_invalid_front_matter() {
+    let loader = FrontMatterLoader::new();
+
+    match loader.parse("---\ndescription: Fine\ntitle: [unclosed\n---\nBody") {
+        Err(Error::SerializationError(message)) => {
+            assert!(
+                message.starts_with("Invalid YAML front matter at line 4 column 1: "),
+                "{}",
+                message
+            );
+            // The location is reported once, relative to the document
+            assert!(!message.contains("line 3 column 1"), "{}", message);
+        }
+        other => panic!("expected a YAML error, got {:?}", other),
+    }
+    assert!(loader.parse("+++\ntitle = \n+++\n").is_err());
+    assert!(loader.parse("---\n- a list\n---\n").is_err());
+    assert!(loader.parse("---\nauthors: [{url: x}]\n---\n").is_err());
+    assert!(loader.parse("---\ntitle: Unclosed\n").is_err());
+}
@@ -0,0 +1,137 @@
+//! Tests for async metadata generators with parent metadata
+
+use futures::executor::block_on;
+use leptos_next_metadata::metadata::generator::{
+    MetadataGenerator, MetadataRequest, ParentMetadata,
+};
+use leptos_next_metadata::metadata::registry::MetadataRegistry;
+use leptos_next_metadata::metadata::{Metadata, MetadataContext, OgImage, OpenGraph, Title};
+use leptos_next_metadata::MetadataConfig;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Appends the product image to the images of the parent levels
+struct ProductMetadata;
+
+#[async_trait::async_trait]
+impl MetadataGenerator for ProductMetadata {
+    async fn generate(&self, request: MetadataRequest, parent: ParentMetadata) -> Metadata {
+        let parent = parent.await;
+        let mut images = parent.open_graph.map(|og| og.images).unwrap_or_default();
+        images.push(OgImage::new(&format!(
+            "/products/{}.png",
+            request.params["id"]
+        )));
+
+        let title = match request.locale.as_deref() {
+            Some(locale) if locale.starts_with("de") => "Produkt",
+            _ => "Product",
+        };
+        Metadata::with_title(format!("{} {}", title, request.params["id"])).open_graph(OpenGraph {
+            images,
+            ..Default::default()
+        })
+    }
+}
+
+fn shop() -> MetadataRegistry {
+    MetadataRegistry::new()
+        .layout(
+            "/",
+            Metadata::default()
+                .title(Title::Template {
+                    template: "%s | Shop".into(),
+                    default: "Shop".into(),
+                })
+                .open_graph(OpenGraph::default().image(OgImage::new("/og/shop.png"))),
+        )
+        .layout_generator("/products", |_request, parent: ParentMetadata| async move {
+            // Sees the resolved root title
+            let parent = parent.await;
+            let title = parent
+                .title
+                .map(|title| title.resolve(None))
+                .unwrap_or_default();
+            Metadata::default().description(format!("Products from {}", title))
+        })
+        .page_generator("/products/:id", ProductMetadata)
+}
+
+#[test]
+fn test_generator_builds_on_parent() {
+    let request = MetadataRequest::new("/products/42").header("Accept-Language", "de-AT, en;q=0.5");
+    let layers = block_on(shop().resolve_request(request, Vec::new()));
+    assert_eq!(layers.len(), 3);
+    assert_eq!(layers[1].description.as_deref(), Some("Products from Shop"));
+
+    let product = &layers[2];
+    assert_eq!(product.title, Some(Title::Static("Produkt 42".into())));
+    let images: Vec<_> = product
+        .open_graph
+        .as_ref()
+        .unwrap()
+        .images
+        .iter()
+        .map(|image| image.url.as_str())
+        .collect();
+    assert_eq!(images, ["/og/shop.png", "/products/42.png"]);
+
+    let merged = block_on(shop().resolve("/products/7"));
+    assert_eq!(merged.title, Some(Title::Static("Product 7 | Shop".into())));
+    assert_eq!(merged.description.as_deref(), Some("Products from Shop"));
+}
+
+#[test]
+fn test_parent_includes_context_defaults() {
+    let context = MetadataContext::with_config(MetadataConfig {
+        metadata_base: Some("https://shop.example".parse().unwrap()),
+        ..Default::default()
+    })
+    .with_registry(MetadataRegistry::new().page_generator(
+        "/",
+        |_request, parent: ParentMetadata| async move {
+            let base = parent
+                .await
+                .metadata_base
+                .map(String::from)
+                .unwrap_or_default();
+            Metadata::with_title(base)
+        },
+    ));
+
+    block_on(context.load_request(MetadataRequest::new("/").locale("en")));
+    assert_eq!(
+        context.get_merged_metadata().title,
+        Some(Title::Static("https://shop.example/".into()))
+    );
+}
+
+#[test]
+fn test_parent_levels_resolve_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let registry = MetadataRegistry::new()
+        .layout_with("/", move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Metadata::with_title("Root") }
+        })
+        .layout_generator("/a", |_request, parent: ParentMetadata| async move {
+            // Awaiting a clone twice does not re-run the root
+            let _ = parent.clone().await;
+            parent.await;
+            Metadata::default()
+        })
+        .page_generator("/a/b", |_request, parent: ParentMetadata| async move {
+            Metadata::default().description(
+                parent
+                    .await
+                    .title
+                    .map(|title| title.resolve(None))
+                    .unwrap_or_default(),
+            )
+        });
+
+    let layers = block_on(registry.resolve_layers("/a/b"));
+    assert_eq!(layers[2].description.as_deref(), Some("Root"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,169 @@
+//! Tests for localized metadata bundles
+
+use futures::executor::block_on;
+use leptos_next_metadata::metadata::context::HEAD_MARKER;
+use leptos_next_metadata::metadata::generator::MetadataRequest;
+use leptos_next_metadata::metadata::i18n::LocalizedMetadata;
+use leptos_next_metadata::metadata::registry::MetadataRegistry;
+use leptos_next_metadata::metadata::{
+    Alternates, Metadata, MetadataContext, OgImage, OpenGraph, Title,
+};
+use std::collections::HashMap;
+
+fn pricing() -> LocalizedMetadata {
+    LocalizedMetadata::new("en")
+        .common(
+            Metadata::default()
+                .open_graph(OpenGraph::default().image(OgImage::new("/og/pricing.png"))),
+        )
+        .locale(
+            "en",
+            Metadata::with_title("Pricing")
+                .description("Plans for teams")
+                .open_graph(OpenGraph {
+                    title: Some("Acme pricing".into()),
+                    ..Default::default()
+                }),
+        )
+        .locale("de", Metadata::with_title("Preise").description("Tarife"))
+        .locale(
+            "de_at",
+            Metadata::default().description("Tarife für Österreich"),
+        )
+        .locale("fr", Metadata::with_title("Tarifs"))
+        .alternate_urls("/{locale}/pricing")
+}
+
+#[test]
+fn test_resolve_falls_back_per_field() {
+    let metadata = pricing().resolve("de-AT");
+    assert_eq!(metadata.title, Some(Title::Static("Preise".into())));
+    assert_eq!(
+        metadata.description.as_deref(),
+        Some("Tarife für Österreich")
+    );
+    assert_eq!(metadata.lang.as_deref(), Some("de-AT"));
+
+    let og = metadata.open_graph.unwrap();
+    // Nested fields fall back too, and shared fields are kept
+    assert_eq!(og.title.as_deref(), Some("Acme pricing"));
+    assert_eq!(og.images[0].url, "/og/pricing.png");
+    assert_eq!(og.locale.as_deref(), Some("de_AT"));
+    assert_eq!(og.alternate_locale, ["de", "en", "fr"]);
+
+    let french = pricing().resolve("fr-CA");
+    assert_eq!(french.lang.as_deref(), Some("fr"));
+    assert_eq!(french.description.as_deref(), Some("Plans for teams"));
+
+    let unknown = pricing().resolve("ja");
+    assert_eq!(unknown.lang.as_deref(), Some("en"));
+    assert_eq!(unknown.title, Some(Title::Static("Pricing".into())));
+}
+
+#[test]
+fn test_hreflang_alternates() {
+    let metadata = pricing().resolve("fr");
+    let languages = &metadata.alternates.as_ref().unwrap().languages;
+    assert_eq!(languages.len(), 5);
+    assert_eq!(languages["de-AT"], "/de-AT/pricing");
+    assert_eq!(languages[Alternates::X_DEFAULT], "/en/pricing");
+
+    let html = metadata.to_head_html();
+    assert!(html.contains(r#"<meta property="og:locale" content="fr">"#));
+    assert!(html.contains(r#"<meta property="og:locale:alternate" content="de_AT">"#));
+    assert!(html.contains(r#"<link rel="alternate" hreflang="de" href="/de/pricing">"#));
+
+    // Alternate locales survive a round trip through HTML
+    let parsed = Metadata::from_html(&html);
+    assert_eq!(
+        parsed.open_graph.unwrap().alternate_locale,
+        ["de", "de_AT", "en"]
+    );
+}
+
+#[test]
+fn test_messages_from_catalog() {
+    let mut catalog: HashMap<String, HashMap<String, String>> = HashMap::new();
+    catalog.entry("en".into()).or_default().extend([
+        ("pricing-title".to_string(), "Pricing".to_string()),
+        ("pricing-og-title".to_string(), "Acme pricing".to_string()),
+    ]);
+    catalog
+        .entry("de".into())
+        .or_default()
+        .insert("pricing-title".into(), "Preise".into());
+
+    let bundle = LocalizedMetadata::new("en")
+        .locale("de", Metadata::default().description("Tarife"))
+        .messages(catalog, "pricing");
+    let metadata = bundle.resolve("de-DE");
+    assert_eq!(metadata.title, Some(Title::Static("Preise".into())));
+    assert_eq!(metadata.description.as_deref(), Some("Tarife"));
+    let og = metadata.open_graph.unwrap();
+    assert_eq!(og.title.as_deref(), Some("Acme pricing"));
+    assert_eq!(og.alternate_locale, ["en"]);
+
+    // Explicit metadata wins over catalog messages
+    let bundle = LocalizedMetadata::new("en")
+        .locale("en", Metadata::with_title("Plans"))
+        .messages(
+            |_locale: &str, id: &str| (id == "title").then(|| "Pricing".to_string()),
+            "",
+        );
+    assert_eq!(
+        bundle.resolve("en").title,
+        Some(Title::Static("Plans".into()))
+    );
+}
+
+#[test]
+fn test_bundle_from_catalog_only() {
+    let catalog: HashMap<String, HashMap<String, String>> = HashMap::from([
+        (
+            "en".to_string(),
+            HashMap::from([("title".to_string(), "Pricing".to_string())]),
+        ),
+        (
+            "de".to_string(),
+            HashMap::from([("title".to_string(), "Preise".to_string())]),
+        ),
+    ]);
+    let bundle = LocalizedMetadata::new("en")
+        .messages(catalog, "")
+        .serve_locales(["en", "de"])
+        .alternate_urls("/{locale}/pricing");
+
+    assert_eq!(bundle.locales(), ["de", "en"]);
+    assert_eq!(bundle.negotiate("de-AT"), "de");
+
+    let metadata = bundle.resolve("de");
+    assert_eq!(metadata.title, Some(Title::Static("Preise".into())));
+    assert_eq!(metadata.lang.as_deref(), Some("de"));
+    assert_eq!(metadata.open_graph.unwrap().alternate_locale, ["en"]);
+    let languages = &metadata.alternates.unwrap().languages;
+    assert_eq!(languages["de"], "/de/pricing");
+    assert_eq!(languages["en"], "/en/pricing");
+}
+
+#[test]
+fn test_html_lang_is_injected() {
+    let context = MetadataContext::new()
+        .with_registry(MetadataRegistry::new().page_generator("/:locale/pricing", pricing()));
+    block_on(context.load_request(MetadataRequest::new("/de/pricing")));
+
+    let html = format!(
+        "<!DOCTYPE html><html lang=\"en\"><head>{}</head><body></body></html>",
+        HEAD_MARKER
+    );
+    let html = context.inject_head(&html);
+    assert!(html.starts_with("<!DOCTYPE html><html lang=\"de\"><head><title>Preise</title>"));
+    assert_eq!(Metadata::from_html(&html).lang.as_deref(), Some("de"));
+
+    // Without a route parameter the request locale is used
+    let context = MetadataContext::new()
+        .with_registry(MetadataRegistry::new().page_generator("/pricing", pricing()));
+    block_on(context.load_request(
+        MetadataRequest::new("/pricing").header("Accept-Language", "de-AT, de;q=0.8"),
+    ));
+    assert_eq!(context.get_merged_metadata().lang.as_deref(), Some("de-AT"));
+}
@@ -0,0 +1,223 @@
+//! Tests for the Next.js metadata JSON shape
+
+use leptos_next_metadata::metadata::{
+    AdditionalValue, Authors, Keywords, MaxImagePreview, Metadata, OgType, Title, TwitterCard,
+    ViewportWidth,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+const NEXTJS_METADATA: &str = r##"{
+    "metadataBase": "https://acme.com",
+    "title": { "template": "%s | Acme", "default": "Acme" },
+    "description": "Tools for teams",
+    "applicationName": "Acme",
+    "generator": "Next.js",
+    "keywords": "tools",
+    "authors": [{ "name": "Ana", "url": "https://acme.com/ana" }, { "name": "Ben" }],
+    "robots": {
+        "index": false,
+        "follow": true,
+        "nocache": true,
+        "googleBot": {
+            "index": true,
+            "max-video-preview": -1,
+            "max-image-preview": "large",
+            "max-snippet": -1
+        }
+    },
+    "alternates": {
+        "canonical": "/",
+        "languages": { "en-US": "/en-US", "de-DE": [{ "url": "/de-DE", "title": "Deutsch" }] },
+        "types": { "application/rss+xml": "/rss" }
+    },
+    "openGraph": {
+        "title": "Acme",
+        "url": "https://acme.com",
+        "siteName": "Acme",
+        "type": "article",
+        "publishedTime": "2024-01-01T00:00:00Z",
+        "authors": ["Ana", "Ben"],
+        "tags": "launch",
+        "images": [
+            "/og.png",
+            { "url": "/og-alt.png", "width": 800, "height": "600", "alt": "Acme" }
+        ]
+    },
+    "twitter": {
+        "card": "summary_large_image",
+        "site": "@acme",
+        "images": { "url": "/twitter.png", "alt": "Acme" },
+        "app": { "name": "Acme", "id": { "iphone": 123456, "googleplay": "com.acme" } }
+    },
+    "viewport": "width=device-width, initial-scale=1",
+    "themeColor": [{ "color": "#fff", "media": "(prefers-color-scheme: light)" }],
+    "icons": { "icon": "/icon.png", "apple": [{ "url": "/apple.png", "sizes": "180x180" }] },
+    "verification": { "google": "token", "other": { "me": ["a@acme.com", 42] } },
+    "appleWebApp": true,
+    "itunes": { "appId": "123" },
+    "other": { "msvalidate.01": "bing-token" }
+}"##;
+
+#[test]
+fn test_from_nextjs_json() {
+    let metadata = Metadata::from_nextjs_json(NEXTJS_METADATA).unwrap();
+
+    assert_eq!(
+        metadata.metadata_base.unwrap().as_str(),
+        "https://acme.com/"
+    );
+    assert_eq!(
+        metadata.title,
+        Some(Title::Template {
+            template: "%s | Acme".into(),
+            default: "Acme".into()
+        })
+    );
+    assert_eq!(metadata.keywords, Some(Keywords::Single("tools".into())));
+    match metadata.authors {
+        Some(Authors::Multiple(ref authors)) => {
+            assert_eq!(authors[0].url.as_deref(), Some("https://acme.com/ana"));
+            assert_eq!(authors[1].name, "Ben");
+        }
+        ref authors => panic!("unexpected authors: {:?}", authors),
+    }
+    assert_eq!(
+        metadata.additional["application-name"],
+        AdditionalValue::String("Acme".into())
+    );
+    assert_eq!(
+        metadata.additional["msvalidate.01"],
+        AdditionalValue::String("bing-token".into())
+    );
+
+    let robots = metadata.robots.as_ref().unwrap();
+    assert_eq!(robots.index, Some(false));
+    assert_eq!(robots.directives.nocache, Some(true));
+    let google_bot = robots.google_bot.as_ref().unwrap();
+    assert_eq!(google_bot.index, Some(true));
+    assert_eq!(google_bot.directives.max_video_preview, Some(-1));
+    assert_eq!(
+        google_bot.directives.max_image_preview,
+        Some(MaxImagePreview::Large)
+    );
+
+    let alternates = metadata.alternates.as_ref().unwrap();
+    assert_eq!(alternates.canonical.as_deref(), Some("/"));
+    assert_eq!(alternates.languages["de-DE"], "/de-DE");
+    assert_eq!(alternates.types["application/rss+xml"][0].url, "/rss");
+
+    let og = metadata.open_graph.as_ref().unwrap();
+    assert_eq!(og.r#type, Some(OgType::Article));
+    assert_eq!(og.images[0].url, "/og.png");
+    assert_eq!(og.images[1].width, Some(800));
+    assert_eq!(og.images[1].height, Some(600));
+    let article = og.article.as_ref().unwrap();
+    assert_eq!(article.author.as_deref(), Some("Ana"));
+    assert_eq!(article.tag, Some(vec!["launch".to_string()]));
+
+    let twitter = metadata.twitter.as_ref().unwrap();
+    assert_eq!(twitter.card, Some(TwitterCard::SummaryLargeImage));
+    assert_eq!(twitter.image.as_deref(), Some("/twitter.png"));
+    assert_eq!(twitter.image_alt.as_deref(), Some("Acme"));
+    assert_eq!(twitter.app_id_iphone.as_deref(), Some("123456"));
+    assert_eq!(twitter.app_name_googleplay.as_deref(), Some("Acme"));
+    assert_eq!(twitter.app_name_ipad, None);
+
+    assert_eq!(
+        metadata.viewport.unwrap().width,
+        Some(ViewportWidth::DeviceWidth)
+    );
+    assert_eq!(
+        metadata.theme_color.unwrap().media.as_deref(),
+        Some("(prefers-color-scheme: light)")
+    );
+    let icons = metadata.icons.unwrap();
+    assert_eq!(icons.icon[0].url, "/icon.png");
+    assert_eq!(icons.apple[0].sizes.as_deref(), Some("180x180"));
+    let verification = metadata.verification.unwrap();
+    assert_eq!(verification.google, vec!["token"]);
+    assert_eq!(verification.other["me"], vec!["a@acme.com", "42"]);
+    assert_eq!(metadata.apple_web_app.unwrap().capable, Some(true));
+    assert_eq!(metadata.itunes.unwrap().app_id, "123");
+}
+
+#[test]
+fn test_to_nextjs_json() {
+    let metadata = Metadata::with_title("Pricing")
+        .description("Plans")
+        .keywords(vec!["pricing".to_string(), "plans".to_string()])
+        .canonical("https://acme.com/pricing")
+        .alternate("fr", "https://acme.com/fr/pricing");
+
+    assert_eq!(
+        metadata.to_nextjs_value(),
+        json!({
+            "title": "Pricing",
+            "description": "Plans",
+            "keywords": ["pricing", "plans"],
+            "alternates": {
+                "canonical": "https://acme.com/pricing",
+                "languages": { "fr": "https://acme.com/fr/pricing" }
+            }
+        })
+    );
+
+    let absolute = Metadata::default().title(Title::Absolute("Acme".into()));
+    assert_eq!(
+        absolute.to_nextjs_value(),
+        json!({ "title": { "absolute": "Acme" } })
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let metadata = Metadata::from_nextjs_json(NEXTJS_METADATA).unwrap();
+    let json = metadata.to_nextjs_value();
+
+    assert_eq!(json["openGraph"]["siteName"], "Acme");
+    assert_eq!(json["openGraph"]["images"][0], "/og.png");
+    assert_eq!(json["openGraph"]["images"][1]["width"], 800);
+    assert_eq!(json["robots"]["googleBot"]["max-snippet"], -1);
+    assert_eq!(json["twitter"]["app"]["id"]["iphone"], "123456");
+    assert_eq!(json["applicationName"], "Acme");
+    assert_eq!(json["other"]["msvalidate.01"], "bing-token");
+
+    let reparsed = Metadata::from_nextjs_value(json).unwrap();
+    // Only the first article author has a place in `Metadata`
+    assert_eq!(reparsed, metadata);
+}
+
+#[test]
+fn test_robots_and_viewport_strings() {
+    let metadata = Metadata::from_nextjs_json(
+        r#"{ "robots": "noindex, nofollow", "viewport": { "width": 1024, "userScalable": false } }"#,
+    )
+    .unwrap();
+    let robots = metadata.robots.unwrap();
+    assert_eq!(robots.index, Some(false));
+    assert_eq!(robots.follow, Some(false));
+    let viewport = metadata.viewport.unwrap();
+    assert_eq!(viewport.width, Some(ViewportWidth::Pixels(1024)));
+    assert_eq!(viewport.user_scalable, Some(false));
+}
+
+#[test]
+fn test_serde_with() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Page {
+        path: String,
+        #[serde(with = "leptos_next_metadata::metadata::nextjs")]
+        metadata: Metadata,
+    }
+
+    let page: Page = serde_json::from_value(json!({
+        "path": "/",
+        "metadata": { "title": "Home", "openGraph": { "images": "/og.png" } }
+    }))
+    .unwrap();
+    assert_eq!(page.metadata.title, Some(Title::Static("Home".into())));
+
+    let value = serde_json::to_value(&page).unwrap();
+    assert_eq!(value["metadata"]["openGraph"]["images"], "/og.png");
+}
@@ -0,0 +1,156 @@
+//! Tests for the typed Open Graph object model
+
+use leptos_next_metadata::metadata::{
+    MetadataValidator, Music, MusicTrackRef, OgImage, OgType, OpenGraph, Product, ProductPrice,
+    ValidationErrorCode,
+};
+use leptos_next_metadata::prelude::*;
+
+#[test]
+fn test_og_type_serde_uses_og_values() {
+    for og_type in OgType::ALL {
+        let json = serde_json::to_string(&og_type).unwrap();
+        assert_eq!(json, format!("\"{}\"", og_type));
+
+        let parsed: OgType = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, og_type);
+    }
+}
+
+#[test]
+fn test_og_type_from_str() {
+    assert_eq!(
+        "music.radio_station".parse::<OgType>().unwrap(),
+        OgType::MusicRadioStation
+    );
+    assert_eq!(
+        "video.tv_show".parse::<OgType>().unwrap(),
+        OgType::VideoTvShow
+    );
+    assert!("video.series".parse::<OgType>().is_err());
+}
+
+#[test]
+fn test_open_graph_round_trip_with_namespace() {
+    let og = OpenGraph {
+        r#type: Some(OgType::MusicAlbum),
+        music: Some(Music {
+            song: Some(vec![MusicTrackRef {
+                url: "https://example.com/songs/1".into(),
+                disc: Some(1),
+                track: Some(1),
+            }]),
+            release_date: Some("2024-05-01".into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_value(&og).unwrap();
+    assert_eq!(json["type"], "music.album");
+
+    let parsed: OpenGraph = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, og);
+}
+
+#[test]
+fn test_validation_rejects_mismatched_namespace() {
+    let metadata = Metadata::with_title("Album").open_graph(OpenGraph {
+        r#type: Some(OgType::Article),
+        music: Some(Music {
+            duration: Some(180),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let result = metadata.validate();
+    assert!(result.errors.iter().any(|e| {
+        e.code == ValidationErrorCode::InvalidFormat
+            && e.field.as_deref() == Some("openGraph.music")
+    }));
+}
+
+#[test]
+fn test_validation_accepts_matching_namespace() {
+    let metadata = Metadata::with_title("Widget").open_graph(OpenGraph {
+        r#type: Some(OgType::Product),
+        product: Some(Product {
+            price: Some(ProductPrice {
+                amount: 19.99,
+                currency: "EUR".into(),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let result = metadata.validate();
+    assert!(!result.errors.iter().any(|e| e
+        .field
+        .as_deref()
+        .is_some_and(|f| f.starts_with("openGraph.product"))));
+}
+
+#[test]
+fn test_validation_rejects_invalid_product_price() {
+    let metadata = Metadata::with_title("Widget").open_graph(OpenGraph {
+        r#type: Some(OgType::Product),
+        product: Some(Product {
+            price: Some(ProductPrice {
+                amount: -1.0,
+                currency: "euro".into(),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let fields: Vec<_> = metadata
+        .validate()
+        .errors
+        .into_iter()
+        .filter_map(|e| e.field)
+        .collect();
+    assert!(fields.contains(&"openGraph.product.price.amount".to_string()));
+    assert!(fields.contains(&"openGraph.product.price.currency".to_string()));
+}
+
+#[test]
+fn test_media_deserialize_from_single_value_or_list() {
+    let og: OpenGraph = serde_json::from_value(serde_json::json!({
+        "image": { "url": "https://example.com/og.png", "width": 1200, "height": 630 },
+        "audio": [{ "url": "https://example.com/a.mp3" }, { "url": "https://example.com/b.mp3" }]
+    }))
+    .unwrap();
+
+    assert_eq!(og.images.len(), 1);
+    assert_eq!(og.images[0].width, Some(1200));
+    assert_eq!(og.audio.len(), 2);
+    assert!(og.videos.is_empty());
+}
+
+#[test]
+fn test_validate_og_image_checks_each_entry() {
+    let images = [
+        OgImage::with_dimensions("https://example.com/a.png", 1200, 630),
+        OgImage {
+            secure_url: Some("http://example.com/b.png".into()),
+            ..OgImage::with_dimensions("https://example.com/b.png", 1200, 630)
+        },
+        OgImage::new("not a url"),
+    ];
+
+    let fields: Vec<_> = MetadataValidator::validate_og_image(&images)
+        .into_iter()
+        .filter_map(|e| e.field)
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "images[1].secure_url",
+            "images[2].url",
+            "images[2].dimensions"
+        ]
+    );
+}
@@ -0,0 +1,293 @@
+//! Tests for parsing HTML documents back into metadata
+
+use leptos_next_metadata::metadata::{
+    AdditionalValue, AlternateLink, Alternates, AppLinks, AppLinksAndroid, AppLinksApple, Article,
+    Authors, BotDirectives, ColorScheme, IconDescriptor, Icons, Itunes, Metadata, Music,
+    MusicTrackRef, OgImage, OgType, OpenGraph, Product, ProductPrice, Robots, ThemeColor, Title,
+    Twitter, TwitterCard, Verification, Viewport,
+};
+
+fn rendered_metadata() -> Metadata {
+    Metadata::with_title("Pricing & plans")
+        .description(r#"Compare "Pro" and <Team>"#)
+        .keywords(vec!["pricing".to_string(), "plans".to_string()])
+        .robots(Robots::all().bot("bingbot", BotDirectives::noindex()))
+        .canonical("https://acme.com/pricing")
+        .alternates(
+            Alternates::default()
+                .language("de-DE", "https://acme.com/de/pricing")
+                .x_default("https://acme.com/pricing")
+                .media("only screen and (max-width: 600px)", "https://m.acme.com")
+                .feed(
+                    "application/rss+xml",
+                    "https://acme.com/feed.xml",
+                    Some("Blog"),
+                ),
+        )
+        .viewport(Viewport::device_width())
+        .theme_color(ThemeColor::dark("#000000"))
+        .color_scheme(ColorScheme::Dark)
+        .verification(Verification::default().google("google-token"))
+        .itunes(Itunes {
+            app_argument: Some("https://acme.com/pricing".into()),
+            ..Itunes::new("123456789")
+        })
+        .open_graph(OpenGraph {
+            title: Some("Pricing".into()),
+            r#type: Some(OgType::Article),
+            images: vec![
+                OgImage {
+                    alt: Some("Plans".into()),
+                    ..OgImage::with_dimensions("https://acme.com/og.png", 1200, 630)
+                },
+                OgImage::new("https://acme.com/og-square.png"),
+            ],
+            article: Some(Article {
+                author: Some("https://acme.com/team/ana".into()),
+                tag: Some(vec!["pricing".into(), "saas".into()]),
+                ..Default::default()
+            }),
+            product: Some(Product {
+                price: Some(ProductPrice {
+                    amount: 19.5,
+                    currency: "EUR".into(),
+                }),
+                brand: Some("Acme".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .twitter(Twitter {
+            card: Some(TwitterCard::SummaryLargeImage),
+            site: Some("@acme".into()),
+            player_width: Some(640),
+            ..Default::default()
+        })
+        .app_links(AppLinks {
+            ios: vec![AppLinksApple {
+                url: "acme://pricing".into(),
+                app_store_id: Some("123456789".into()),
+                app_name: None,
+            }],
+            android: vec![AppLinksAndroid {
+                package: "com.acme".into(),
+                url: Some("acme://pricing".into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        })
+        .icons(
+            Icons::default()
+                .icon("/favicon.ico")
+                .apple(IconDescriptor {
+                    sizes: Some("180x180".into()),
+                    ..IconDescriptor::new("/apple-touch-icon.png")
+                })
+                .other(IconDescriptor {
+                    rel: Some("mask-icon".into()),
+                    color: Some("#5bbad5".into()),
+                    ..IconDescriptor::new("/safari-pinned-tab.svg")
+                }),
+        )
+        .manifest("/manifest.webmanifest")
+}
+
+#[test]
+fn test_round_trip_rendered_head() {
+    let metadata = rendered_metadata();
+    let parsed = Metadata::from_html(&metadata.to_head_html());
+
+    assert_eq!(parsed.title, metadata.title);
+    assert_eq!(parsed.description, metadata.description);
+    assert_eq!(parsed.keywords, metadata.keywords);
+    assert_eq!(parsed.robots, metadata.robots);
+    assert_eq!(parsed.canonical, metadata.canonical);
+    assert_eq!(parsed.alternates, metadata.alternates);
+    assert_eq!(parsed.viewport, metadata.viewport);
+    assert_eq!(parsed.theme_color, metadata.theme_color);
+    assert_eq!(parsed.color_scheme, metadata.color_scheme);
+    assert_eq!(parsed.verification, metadata.verification);
+    assert_eq!(parsed.itunes, metadata.itunes);
+    assert_eq!(parsed.open_graph, metadata.open_graph);
+    assert_eq!(parsed.twitter, metadata.twitter);
+    assert_eq!(parsed.app_links, metadata.app_links);
+    assert_eq!(parsed.icons, metadata.icons);
+    assert_eq!(parsed.manifest, metadata.manifest);
+    assert!(parsed.additional.is_empty());
+
+    // Parsing is the inverse of rendering
+    assert_eq!(parsed.to_head_html(), metadata.to_head_html());
+}
+
+#[test]
+fn test_round_trip_partial_robots() {
+    // A single directive does not gain `noindex, nofollow`
+    let html = r#"<meta name="robots" content="noarchive">"#;
+    let metadata = Metadata::from_html(html);
+    let robots = metadata.robots.as_ref().unwrap();
+    assert_eq!((robots.index, robots.follow), (None, None));
+    assert_eq!(metadata.to_head_html(), format!("{}\n", html));
+
+    // A crawler override alone does not add a `robots` tag
+    let html = r#"<meta name="googlebot" content="noindex">"#;
+    let metadata = Metadata::from_html(html);
+    assert_eq!(
+        metadata.robots.as_ref().unwrap().google_bot,
+        Some(BotDirectives::noindex())
+    );
+    assert_eq!(metadata.to_head_html(), format!("{}\n", html));
+}
+
+#[test]
+fn test_parse_document_head_only() {
+    let metadata = Metadata::from_html(
+        r#"<!DOCTYPE html>
+        <html lang="en">
+        <HEAD>
+          <meta charset="utf-8">
+          <title>Docs | Acme</title>
+          <meta name="author" content="Ana"><link rel="author" href="https://acme.com/ana">
+          <meta name="author" content="Ben">
+          <!-- <meta name="description" content="commented out"> -->
+          <link rel="alternate" hreflang="fr" media="print" href="/fr/print">
+        </HEAD>
+        <body>
+          <header><title>Not the page title</title></header>
+          <meta name="description" content="In the body">
+        </body>
+        </html>"#,
+    );
+
+    assert_eq!(metadata.title, Some(Title::Static("Docs | Acme".into())));
+    assert_eq!(metadata.description, None);
+    match metadata.authors {
+        Some(Authors::Multiple(authors)) => {
+            assert_eq!(authors.len(), 2);
+            assert_eq!(authors[0].url.as_deref(), Some("https://acme.com/ana"));
+            assert_eq!(authors[1].name, "Ben");
+        }
+        other => panic!("expected two authors, got {:?}", other),
+    }
+    assert_eq!(
+        metadata.alternate_links,
+        vec![AlternateLink {
+            url: "/fr/print".into(),
+            hreflang: Some("fr".into()),
+            media: Some("print".into()),
+            type_: None,
+        }]
+    );
+    assert_eq!(
+        metadata.additional.get("charset"),
+        Some(&AdditionalValue::String("utf-8".into()))
+    );
+}
+
+#[test]
+fn test_unknown_tags_go_to_additional() {
+    let metadata = Metadata::from_html(
+        r#"<meta name="generator" content="Leptos">
+        <meta name="msvalidate.01" content="a">
+        <meta name="msvalidate.01" content="b">
+        <meta property="fb:app_id" content="42">
+        <meta name="viewport" content="width=device-width, shrink-to-fit=no">
+        <link rel="preconnect" href="https://fonts.gstatic.com">
+        <meta property="og:custom" content="value">
+        <meta name="twitter:label1" content="Price">"#,
+    );
+
+    let additional = &metadata.additional;
+    assert_eq!(
+        additional["generator"],
+        AdditionalValue::String("Leptos".into())
+    );
+    assert_eq!(
+        additional["msvalidate.01"],
+        AdditionalValue::Array(vec!["a".into(), "b".into()])
+    );
+    assert_eq!(
+        additional["fb:app_id"],
+        AdditionalValue::String("42".into())
+    );
+    assert_eq!(
+        additional["viewport"],
+        AdditionalValue::String("width=device-width, shrink-to-fit=no".into())
+    );
+    assert_eq!(
+        additional["link:preconnect"],
+        AdditionalValue::String("https://fonts.gstatic.com".into())
+    );
+    assert!(metadata.viewport.is_none());
+
+    assert_eq!(metadata.open_graph.unwrap().other["custom"], "value");
+    assert_eq!(metadata.twitter.unwrap().other["label1"], "Price");
+}
+
+#[test]
+fn test_parse_music_references() {
+    let metadata = Metadata::from_html(
+        r#"<meta property="og:type" content="music.album">
+        <meta property="music:song" content="https://acme.com/song/1">
+        <meta property="music:song:track" content="1">
+        <meta property="music:song" content="https://acme.com/song/2">
+        <meta property="music:song:disc" content="2">
+        <meta property="music:song:track" content="not a number">"#,
+    );
+
+    let open_graph = metadata.open_graph.unwrap();
+    assert_eq!(open_graph.r#type, Some(OgType::MusicAlbum));
+    assert_eq!(
+        open_graph.music,
+        Some(Music {
+            song: Some(vec![
+                MusicTrackRef {
+                    url: "https://acme.com/song/1".into(),
+                    disc: None,
+                    track: Some(1),
+                },
+                MusicTrackRef {
+                    url: "https://acme.com/song/2".into(),
+                    disc: Some(2),
+                    track: None,
+                },
+            ]),
+            ..Default::default()
+        })
+    );
+    assert_eq!(open_graph.other["music:song:track"], "not a number");
+}
+
+#[cfg(feature = "json-ld")]
+#[test]
+fn test_parse_json_ld_scripts() {
+    let metadata = Metadata::from_html(
+        r#"<script type="application/ld+json">{"@type":"Organization","name":"A < B"}</script>
+        <script>var ignored = "<title>";</script>
+        <script type="application/ld+json">{"@type":"WebSite"}</script>
+        <script type="application/ld+json">{not json}</script>"#,
+    );
+
+    assert_eq!(
+        metadata.json_ld,
+        Some(serde_json::json!([
+            { "@type": "Organization", "name": "A < B" },
+            { "@type": "WebSite" }
+        ]))
+    );
+    assert!(metadata.title.is_none());
+    assert_eq!(
+        metadata.additional["json-ld"],
+        AdditionalValue::String("{not json}".into())
+    );
+
+    let single = Metadata::from_html(
+        &Metadata::default()
+            .json_ld(serde_json::json!({ "@type": "Person" }))
+            .to_head_html(),
+    );
+    assert_eq!(
+        single.json_ld,
+        Some(serde_json::json!({ "@type": "Person" }))
+    );
+    assert!(single.additional.is_empty());
+}
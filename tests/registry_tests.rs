@@ -0,0 +1,130 @@
+//! Tests for the route-pattern metadata registry
+
+use futures::executor::block_on;
+use leptos_next_metadata::metadata::registry::{MetadataRegistry, RouteKind};
+use leptos_next_metadata::metadata::{Metadata, MetadataContext, Title};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn registry() -> MetadataRegistry {
+    MetadataRegistry::new()
+        .layout(
+            "/",
+            Metadata::default()
+                .title(Title::Template {
+                    template: "%s | Acme".into(),
+                    default: "Acme".into(),
+                })
+                .description("Tools for teams"),
+        )
+        .layout(
+            "/docs",
+            Metadata::default().title(Title::Template {
+                template: "%s | Acme Docs".into(),
+                default: "Docs".into(),
+            }),
+        )
+        .page_with("/docs/*path", |request| async move {
+            Metadata::with_title(request.params["path"].replace('/', " / "))
+        })
+        .page("/docs/changelog", Metadata::with_title("Changelog"))
+        .page_with("/blog/:slug", |request| async move {
+            let mut metadata = Metadata::with_title(request.params["slug"].clone());
+            if let Some(page) = request.query.get("page") {
+                metadata =
+                    metadata.canonical(format!("/blog/{}?page={}", request.params["slug"], page));
+            }
+            metadata
+        })
+}
+
+#[test]
+fn test_resolve_chain() {
+    let registry = registry();
+
+    let home = block_on(registry.resolve("/"));
+    assert_eq!(home.title, Some(Title::Static("Acme".into())));
+
+    // Root layout, docs layout, then the wildcard page
+    let chain: Vec<_> = registry
+        .matching("/docs/guide/setup")
+        .into_iter()
+        .map(|(pattern, kind, _)| (pattern.as_str(), kind))
+        .collect();
+    assert_eq!(
+        chain,
+        vec![
+            ("/", RouteKind::Layout),
+            ("/docs", RouteKind::Layout),
+            ("/docs/*path", RouteKind::Page),
+        ]
+    );
+    let guide = block_on(registry.resolve("/docs/guide/setup"));
+    assert_eq!(
+        guide.title,
+        Some(Title::Static("guide / setup | Acme Docs".into()))
+    );
+    assert_eq!(guide.description.as_deref(), Some("Tools for teams"));
+
+    // The static page is more specific than the wildcard
+    let changelog = block_on(registry.resolve("/docs/changelog"));
+    assert_eq!(
+        changelog.title,
+        Some(Title::Static("Changelog | Acme Docs".into()))
+    );
+
+    // A wildcard also matches the section root, with an empty capture
+    let (pattern, _, request) = registry.matching("/docs").pop().unwrap();
+    assert_eq!(pattern.as_str(), "/docs/*path");
+    assert_eq!(request.params["path"], "");
+
+    // Unknown paths only get the root layout
+    let layers = block_on(registry.resolve_layers("/pricing"));
+    assert_eq!(layers.len(), 1);
+}
+
+#[test]
+fn test_generators_receive_params_and_query() {
+    let post = block_on(registry().resolve("/blog/launch-day?page=2&ref=feed"));
+    assert_eq!(post.title, Some(Title::Static("launch-day | Acme".into())));
+    assert_eq!(post.canonical.as_deref(), Some("/blog/launch-day?page=2"));
+}
+
+#[test]
+fn test_context_loads_route_layers() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let registry = registry().page_with("/team/:name", move |request| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        async move { Metadata::with_title(request.params["name"].clone()) }
+    });
+
+    let context = MetadataContext::new().with_registry(registry);
+    block_on(context.load_route("/team/ana"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // Component metadata overrides the route layers
+    assert_eq!(
+        context.get_merged_metadata().title,
+        Some(Title::Static("ana | Acme".into()))
+    );
+    context.push_metadata(Metadata::default().description("Ana's profile"));
+    let merged = context.get_merged_metadata();
+    assert_eq!(merged.description.as_deref(), Some("Ana's profile"));
+    assert!(context.render_head().contains("<title>ana | Acme</title>"));
+
+    // Loading another route replaces the layers
+    block_on(context.load_route("/"));
+    assert_eq!(context.route_layers.read().len(), 1);
+
+    // Without a registry nothing is loaded
+    let context = MetadataContext::new();
+    block_on(context.load_route("/team/ana"));
+    assert!(context.route_layers.read().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Invalid route pattern")]
+fn test_invalid_pattern_panics() {
+    let _ = MetadataRegistry::new().page("blog/:slug", Metadata::default());
+}
@@ -0,0 +1,180 @@
+//! Tests for robots directive validation
+
+use chrono::{Duration, Utc};
+use leptos_next_metadata::metadata::{
+    Alternates, BotDirectives, Metadata, OpenGraph, Robots, RobotsDirectives, RobotsTxtRules,
+    ValidationErrorCode, ValidationWarningCode,
+};
+
+fn conflict_fields(result: &leptos_next_metadata::metadata::ValidationResult) -> Vec<&str> {
+    result
+        .warnings
+        .iter()
+        .filter(|w| w.code == ValidationWarningCode::ConflictingDirectives)
+        .filter_map(|w| w.field.as_deref())
+        .collect()
+}
+
+#[test]
+fn test_bot_overrides_contradicting_robots() {
+    let robots = Robots {
+        google_bot: Some(BotDirectives::noindex()),
+        ..Robots::all()
+    }
+    .bot("bingbot", BotDirectives::all());
+
+    let result = Metadata::default().robots(robots).validate();
+    assert_eq!(conflict_fields(&result), vec!["robots.googleBot.index"]);
+}
+
+#[test]
+fn test_unavailable_after_in_the_past() {
+    let past = Utc::now() - Duration::days(1);
+    let robots = Robots {
+        directives: RobotsDirectives {
+            unavailable_after: Some(past),
+            ..Default::default()
+        },
+        ..Robots::all()
+    }
+    .bot(
+        "bingbot",
+        BotDirectives {
+            directives: RobotsDirectives {
+                unavailable_after: Some(Utc::now() + Duration::days(30)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+
+    let result = Metadata::default().robots(robots).validate();
+    let errors: Vec<_> = result
+        .errors
+        .iter()
+        .filter(|e| e.code == ValidationErrorCode::InvalidDate)
+        .filter_map(|e| e.field.as_deref())
+        .collect();
+    assert_eq!(errors, vec!["robots.unavailableAfter"]);
+}
+
+#[test]
+fn test_noindex_with_canonical_and_hreflang() {
+    let metadata = Metadata::default()
+        .metadata_base("https://acme.com".parse().unwrap())
+        .robots(Robots {
+            follow: Some(true),
+            ..Robots::noindex()
+        })
+        .alternates(
+            Alternates::default()
+                .canonical("/post")
+                .language("de", "/de/post"),
+        );
+
+    let result = metadata.validate();
+    assert_eq!(conflict_fields(&result), vec!["canonical", "alternates"]);
+}
+
+#[test]
+fn test_unset_index_and_follow_allow_crawling() {
+    // Only a flag is set, so the page is index, follow
+    let metadata = Metadata::default()
+        .metadata_base("https://acme.com".parse().unwrap())
+        .robots(Robots {
+            directives: RobotsDirectives {
+                noarchive: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .alternates(
+            Alternates::default()
+                .canonical("/original")
+                .language("de", "/de/original"),
+        )
+        .open_graph(OpenGraph {
+            url: Some("/copy".into()),
+            ..Default::default()
+        });
+    assert!(conflict_fields(&metadata.validate()).is_empty());
+
+    let rules = RobotsTxtRules::parse("User-agent: *\nDisallow: /private\n");
+    let result = metadata.validate_with_robots_txt(&rules, "/private/page");
+    assert!(result
+        .warnings
+        .iter()
+        .any(|w| w.message.contains("so the page cannot be indexed")));
+}
+
+#[test]
+fn test_nofollow_with_canonical_elsewhere() {
+    let metadata = Metadata::default()
+        .metadata_base("https://acme.com".parse().unwrap())
+        .robots(Robots {
+            follow: Some(false),
+            ..Robots::all()
+        })
+        .canonical("/original")
+        .open_graph(OpenGraph {
+            url: Some("https://acme.com/copy".into()),
+            ..Default::default()
+        });
+    assert_eq!(conflict_fields(&metadata.validate()), vec!["robots.follow"]);
+
+    let metadata = metadata.open_graph(OpenGraph {
+        url: Some("/original".into()),
+        ..Default::default()
+    });
+    assert!(conflict_fields(&metadata.validate()).is_empty());
+}
+
+#[test]
+fn test_snippet_directives() {
+    let robots = Robots {
+        directives: RobotsDirectives {
+            nosnippet: Some(true),
+            max_snippet: Some(50),
+            max_video_preview: Some(-2),
+            ..Default::default()
+        },
+        ..Robots::all()
+    };
+
+    let result = Metadata::default().robots(robots).validate();
+    assert_eq!(conflict_fields(&result), vec!["robots.maxSnippet"]);
+    assert_eq!(
+        result.errors[0].field.as_deref(),
+        Some("robots.maxVideoPreview")
+    );
+}
+
+#[test]
+fn test_validate_with_robots_txt() {
+    let rules = RobotsTxtRules::parse(
+        "User-agent: *\n\
+         Disallow: /private\n\
+         \n\
+         User-agent: googlebot\n\
+         Disallow: /drafts\n",
+    );
+    let metadata = Metadata::default().robots(Robots::all());
+
+    let result = metadata.validate_with_robots_txt(&rules, "/private/page");
+    assert_eq!(conflict_fields(&result), vec!["robots.index"]);
+
+    let metadata = metadata.robots(Robots {
+        google_bot: Some(BotDirectives::noindex()),
+        ..Robots::all()
+    });
+    let result = metadata.validate_with_robots_txt(&rules, "/drafts/post");
+    assert!(conflict_fields(&result).contains(&"robots.googleBot.index"));
+    assert!(!conflict_fields(&result).contains(&"robots.index"));
+    assert!(result
+        .warnings
+        .iter()
+        .any(|w| w.message.contains("noindex is never seen")));
+
+    let result = metadata.validate_with_robots_txt(&rules, "/blog");
+    assert_eq!(conflict_fields(&result), vec!["robots.googleBot.index"]);
+}
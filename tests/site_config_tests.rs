@@ -0,0 +1,241 @@
+//! Tests for site-wide defaults loaded from a config file
+
+use leptos_next_metadata::metadata::{merge_metadata_chain, Metadata, OgType, Title, TwitterCard};
+use leptos_next_metadata::site_config::SiteConfig;
+use leptos_next_metadata::Error;
+use serde_json::json;
+
+const TOML_CONFIG: &str = r#"
+site_name = "Acme"
+base_url = "https://acme.com"
+title_template = "%s | Acme"
+description = "Tools for teams"
+twitter_site = "@acme"
+locales = ["en-US", "de-DE"]
+default_og_image = { url = "/og.png", width = 1200, height = 630 }
+
+[organization]
+logo = "/logo.png"
+sameAs = ["https://github.com/acme"]
+
+[[routes]]
+pattern = "/blog/*rest"
+title = "Blog"
+title_template = "%s | Acme Blog"
+og_image = "/og/blog.png"
+
+[[routes]]
+pattern = "/blog/drafts/:slug"
+noindex = true
+description = "Unpublished draft"
+"#;
+
+fn config_error(result: Result<SiteConfig, Error>) -> String {
+    match result {
+        Err(Error::ConfigError(message)) => message,
+        other => panic!("expected a config error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_base_metadata_from_toml() {
+    let config = SiteConfig::from_toml_str(TOML_CONFIG).unwrap();
+    assert_eq!(
+        config.metadata_config().metadata_base.unwrap().as_str(),
+        "https://acme.com/"
+    );
+
+    let base = config.base_metadata();
+    assert_eq!(
+        base.title,
+        Some(Title::Template {
+            template: "%s | Acme".into(),
+            default: "Acme".into(),
+        })
+    );
+    assert_eq!(base.description.as_deref(), Some("Tools for teams"));
+
+    let open_graph = base.open_graph.as_ref().unwrap();
+    assert_eq!(open_graph.r#type, Some(OgType::Website));
+    assert_eq!(open_graph.site_name.as_deref(), Some("Acme"));
+    assert_eq!(open_graph.locale.as_deref(), Some("en_US"));
+    assert_eq!(open_graph.images[0].url, "https://acme.com/og.png");
+    assert_eq!(open_graph.images[0].width, Some(1200));
+
+    let twitter = base.twitter.as_ref().unwrap();
+    assert_eq!(twitter.card, Some(TwitterCard::SummaryLargeImage));
+    assert_eq!(twitter.site.as_deref(), Some("@acme"));
+
+    assert_eq!(
+        base.json_ld,
+        Some(json!({
+            "@context": "https://schema.org",
+            "@type": "Organization",
+            "name": "Acme",
+            "url": "https://acme.com/",
+            "logo": "https://acme.com/logo.png",
+            "sameAs": ["https://github.com/acme"]
+        }))
+    );
+}
+
+#[test]
+fn test_route_overrides() {
+    let config = SiteConfig::from_toml_str(TOML_CONFIG).unwrap();
+
+    let home = config.metadata_for("/");
+    assert_eq!(home.title, Some(Title::Static("Acme".into())));
+    assert!(home.robots.is_none());
+
+    // The section's own title is wrapped by the site template
+    let blog = config.metadata_for("/blog");
+    assert_eq!(blog.title, Some(Title::Static("Blog | Acme".into())));
+    let open_graph = blog.open_graph.as_ref().unwrap();
+    assert_eq!(open_graph.images[0].url, "https://acme.com/og/blog.png");
+    assert_eq!(open_graph.site_name.as_deref(), Some("Acme"));
+    assert_eq!(
+        blog.twitter.as_ref().unwrap().image.as_deref(),
+        Some("https://acme.com/og/blog.png")
+    );
+
+    // Both overrides match, the more specific one last
+    let draft = config.metadata_for("/blog/drafts/launch");
+    assert_eq!(draft.robots.unwrap().index, Some(false));
+    assert_eq!(draft.description.as_deref(), Some("Unpublished draft"));
+    assert_eq!(
+        draft.open_graph.unwrap().images[0].url,
+        "https://acme.com/og/blog.png"
+    );
+
+    // Pages below the section use its template
+    let mut layers = config.layers("/blog/launch");
+    layers.push(Metadata::with_title("Launch day"));
+    let page = merge_metadata_chain(layers);
+    assert_eq!(
+        page.title,
+        Some(Title::Static("Launch day | Acme Blog".into()))
+    );
+}
+
+#[test]
+fn test_base_url_with_path() {
+    let config = SiteConfig::from_toml_str(
+        r#"
+site_name = "Acme Docs"
+base_url = "https://acme.com/docs"
+default_og_image = "/og.png"
+
+[organization]
+logo = "/logo.png"
+image = "/og.png"
+"#,
+    )
+    .unwrap();
+
+    let base = config.base_metadata();
+    let og_image = &base.open_graph.as_ref().unwrap().images[0].url;
+    assert_eq!(og_image, "https://acme.com/docs/og.png");
+
+    // The Organization resolves its URLs like the Open Graph image
+    let json_ld = base.json_ld.unwrap();
+    assert_eq!(json_ld["url"], "https://acme.com/docs");
+    assert_eq!(json_ld["logo"], "https://acme.com/docs/logo.png");
+    assert_eq!(json_ld["image"], og_image.as_str());
+}
+
+#[test]
+fn test_yaml_matches_toml() {
+    let yaml = SiteConfig::from_yaml_str(
+        r#"
+site_name: Acme
+base_url: https://acme.com
+title_template: "%s | Acme"
+description: Tools for teams
+twitter_site: "@acme"
+locales: [en-US, de-DE]
+default_og_image:
+  url: /og.png
+  width: 1200
+  height: 630
+organization:
+  logo: /logo.png
+  sameAs: [https://github.com/acme]
+routes:
+  - pattern: /blog/*rest
+    title: Blog
+    title_template: "%s | Acme Blog"
+    og_image: /og/blog.png
+  - pattern: /blog/drafts/:slug
+    noindex: true
+    description: Unpublished draft
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(yaml, SiteConfig::from_toml_str(TOML_CONFIG).unwrap());
+    assert_eq!(
+        SiteConfig::from_yaml_str("").unwrap(),
+        SiteConfig::default()
+    );
+}
+
+#[test]
+fn test_errors_point_to_line() {
+    let message = config_error(SiteConfig::from_toml_str(
+        "site_name = \"Acme\"\ntitle_templat = \"%s | Acme\"\n",
+    ));
+    assert!(
+        message.starts_with("line 2, column 1: unknown field `title_templat`"),
+        "{}",
+        message
+    );
+
+    let message = config_error(SiteConfig::from_toml_str(
+        "site_name = \"Acme\"\n\n[[routes]]\npattern = \"blog/:slug\"\n",
+    ));
+    assert!(message.starts_with("line 4, column 11:"), "{}", message);
+    assert!(message.contains("must start with `/`"), "{}", message);
+
+    let message = config_error(SiteConfig::from_yaml_str(
+        "site_name: Acme\ntwitter_site: acme\n",
+    ));
+    assert!(message.starts_with("line 2, column 15:"), "{}", message);
+    assert!(message.contains("not a Twitter handle"), "{}", message);
+
+    let message = config_error(SiteConfig::from_yaml_str("base_url: not a url\n"));
+    assert!(message.starts_with("line 1, column 11:"), "{}", message);
+
+    let message = config_error(SiteConfig::from_yaml_str(
+        "site_name: Acme\ntitle_template: Acme\n",
+    ));
+    assert!(message.contains("no `%s` placeholder"), "{}", message);
+
+    let message = config_error(SiteConfig::from_yaml_str(
+        "locales: [en]\ndefault_locale: fr\n",
+    ));
+    assert!(message.contains("not listed in `locales`"), "{}", message);
+
+    let message = config_error(SiteConfig::from_yaml_str("default_og_image: /og.png\n"));
+    assert!(message.contains("needs a `base_url`"), "{}", message);
+}
+
+#[test]
+fn test_load_file() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(SiteConfig::discover(dir.path()).unwrap(), None);
+
+    let path = dir.path().join("metadata.yaml");
+    std::fs::write(&path, "site_name: Acme\nlocales: [english]\n").unwrap();
+    let message = config_error(SiteConfig::discover(dir.path()).map(Option::unwrap_or_default));
+    assert!(
+        message.starts_with(&format!("{}: line 2, column 11:", path.display())),
+        "{}",
+        message
+    );
+
+    std::fs::write(dir.path().join("metadata.toml"), "site_name = \"Acme\"\n").unwrap();
+    let config = SiteConfig::discover(dir.path()).unwrap().unwrap();
+    assert_eq!(config.site_name.as_deref(), Some("Acme"));
+
+    assert!(SiteConfig::from_file(dir.path().join("metadata.json")).is_err());
+}
@@ -0,0 +1,109 @@
+//! Tests for async metadata in streamed responses
+
+use futures::StreamExt;
+use leptos_next_metadata::metadata::context::HEAD_MARKER;
+use leptos_next_metadata::metadata::registry::MetadataRegistry;
+use leptos_next_metadata::metadata::streaming::{HeadStreaming, HEAD_END_MARKER};
+use leptos_next_metadata::metadata::{Metadata, MetadataContext};
+use leptos_next_metadata::{LimitConfig, MetadataConfig};
+use std::time::Duration;
+
+fn chunks() -> futures::stream::Iter<std::vec::IntoIter<String>> {
+    futures::stream::iter(vec![
+        format!("<html><head>{}</head><body>", HEAD_MARKER),
+        "<template>resolved</template></body></html>".to_string(),
+    ])
+}
+
+async fn product() -> Metadata {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    Metadata::with_title("Product 42")
+}
+
+#[tokio::test]
+async fn test_block_waits_for_pending_metadata() {
+    let context = MetadataContext::new();
+    context.push_metadata(Metadata::with_title("Shop"));
+    context.push_metadata_async(product());
+
+    let output: Vec<String> = context.clone().inject_head_stream(chunks()).collect().await;
+    assert_eq!(output.len(), 2);
+    assert!(output[0].contains("<title>Product 42</title>"));
+    assert!(!output[0].contains(HEAD_END_MARKER));
+    assert!(!context.has_pending_metadata());
+}
+
+#[tokio::test]
+async fn test_block_times_out() {
+    let context = MetadataContext::with_config(MetadataConfig {
+        limits: LimitConfig {
+            max_generation_time: 10,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    context.push_metadata(Metadata::with_title("Shop"));
+    context.push_metadata_async(futures::future::pending::<Metadata>());
+
+    let output: Vec<String> = context.inject_head_stream(chunks()).collect().await;
+    assert!(output[0].contains("<title>Shop</title>"));
+    assert_eq!(output[1], "<template>resolved</template></body></html>");
+}
+
+#[tokio::test]
+async fn test_patch_streams_script() {
+    let context = MetadataContext::new().with_head_streaming(HeadStreaming::Patch);
+    context.push_metadata(Metadata::with_title("Shop"));
+    context.push_metadata_async(product());
+
+    let output: Vec<String> = context.inject_head_stream(chunks()).collect().await;
+    assert_eq!(output.len(), 3);
+    assert_eq!(
+        output[0],
+        format!(
+            "<html><head>{}<title>Shop</title>\n{}</head><body>",
+            HEAD_MARKER, HEAD_END_MARKER
+        )
+    );
+
+    // The body chunk is not held back by the pending metadata
+    assert_eq!(output[1], "<template>resolved</template></body></html>");
+    assert!(output[2].starts_with("<script>"));
+    assert!(output[2].contains(r#"t.innerHTML="\u003ctitle\u003eProduct 42\u003c/title\u003e\n""#));
+}
+
+#[tokio::test]
+async fn test_without_pending_metadata_nothing_is_added() {
+    let context = MetadataContext::new().with_head_streaming(HeadStreaming::Patch);
+    context.push_metadata(Metadata::with_title("Shop"));
+
+    let output: Vec<String> = context.inject_head_stream(chunks()).collect().await;
+    assert_eq!(output.len(), 2);
+    assert_eq!(output[0], "<html><head><title>Shop</title>\n</head><body>");
+}
+
+#[tokio::test]
+async fn test_streaming_mode_per_route() {
+    let registry = MetadataRegistry::new()
+        .layout("/", Metadata::with_title("Shop"))
+        .head_streaming("/", HeadStreaming::Block)
+        .head_streaming("/products", HeadStreaming::Patch)
+        .head_streaming("/products/featured", HeadStreaming::Block);
+    assert_eq!(
+        registry.head_streaming_for("/about"),
+        Some(HeadStreaming::Block)
+    );
+    assert_eq!(
+        registry.head_streaming_for("/products/7"),
+        Some(HeadStreaming::Patch)
+    );
+    assert_eq!(
+        registry.head_streaming_for("/products/featured"),
+        Some(HeadStreaming::Block)
+    );
+    assert_eq!(MetadataRegistry::new().head_streaming_for("/"), None);
+
+    let context = MetadataContext::new().with_registry(registry);
+    context.load_route("/products/7").await;
+    assert_eq!(context.head_streaming(), HeadStreaming::Patch);
+}
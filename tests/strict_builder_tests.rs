@@ -0,0 +1,88 @@
+//! Tests for the typestate builders
+
+use leptos_next_metadata::metadata::strict::{
+    StrictMetadataBuilder, StrictOpenGraphBuilder, StrictTwitterBuilder,
+};
+use leptos_next_metadata::metadata::{Metadata, OgImage, OgType, Title, TwitterCard};
+
+#[test]
+fn test_strict_metadata_in_any_order() {
+    let metadata = StrictMetadataBuilder::new()
+        .canonical("https://acme.com/about")
+        .keywords("acme")
+        .description("About Acme")
+        .title("About")
+        .build();
+
+    assert_eq!(metadata.title, Some(Title::Static("About".into())));
+    assert_eq!(metadata.description.as_deref(), Some("About Acme"));
+    assert_eq!(
+        metadata.canonical.as_deref(),
+        Some("https://acme.com/about")
+    );
+    assert!(metadata.validate().is_valid());
+
+    let equivalent = Metadata::with_title("About")
+        .description("About Acme")
+        .canonical("https://acme.com/about")
+        .keywords("acme");
+    assert_eq!(metadata, equivalent);
+}
+
+#[test]
+fn test_strict_open_graph() {
+    let og = StrictOpenGraphBuilder::new()
+        .image(OgImage::with_dimensions("/og.png", 1200, 630))
+        .image("/og-square.png")
+        .url("https://acme.com")
+        .og_type(OgType::Website)
+        .title("Acme")
+        .site_name("Acme")
+        .build();
+
+    assert_eq!(og.r#type, Some(OgType::Website));
+    assert_eq!(og.images.len(), 2);
+    assert_eq!(og.images[0].width, Some(1200));
+    assert_eq!(og.site_name.as_deref(), Some("Acme"));
+}
+
+#[test]
+fn test_strict_twitter_cards() {
+    let summary = StrictTwitterBuilder::summary().title("Acme").build();
+    assert_eq!(summary.card, Some(TwitterCard::Summary));
+    assert_eq!(summary.image, None);
+
+    let large = StrictTwitterBuilder::summary_large_image()
+        .image("/og.png")
+        .title("Acme")
+        .image_alt("Logo")
+        .build();
+    assert_eq!(large.card, Some(TwitterCard::SummaryLargeImage));
+    assert_eq!(large.image.as_deref(), Some("/og.png"));
+
+    let player = StrictTwitterBuilder::player("https://acme.com/embed/1", 640, 360)
+        .title("Launch video")
+        .site("@acme")
+        .image("/video.png")
+        .build();
+    assert_eq!(player.player_width, Some(640));
+    assert_eq!(player.site.as_deref(), Some("@acme"));
+
+    // An Android-only app needs no App Store IDs
+    let app = StrictTwitterBuilder::app()
+        .site("@acme")
+        .app_id_googleplay("com.acme")
+        .build();
+    assert_eq!(app.card, Some(TwitterCard::App));
+    assert_eq!(app.app_id_googleplay.as_deref(), Some("com.acme"));
+    assert_eq!(app.app_id_iphone, None);
+    assert_eq!(app.title, None);
+
+    let app = StrictTwitterBuilder::app()
+        .app_id_iphone("123")
+        .app_id_ipad("456")
+        .site("@acme")
+        .build();
+    assert_eq!(app.app_id_iphone.as_deref(), Some("123"));
+    assert_eq!(app.app_id_ipad.as_deref(), Some("456"));
+}
@@ -0,0 +1,159 @@
+//! Tests for viewport, theme color and color scheme configuration
+
+use leptos_next_metadata::metadata::{
+    ColorScheme, Metadata, ThemeColor, ValidationErrorCode, ValidationWarningCode, Viewport,
+    ViewportConfig, ViewportFit,
+};
+
+#[test]
+fn test_render_viewport_config() {
+    let config = ViewportConfig::new()
+        .viewport(Viewport {
+            maximum_scale: Some(5.0),
+            viewport_fit: Some(ViewportFit::Cover),
+            ..Viewport::device_width()
+        })
+        .theme_color(ThemeColor::light("#ffffff"))
+        .theme_color(ThemeColor::dark("#0f172a"))
+        .color_scheme(ColorScheme::Dark);
+
+    let html = config.to_head_html();
+    assert_eq!(
+        html,
+        concat!(
+            r#"<meta name="viewport" content="width=device-width, initial-scale=1, maximum-scale=5, viewport-fit=cover">"#,
+            "\n",
+            r##"<meta name="theme-color" content="#ffffff" media="(prefers-color-scheme: light)">"##,
+            "\n",
+            r##"<meta name="theme-color" content="#0f172a" media="(prefers-color-scheme: dark)">"##,
+            "\n",
+            r#"<meta name="color-scheme" content="dark">"#,
+            "\n",
+        )
+    );
+
+    let mut streamed = Vec::new();
+    config.write_head_html(&mut streamed).unwrap();
+    assert_eq!(String::from_utf8(streamed).unwrap(), html);
+
+    assert!(ViewportConfig::default().to_head_html().is_empty());
+}
+
+#[test]
+fn test_deserialize_theme_colors() {
+    let config: ViewportConfig = serde_json::from_value(serde_json::json!({
+        "width": "DeviceWidth",
+        "initial_scale": 1.0,
+        "theme_color": [
+            "#ffffff",
+            { "color": "#000000", "media": "(prefers-color-scheme: dark)" }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(config.viewport, Viewport::device_width());
+    assert_eq!(
+        config.theme_color,
+        vec![ThemeColor::new("#ffffff"), ThemeColor::dark("#000000")]
+    );
+
+    let config: ViewportConfig =
+        serde_json::from_value(serde_json::json!({ "theme_color": "#ffffff" })).unwrap();
+    assert_eq!(config.theme_color, vec![ThemeColor::new("#ffffff")]);
+}
+
+#[test]
+fn test_metadata_theme_color_media() {
+    let html = Metadata::default()
+        .theme_color(ThemeColor::dark("#000000"))
+        .to_head_html();
+
+    assert!(html.contains(
+        r##"<meta name="theme-color" content="#000000" media="(prefers-color-scheme: dark)">"##
+    ));
+}
+
+#[test]
+fn test_validate_scales() {
+    let config = ViewportConfig::new().viewport(Viewport {
+        initial_scale: Some(20.0),
+        minimum_scale: Some(3.0),
+        maximum_scale: Some(2.0),
+        ..Default::default()
+    });
+
+    let result = config.validate();
+    let errors: Vec<_> = result
+        .errors
+        .iter()
+        .filter(|e| e.code == ValidationErrorCode::InvalidFormat)
+        .filter_map(|e| e.field.as_deref())
+        .collect();
+    assert_eq!(
+        errors,
+        vec!["viewport.initialScale", "viewport.minimumScale"]
+    );
+
+    let config = ViewportConfig::new().viewport(Viewport {
+        initial_scale: Some(0.5),
+        minimum_scale: Some(1.0),
+        ..Viewport::device_width()
+    });
+    let result = config.validate();
+    assert!(result.is_valid());
+    assert_eq!(
+        result.warnings[0].field.as_deref(),
+        Some("viewport.initialScale")
+    );
+}
+
+#[test]
+fn test_validate_zoom_accessibility() {
+    let config = ViewportConfig::new().viewport(Viewport {
+        maximum_scale: Some(1.0),
+        user_scalable: Some(false),
+        ..Viewport::device_width()
+    });
+
+    let result = config.validate();
+    assert!(result.is_valid());
+    let fields: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.code == ValidationWarningCode::AccessibilityConsideration)
+        .filter_map(|w| w.field.as_deref())
+        .collect();
+    assert_eq!(
+        fields,
+        vec!["viewport.userScalable", "viewport.maximumScale"]
+    );
+
+    let metadata = Metadata::default().viewport(config.viewport);
+    assert!(metadata
+        .validate()
+        .warnings
+        .iter()
+        .any(|w| w.field.as_deref() == Some("viewport.userScalable")));
+}
+
+#[test]
+fn test_validate_theme_colors() {
+    let config = ViewportConfig::new()
+        .theme_color("#ffffff")
+        .theme_color(ThemeColor::dark(" "))
+        .theme_color("#eeeeee");
+
+    let result = config.validate();
+    let fields: Vec<_> = result
+        .errors
+        .iter()
+        .filter_map(|e| e.field.as_deref())
+        .collect();
+    assert_eq!(fields, vec!["themeColor[1].color", "themeColor[2].media"]);
+
+    assert!(ViewportConfig::new()
+        .theme_color(ThemeColor::light("#ffffff"))
+        .theme_color(ThemeColor::dark("#000000"))
+        .validate()
+        .is_valid());
+}